@@ -0,0 +1,1370 @@
+use std::collections::BTreeMap;
+#[cfg(feature = "fs")]
+use std::io::Read as _;
+use std::path::Path;
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+use crate::rules::{builtin_needles, registry, Severity, StructuralLimits};
+
+/// Maximum config file size. Configs larger than this are rejected before parsing.
+#[cfg(feature = "fs")]
+const MAX_CONFIG_BYTES: u64 = 1024 * 1024; // 1 MiB
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Config {
+    pub version: u32,
+    /// Ids of built-in rules that do not run, as listed by `unai rules doc`.
+    #[serde(default)]
+    pub disabled_rules: Vec<String>,
+    /// Severity for built-in rules by id, in place of the shipped one.
+    #[serde(default)]
+    pub severity_overrides: BTreeMap<String, String>,
+    #[serde(default)]
+    pub rules: Vec<UserRule>,
+    #[serde(default)]
+    pub ignore: IgnoreConfig,
+    #[serde(default)]
+    pub fail: FailConfig,
+    #[serde(default)]
+    pub detector: DetectorConfig,
+    #[serde(default)]
+    pub whitespace: WhitespaceConfig,
+    #[serde(default)]
+    pub punctuation: PunctuationConfig,
+    #[serde(default)]
+    pub output: OutputConfig,
+    #[serde(default)]
+    pub code: CodeConfig,
+    #[serde(default)]
+    pub structural: StructuralConfig,
+    #[serde(default)]
+    pub input: InputConfig,
+    #[serde(default)]
+    pub commit: CommitConfig,
+    #[serde(default)]
+    pub allow: Vec<AllowPhrase>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            version: 1,
+            disabled_rules: Vec::new(),
+            severity_overrides: BTreeMap::new(),
+            rules: Vec::new(),
+            ignore: IgnoreConfig::default(),
+            fail: FailConfig::default(),
+            detector: DetectorConfig::default(),
+            whitespace: WhitespaceConfig::default(),
+            punctuation: PunctuationConfig::default(),
+            output: OutputConfig::default(),
+            code: CodeConfig::default(),
+            structural: StructuralConfig::default(),
+            input: InputConfig::default(),
+            commit: CommitConfig::default(),
+            allow: Vec::new(),
+        }
+    }
+}
+
+/// A phrase that is correct as written ("comprehensive income"): findings
+/// inside one of its occurrences are suppressed, elsewhere they still fire.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct AllowPhrase {
+    pub phrase: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UserRule {
+    /// Rule id in reports; `user/<pattern>` when unset.
+    pub id: Option<String>,
+    pub pattern: String,
+    pub replacement: Option<String>,
+    pub severity: Option<String>,
+    pub message: Option<String>,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Match `pattern` with its exact case; by default case is ignored.
+    #[serde(default)]
+    pub case_sensitive: bool,
+    /// Read `pattern` as a regular expression instead of a whole word.
+    #[serde(default)]
+    pub regex: bool,
+}
+
+impl UserRule {
+    /// The compiled `pattern` of a `regex = true` rule, ignoring case unless
+    /// `case_sensitive` is set.
+    pub fn compile(&self) -> std::result::Result<regex::Regex, regex::Error> {
+        regex::RegexBuilder::new(&self.pattern)
+            .case_insensitive(!self.case_sensitive)
+            .build()
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+pub struct IgnoreConfig {
+    #[serde(default)]
+    pub words: Vec<String>,
+    /// Compare `words` with a finding's matched text case-sensitively.
+    /// Off by default, so "delve" also hides "Delve".
+    #[serde(default)]
+    pub case_sensitive: bool,
+    // Glob patterns for files to skip entirely — wired in v0.4.0.
+    #[serde(default)]
+    #[allow(dead_code)]
+    pub files: Vec<String>,
+}
+
+impl IgnoreConfig {
+    /// `word` as compared against `words`: lowercased unless `case_sensitive`.
+    pub fn word_key(&self, word: &str) -> String {
+        if self.case_sensitive {
+            word.to_string()
+        } else {
+            word.to_lowercase()
+        }
+    }
+}
+
+/// Per-category `--fail` thresholds: a severity, or `"never"` to keep the
+/// category advisory. Unset categories fail on any finding shown after
+/// `--min-severity`.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct FailConfig {
+    pub text: Option<String>,
+    pub structural: Option<String>,
+    pub code: Option<String>,
+    pub user: Option<String>,
+}
+
+/// Limits for the whitespace cleanup rules.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct WhitespaceConfig {
+    /// Longest run of blank lines kept; extra blank lines are removed.
+    #[serde(default = "default_max_blank_lines")]
+    pub max_blank_lines: usize,
+}
+
+fn default_max_blank_lines() -> usize {
+    2
+}
+
+impl Default for WhitespaceConfig {
+    fn default() -> Self {
+        WhitespaceConfig {
+            max_blank_lines: default_max_blank_lines(),
+        }
+    }
+}
+
+/// Limits and fixes for the typographic punctuation rules.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PunctuationConfig {
+    /// Em dashes between clauses a paragraph may hold before the rest are
+    /// flagged.
+    #[serde(default = "default_em_dashes_per_paragraph")]
+    pub em_dashes_per_paragraph: usize,
+    /// What a clean run turns a flagged em dash into; flagged only when unset.
+    #[serde(default)]
+    pub em_dash_fix: Option<EmDashFix>,
+}
+
+fn default_em_dashes_per_paragraph() -> usize {
+    1
+}
+
+impl Default for PunctuationConfig {
+    fn default() -> Self {
+        PunctuationConfig {
+            em_dashes_per_paragraph: default_em_dashes_per_paragraph(),
+            em_dash_fix: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EmDashFix {
+    /// "ships Tuesday — if" becomes "ships Tuesday, if".
+    Comma,
+    /// "ships Tuesday — if" becomes "ships Tuesday - if".
+    Hyphen,
+}
+
+impl EmDashFix {
+    /// Text put in place of the dash and the space before it.
+    pub fn replacement(self) -> &'static str {
+        match self {
+            EmDashFix::Comma => ",",
+            EmDashFix::Hyphen => " -",
+        }
+    }
+}
+
+/// Limits on what the rules analyze.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct InputConfig {
+    /// Lines longer than this many bytes are skipped by the lexical rules and
+    /// reported once instead; 0 analyzes every line.
+    #[serde(default = "default_max_line_bytes")]
+    pub max_line_bytes: usize,
+    /// Run the text and structural rules on a leading `---` or `+++`
+    /// frontmatter block instead of skipping it.
+    #[serde(default)]
+    pub scan_frontmatter: bool,
+}
+
+fn default_max_line_bytes() -> usize {
+    10_000
+}
+
+impl Default for InputConfig {
+    fn default() -> Self {
+        InputConfig {
+            max_line_bytes: default_max_line_bytes(),
+            scan_frontmatter: false,
+        }
+    }
+}
+
+/// How commit message files are read.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct CommitConfig {
+    /// Start of the lines git strips from a message, as set by
+    /// `core.commentChar`; the commit rules skip them.
+    #[serde(default = "default_comment_char")]
+    pub comment_char: String,
+}
+
+fn default_comment_char() -> String {
+    "#".to_string()
+}
+
+impl Default for CommitConfig {
+    fn default() -> Self {
+        CommitConfig {
+            comment_char: default_comment_char(),
+        }
+    }
+}
+
+/// Structural rules for text mode: how long a document must be before each
+/// runs, and the opt-in ones.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct StructuralConfig {
+    /// Flag a section when more than this fraction (0.0–1.0) of its sentences
+    /// open with "we" or "our". Off when unset.
+    pub first_person_plural_max: Option<f64>,
+    /// Prose words before connector density is checked.
+    #[serde(default = "default_connector_min_words")]
+    pub connector_min_words: usize,
+    /// Connectors per 100 words of a paragraph at which it is dense.
+    #[serde(default = "default_connectors_per_100_words")]
+    pub connectors_per_100_words: f64,
+    /// Prose sentences before sentence-length uniformity is checked.
+    #[serde(default = "default_uniformity_min_sentences")]
+    pub uniformity_min_sentences: usize,
+    /// Prose words before conclusion paragraphs are checked.
+    #[serde(default = "default_conclusion_min_words")]
+    pub conclusion_min_words: usize,
+    /// Prose words before duplicate sentences and repeated stems are checked.
+    #[serde(default = "default_repetition_min_words")]
+    pub repetition_min_words: usize,
+    /// Non-empty lines before heading density and the heading ratio are checked.
+    #[serde(default = "default_heading_min_lines")]
+    pub heading_min_lines: usize,
+    /// Prose lines per heading below which a document is over-structured.
+    #[serde(default = "default_lines_per_heading")]
+    pub lines_per_heading: f64,
+}
+
+fn default_connector_min_words() -> usize {
+    StructuralLimits::default().connector_min_words
+}
+
+fn default_connectors_per_100_words() -> f64 {
+    StructuralLimits::default().connectors_per_100_words
+}
+
+fn default_uniformity_min_sentences() -> usize {
+    StructuralLimits::default().uniformity_min_sentences
+}
+
+fn default_conclusion_min_words() -> usize {
+    StructuralLimits::default().conclusion_min_words
+}
+
+fn default_repetition_min_words() -> usize {
+    StructuralLimits::default().repetition_min_words
+}
+
+fn default_heading_min_lines() -> usize {
+    StructuralLimits::default().heading_min_lines
+}
+
+fn default_lines_per_heading() -> f64 {
+    StructuralLimits::default().lines_per_heading
+}
+
+impl Default for StructuralConfig {
+    fn default() -> Self {
+        StructuralConfig {
+            first_person_plural_max: None,
+            connector_min_words: default_connector_min_words(),
+            connectors_per_100_words: default_connectors_per_100_words(),
+            uniformity_min_sentences: default_uniformity_min_sentences(),
+            conclusion_min_words: default_conclusion_min_words(),
+            repetition_min_words: default_repetition_min_words(),
+            heading_min_lines: default_heading_min_lines(),
+            lines_per_heading: default_lines_per_heading(),
+        }
+    }
+}
+
+impl StructuralConfig {
+    /// The document-size limits the structural rules run with.
+    pub fn limits(&self) -> StructuralLimits {
+        StructuralLimits {
+            connector_min_words: self.connector_min_words,
+            connectors_per_100_words: self.connectors_per_100_words,
+            uniformity_min_sentences: self.uniformity_min_sentences,
+            conclusion_min_words: self.conclusion_min_words,
+            repetition_min_words: self.repetition_min_words,
+            heading_min_lines: self.heading_min_lines,
+            lines_per_heading: self.lines_per_heading,
+        }
+    }
+}
+
+/// Code-mode options; each mirrors the CLI flag of the same name.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct CodeConfig {
+    #[serde(default)]
+    pub check_strings: bool,
+    #[serde(default)]
+    pub fix_strings: bool,
+}
+
+/// Normalization applied to the text unai writes out, and limits on how
+/// findings are displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct OutputConfig {
+    #[serde(default)]
+    pub final_newline: FinalNewline,
+    #[serde(default)]
+    pub line_endings: LineEndings,
+    /// Longest matched text shown in reports, in chars; 0 shows it in full.
+    #[serde(default = "default_max_matched_len")]
+    pub max_matched_len: usize,
+}
+
+fn default_max_matched_len() -> usize {
+    200
+}
+
+impl Default for OutputConfig {
+    fn default() -> Self {
+        OutputConfig {
+            final_newline: FinalNewline::default(),
+            line_endings: LineEndings::default(),
+            max_matched_len: default_max_matched_len(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FinalNewline {
+    /// Keep the input's final newline, or lack of one.
+    #[default]
+    Preserve,
+    Always,
+    Never,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LineEndings {
+    /// Keep each line's own ending, mixed files included.
+    #[default]
+    Preserve,
+    Lf,
+    Crlf,
+}
+
+/// Extra mode-detection hints for `--mode auto`.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct DetectorConfig {
+    /// Globs for files that hold commit messages, matched against the path
+    /// given on the command line.
+    #[serde(default)]
+    pub commit_globs: Vec<String>,
+}
+
+impl DetectorConfig {
+    fn commit_glob_set(&self) -> Result<GlobSet> {
+        glob_set(&self.commit_globs)
+            .map_err(|e| Error::ConfigInvalid(format!("{} in detector.commit_globs", e)))
+    }
+
+    /// Whether `path` matches one of `commit_globs`.
+    pub fn is_commit_file(&self, path: &str) -> bool {
+        // `validate()` already rejected bad globs, so compiling cannot fail here.
+        self.commit_glob_set()
+            .is_ok_and(|set| set.is_match(Path::new(slash_path(path).as_ref())))
+    }
+}
+
+/// `patterns` compiled into one set; the error names the pattern at fault.
+pub fn glob_set(patterns: &[String]) -> std::result::Result<GlobSet, String> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let glob =
+            Glob::new(pattern).map_err(|e| format!("invalid glob '{}': {}", pattern, e.kind()))?;
+        builder.add(glob);
+    }
+    builder.build().map_err(|e| e.to_string())
+}
+
+/// `path` with `\` separators turned into `/`, so globs written with `/`
+/// match Windows paths like `docs\guide.md` on every platform.
+pub fn slash_path(path: &str) -> std::borrow::Cow<'_, str> {
+    if path.contains('\\') {
+        path.replace('\\', "/").into()
+    } else {
+        path.into()
+    }
+}
+
+impl FailConfig {
+    fn entries(&self) -> [(&'static str, Option<&str>); 4] {
+        [
+            ("text", self.text.as_deref()),
+            ("structural", self.structural.as_deref()),
+            ("code", self.code.as_deref()),
+            ("user", self.user.as_deref()),
+        ]
+    }
+}
+
+impl Config {
+    #[cfg(feature = "fs")]
+    pub fn load(path: &Path) -> Result<Config> {
+        let mut file = std::fs::File::open(path).map_err(|source| Error::FileRead {
+            path: path.into(),
+            source,
+        })?;
+        if file.metadata().map(|m| m.len()).unwrap_or(0) > MAX_CONFIG_BYTES {
+            return Err(Error::ConfigInvalid(
+                "config file exceeds 1 MiB size limit".to_string(),
+            ));
+        }
+        let mut content = String::new();
+        file.read_to_string(&mut content)
+            .map_err(|source| Error::FileRead {
+                path: path.into(),
+                source,
+            })?;
+        let config: Config = toml::from_str(&content).map_err(|source| Error::ConfigParse {
+            path: path.into(),
+            source: Box::new(source),
+        })?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    #[cfg(feature = "fs")]
+    pub fn load_from_cwd() -> Result<Option<Config>> {
+        let path = Path::new("unai.toml");
+        match Config::load(path) {
+            Ok(cfg) => Ok(Some(cfg)),
+            Err(Error::FileRead { source, .. })
+                if source.kind() == std::io::ErrorKind::NotFound =>
+            {
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Settings that are valid but likely not what the user meant.
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if self.ignore.case_sensitive {
+            for word in &self.ignore.words {
+                let lower = word.to_lowercase();
+                if let Some(needle) =
+                    builtin_needles().find(|n| *n != word && n.to_lowercase() == lower)
+                {
+                    warnings.push(format!(
+                        "ignore.words entry '{word}' differs only by case from the built-in '{needle}'; \
+                         with ignore.case_sensitive it hides '{word}' but not '{needle}'"
+                    ));
+                }
+            }
+        }
+        warnings
+    }
+
+    /// Check what deserializing cannot, such as rule ids and globs. [`Config::load`]
+    /// runs this; call it on a config deserialized some other way.
+    pub fn validate(&self) -> Result<()> {
+        if self.version != 1 {
+            return Err(Error::ConfigInvalid(format!(
+                "unsupported version {}",
+                self.version
+            )));
+        }
+        let ids: Vec<String> = registry().into_iter().map(|r| r.id).collect();
+        if let Some(unknown) = self.disabled_rules.iter().find(|id| !ids.contains(id)) {
+            return Err(Error::ConfigInvalid(format!(
+                "unknown rule id '{}' in disabled_rules; valid: {}",
+                unknown,
+                ids.join(", ")
+            )));
+        }
+        for (id, severity) in &self.severity_overrides {
+            if !ids.contains(id) {
+                return Err(Error::ConfigInvalid(format!(
+                    "unknown rule id '{}' in severity_overrides; valid: {}",
+                    id,
+                    ids.join(", ")
+                )));
+            }
+            severity.parse::<Severity>().map_err(|e| {
+                Error::ConfigInvalid(format!("{} for '{}' in severity_overrides", e, id))
+            })?;
+        }
+        if self.allow.iter().any(|a| a.phrase.trim().is_empty()) {
+            return Err(Error::ConfigInvalid(
+                "allow phrase cannot be empty".to_string(),
+            ));
+        }
+        for rule in &self.rules {
+            if rule.pattern.is_empty() || rule.pattern.trim().is_empty() {
+                return Err(Error::ConfigInvalid(
+                    "rule pattern cannot be empty".to_string(),
+                ));
+            }
+            if let Some(id) = &rule.id {
+                if id.is_empty() || id.contains(char::is_whitespace) {
+                    return Err(Error::ConfigInvalid(format!(
+                        "rule id '{}' must be non-empty and without spaces",
+                        id
+                    )));
+                }
+            }
+            if rule.regex {
+                if let Err(e) = rule.compile() {
+                    return Err(Error::ConfigInvalid(format!(
+                        "invalid regex '{}': {}",
+                        rule.pattern, e
+                    )));
+                }
+            }
+            if let Some(ref s) = rule.severity {
+                match s.as_str() {
+                    "critical" | "high" | "medium" | "low" => {}
+                    _ => {
+                        return Err(Error::ConfigInvalid(format!(
+                            "unknown severity '{}'; valid: critical, high, medium, low",
+                            s
+                        )));
+                    }
+                }
+            }
+        }
+        for (category, threshold) in self.fail.entries() {
+            match threshold {
+                None | Some("never" | "critical" | "high" | "medium" | "low") => {}
+                Some(other) => {
+                    return Err(Error::ConfigInvalid(format!(
+                        "unknown fail threshold '{}' for '{}'; valid: never, critical, high, medium, low",
+                        other, category
+                    )));
+                }
+            }
+        }
+        self.detector.commit_glob_set()?;
+        if let Some(max) = self.structural.first_person_plural_max {
+            if !(0.0..=1.0).contains(&max) {
+                return Err(Error::ConfigInvalid(format!(
+                    "structural.first_person_plural_max must be between 0 and 1, got {}",
+                    max
+                )));
+            }
+        }
+        let rate = self.structural.connectors_per_100_words;
+        if !rate.is_finite() || rate < 0.0 {
+            return Err(Error::ConfigInvalid(format!(
+                "structural.connectors_per_100_words must be a non-negative number, got {}",
+                rate
+            )));
+        }
+        let per_heading = self.structural.lines_per_heading;
+        if !per_heading.is_finite() || per_heading < 0.0 {
+            return Err(Error::ConfigInvalid(format!(
+                "structural.lines_per_heading must be a non-negative number, got {}",
+                per_heading
+            )));
+        }
+        if self.whitespace.max_blank_lines == 0 {
+            return Err(Error::ConfigInvalid(
+                "whitespace.max_blank_lines must be at least 1".to_string(),
+            ));
+        }
+        let comment_char = &self.commit.comment_char;
+        if comment_char.is_empty() || comment_char.starts_with(char::is_whitespace) {
+            return Err(Error::ConfigInvalid(format!(
+                "commit.comment_char must be non-empty and not start with whitespace, got '{}'",
+                comment_char
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// One setting in the generated default config.
+struct SettingDoc {
+    key: &'static str,
+    doc: &'static str,
+    /// Shown commented out for a setting that has no default value.
+    example: Option<&'static str>,
+}
+
+/// One section of the generated default config, in the order printed.
+struct SectionDoc {
+    name: &'static str,
+    doc: &'static str,
+    /// A `[[name]]` array of tables; printed as a commented-out entry.
+    array: bool,
+    settings: &'static [SettingDoc],
+}
+
+const fn setting(key: &'static str, doc: &'static str) -> SettingDoc {
+    SettingDoc {
+        key,
+        doc,
+        example: None,
+    }
+}
+
+const fn optional(key: &'static str, doc: &'static str, example: &'static str) -> SettingDoc {
+    SettingDoc {
+        key,
+        doc,
+        example: Some(example),
+    }
+}
+
+const SECTIONS: &[SectionDoc] = &[
+    SectionDoc {
+        name: "ignore",
+        doc: "Findings to hide.",
+        array: false,
+        settings: &[
+            setting(
+                "words",
+                "Hide findings whose matched text is one of these words or phrases.",
+            ),
+            setting(
+                "case_sensitive",
+                "Compare `words` case-sensitively; by default \"delve\" also hides \"Delve\".",
+            ),
+            setting(
+                "files",
+                "Glob patterns for files to skip. Accepted but not applied yet.",
+            ),
+        ],
+    },
+    SectionDoc {
+        name: "fail",
+        doc: "Per-category thresholds for --fail: a severity (critical, high,\n\
+              medium, low) or \"never\". Unset categories fail on any finding\n\
+              shown after --min-severity.",
+        array: false,
+        settings: &[
+            optional("text", "", "\"high\""),
+            optional("structural", "", "\"never\""),
+            optional("code", "", "\"medium\""),
+            optional("user", "", "\"low\""),
+        ],
+    },
+    SectionDoc {
+        name: "severity_overrides",
+        doc: "Severity for built-in rules, by rule id (see `unai rules doc`):\n\
+              critical, high, medium, or low.",
+        array: false,
+        settings: &[optional("\"text/robust\"", "", "\"low\"")],
+    },
+    SectionDoc {
+        name: "detector",
+        doc: "Extra hints for --mode auto.",
+        array: false,
+        settings: &[setting(
+            "commit_globs",
+            "Globs for files that hold commit messages, matched against the path\n\
+             given on the command line.",
+        )],
+    },
+    SectionDoc {
+        name: "whitespace",
+        doc: "Whitespace cleanup.",
+        array: false,
+        settings: &[setting(
+            "max_blank_lines",
+            "Longest run of blank lines kept; at least 1.",
+        )],
+    },
+    SectionDoc {
+        name: "punctuation",
+        doc: "Typographic punctuation. Curly quotes and the ellipsis character\n\
+              are always fixed to their ASCII forms.",
+        array: false,
+        settings: &[
+            setting(
+                "em_dashes_per_paragraph",
+                "Em dashes between clauses a paragraph may hold; the rest are\n\
+                 flagged.",
+            ),
+            optional(
+                "em_dash_fix",
+                "comma or hyphen: what a clean run turns a flagged em dash into.\n\
+                 Flag only when unset.",
+                "\"comma\"",
+            ),
+        ],
+    },
+    SectionDoc {
+        name: "input",
+        doc: "Limits on what the rules analyze.",
+        array: false,
+        settings: &[
+            setting(
+                "max_line_bytes",
+                "Lines longer than this many bytes are skipped by the lexical rules\n\
+                 and reported once instead; 0 analyzes every line.",
+            ),
+            setting(
+                "scan_frontmatter",
+                "Check a leading YAML (---) or TOML (+++) frontmatter block in text\n\
+                 and Markdown mode; skipped by default.",
+            ),
+        ],
+    },
+    SectionDoc {
+        name: "commit",
+        doc: "Commit messages.",
+        array: false,
+        settings: &[setting(
+            "comment_char",
+            "Start of the comment lines git strips from a message; match\n\
+             core.commentChar. The commit rules skip them.",
+        )],
+    },
+    SectionDoc {
+        name: "output",
+        doc: "Normalization of the text unai writes out, and report limits.",
+        array: false,
+        settings: &[
+            setting(
+                "final_newline",
+                "preserve (keep the input's), always, or never.",
+            ),
+            setting(
+                "line_endings",
+                "preserve (each line's own ending), lf, or crlf.",
+            ),
+            setting(
+                "max_matched_len",
+                "Longest matched text shown in reports, in chars; 0 shows it in full.",
+            ),
+        ],
+    },
+    SectionDoc {
+        name: "code",
+        doc: "Code mode; each mirrors the CLI flag of the same name.",
+        array: false,
+        settings: &[
+            setting("check_strings", "Run the text rules on string literals."),
+            setting("fix_strings", "Also rewrite string literals when cleaning."),
+        ],
+    },
+    SectionDoc {
+        name: "structural",
+        doc: "Structural rules for text mode. Short documents are left alone:\n\
+              each rule waits until the document has enough prose to judge.",
+        array: false,
+        settings: &[
+            optional(
+                "first_person_plural_max",
+                "Flag a section when more than this fraction (0.0-1.0) of its\n\
+                 sentences open with \"we\" or \"our\". Off when unset.",
+                "0.5",
+            ),
+            setting(
+                "connector_min_words",
+                "Prose words before connector density is checked.",
+            ),
+            setting(
+                "connectors_per_100_words",
+                "Connectors (moreover, furthermore...) per 100 words that make a\n\
+                 paragraph dense; it also needs at least three.",
+            ),
+            setting(
+                "uniformity_min_sentences",
+                "Prose sentences before sentence-length uniformity is checked.",
+            ),
+            setting(
+                "conclusion_min_words",
+                "Prose words before \"In summary\" paragraphs are checked.",
+            ),
+            setting(
+                "repetition_min_words",
+                "Prose words before duplicate sentences and repeated sentence\n\
+                 openings are checked.",
+            ),
+            setting(
+                "heading_min_lines",
+                "Non-empty lines before the headings are judged against the\n\
+                 prose.",
+            ),
+            setting(
+                "lines_per_heading",
+                "Flag a document with a heading for fewer than this many\n\
+                 lines of prose.",
+            ),
+        ],
+    },
+    SectionDoc {
+        name: "rules",
+        doc: "Your own patterns, flagged alongside the built-in rules; one table\n\
+              per pattern.",
+        array: true,
+        settings: &[
+            optional(
+                "id",
+                "Rule id in reports and JSON; user/<pattern> when unset.",
+                "\"user/synergy\"",
+            ),
+            optional(
+                "pattern",
+                "Text to match, as a whole word; a regular expression with `regex`.",
+                "\"synergize\"",
+            ),
+            optional(
+                "replacement",
+                "Text a clean run puts in its place, with $1 for regex groups;\n\
+                 flag only when unset.",
+                "\"work together\"",
+            ),
+            optional(
+                "severity",
+                "critical, high, medium, or low; low when unset.",
+                "\"high\"",
+            ),
+            optional("message", "Shown in the report.", "\"Corporate jargon\""),
+            optional("enabled", "Set to false to turn the rule off.", "true"),
+            optional(
+                "case_sensitive",
+                "Match `pattern` with its exact case.",
+                "false",
+            ),
+            optional("regex", "Read `pattern` as a regular expression.", "false"),
+        ],
+    },
+    SectionDoc {
+        name: "allow",
+        doc: "Phrases that are correct as written: findings inside them are\n\
+              suppressed. One table per phrase.",
+        array: true,
+        settings: &[optional(
+            "phrase",
+            "Text to allow.",
+            "\"comprehensive income\"",
+        )],
+    },
+];
+
+/// The default configuration as commented TOML, as printed by
+/// `--print-default-config` and written by `unai config init`. Values come
+/// from serializing `Config::default()`, so they match what parsing yields;
+/// settings with no default are shown commented out.
+pub fn default_config_toml() -> String {
+    let defaults = toml::Table::try_from(Config::default()).unwrap_or_default();
+    let mut out = String::from(
+        "# unai configuration. Settings are shown at their defaults; those\n\
+         # without one are commented out. unai reads ./unai.toml, or the file\n\
+         # given with --config.\n\n",
+    );
+    out.push_str(&format!("version = {}\n", Config::default().version));
+    out.push('\n');
+    push_comment(
+        &mut out,
+        "Built-in rules that do not run, by id (see `unai rules doc`).",
+    );
+    out.push_str("# disabled_rules = [\"text/robust\", \"commit/title-case\"]\n");
+    for section in SECTIONS {
+        out.push('\n');
+        push_comment(&mut out, section.doc);
+        let values = defaults.get(section.name).and_then(toml::Value::as_table);
+        if section.array {
+            out.push_str(&format!("# [[{}]]\n", section.name));
+        } else {
+            out.push_str(&format!("[{}]\n", section.name));
+        }
+        for setting in section.settings {
+            push_comment(&mut out, setting.doc);
+            let value = values.and_then(|t| t.get(setting.key));
+            match (value, setting.example) {
+                (Some(value), _) if !section.array => {
+                    out.push_str(&format!("{} = {}\n", setting.key, value));
+                }
+                (_, Some(example)) => {
+                    out.push_str(&format!("# {} = {}\n", setting.key, example));
+                }
+                (_, None) => {}
+            }
+        }
+    }
+    out
+}
+
+fn push_comment(out: &mut String, doc: &str) {
+    for line in doc.lines() {
+        out.push_str(&format!("# {}\n", line.trim()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    // Shared lock for tests that mutate the process working directory.
+    // Both `missing_file_returns_none` and `load_from_cwd_success` must hold
+    // this lock to prevent races when Cargo runs tests in parallel.
+    static CWD_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+    fn write_temp_config(content: &str) -> tempfile::NamedTempFile {
+        let mut f = tempfile::NamedTempFile::new().unwrap();
+        f.write_all(content.as_bytes()).unwrap();
+        f
+    }
+
+    #[test]
+    fn parse_minimal_config() {
+        let f = write_temp_config("version = 1\n");
+        let config = Config::load(f.path()).unwrap();
+        assert_eq!(config.version, 1);
+        assert!(config.rules.is_empty());
+        assert!(config.ignore.words.is_empty());
+        assert!(config.ignore.files.is_empty());
+    }
+
+    #[test]
+    fn parse_rules_list() {
+        let toml = r#"
+version = 1
+
+[[rules]]
+pattern = "synergize"
+replacement = "work together"
+severity = "high"
+message = "Corporate jargon"
+
+[[rules]]
+pattern = "robust"
+enabled = false
+"#;
+        let f = write_temp_config(toml);
+        let config = Config::load(f.path()).unwrap();
+        assert_eq!(config.rules.len(), 2);
+        assert_eq!(config.rules[0].pattern, "synergize");
+        assert_eq!(
+            config.rules[0].replacement.as_deref(),
+            Some("work together")
+        );
+        assert_eq!(config.rules[0].severity.as_deref(), Some("high"));
+        assert_eq!(config.rules[1].pattern, "robust");
+        assert!(!config.rules[1].enabled);
+    }
+
+    #[test]
+    fn parse_ignore_section() {
+        let toml = r#"
+version = 1
+
+[ignore]
+words = ["robust", "comprehensive"]
+files = ["docs/examples/**", "test/fixtures/**"]
+"#;
+        let f = write_temp_config(toml);
+        let config = Config::load(f.path()).unwrap();
+        assert_eq!(config.ignore.words, vec!["robust", "comprehensive"]);
+        assert_eq!(
+            config.ignore.files,
+            vec!["docs/examples/**", "test/fixtures/**"]
+        );
+    }
+
+    #[test]
+    fn ignore_word_key_honors_case_sensitive() {
+        let mut ignore = IgnoreConfig::default();
+        assert_eq!(ignore.word_key("Delve"), "delve");
+        ignore.case_sensitive = true;
+        assert_eq!(ignore.word_key("Delve"), "Delve");
+    }
+
+    #[test]
+    fn warns_on_case_variant_of_builtin_when_case_sensitive() {
+        let toml = "version = 1\n[ignore]\nwords = [\"Delve\", \"delve\", \"widget\"]\n";
+        let f = write_temp_config(toml);
+        assert!(Config::load(f.path()).unwrap().warnings().is_empty());
+        let f = write_temp_config(&format!("{toml}case_sensitive = true\n"));
+        let warnings = Config::load(f.path()).unwrap().warnings();
+        assert_eq!(warnings.len(), 1, "{warnings:?}");
+        assert!(warnings[0].contains("'Delve'"), "{warnings:?}");
+    }
+
+    #[test]
+    fn parse_fail_section() {
+        let toml = r#"
+version = 1
+
+[fail]
+text = "high"
+structural = "never"
+"#;
+        let f = write_temp_config(toml);
+        let config = Config::load(f.path()).unwrap();
+        assert_eq!(config.fail.text.as_deref(), Some("high"));
+        assert_eq!(config.fail.structural.as_deref(), Some("never"));
+        assert!(config.fail.user.is_none());
+    }
+
+    #[test]
+    fn unknown_fail_threshold_rejected() {
+        let toml = "version = 1\n[fail]\nuser = \"sometimes\"\n";
+        let f = write_temp_config(toml);
+        let err = Config::load(f.path()).unwrap_err();
+        assert!(err.to_string().contains("fail threshold"), "got: {err}");
+    }
+
+    #[test]
+    fn parse_detector_section() {
+        let toml = "version = 1\n[detector]\ncommit_globs = [\"release-notes/*.txt\"]\n";
+        let f = write_temp_config(toml);
+        let config = Config::load(f.path()).unwrap();
+        assert!(config.detector.is_commit_file("release-notes/v1.txt"));
+        assert!(!config.detector.is_commit_file("docs/v1.txt"));
+    }
+
+    #[test]
+    fn commit_globs_match_backslash_paths() {
+        let toml = "version = 1\n[detector]\ncommit_globs = [\"release-notes/**\"]\n";
+        let f = write_temp_config(toml);
+        let config = Config::load(f.path()).unwrap();
+        assert!(config
+            .detector
+            .is_commit_file("release-notes\\2024\\v1.txt"));
+        assert!(!config.detector.is_commit_file("docs\\release-notes.txt"));
+        assert_eq!(slash_path("a\\b/c"), "a/b/c");
+        assert_eq!(slash_path("a/b"), "a/b");
+    }
+
+    #[test]
+    fn invalid_commit_glob_rejected() {
+        let toml = "version = 1\n[detector]\ncommit_globs = [\"drafts/[abc\"]\n";
+        let f = write_temp_config(toml);
+        let err = Config::load(f.path()).unwrap_err();
+        assert!(err.to_string().contains("invalid glob"), "got: {err}");
+    }
+
+    #[test]
+    fn parse_whitespace_section() {
+        let f = write_temp_config("version = 1\n");
+        assert_eq!(
+            Config::load(f.path()).unwrap().whitespace.max_blank_lines,
+            2
+        );
+        let f = write_temp_config("version = 1\n[whitespace]\nmax_blank_lines = 1\n");
+        assert_eq!(
+            Config::load(f.path()).unwrap().whitespace.max_blank_lines,
+            1
+        );
+        let f = write_temp_config("version = 1\n[whitespace]\nmax_blank_lines = 0\n");
+        let err = Config::load(f.path()).unwrap_err();
+        assert!(err.to_string().contains("at least 1"), "got: {err}");
+    }
+
+    #[test]
+    fn parse_punctuation_section() {
+        let f = write_temp_config("version = 1\n");
+        assert_eq!(
+            Config::load(f.path()).unwrap().punctuation,
+            PunctuationConfig::default()
+        );
+        let f = write_temp_config(
+            "version = 1\n[punctuation]\nem_dashes_per_paragraph = 0\nem_dash_fix = \"hyphen\"\n",
+        );
+        let punctuation = Config::load(f.path()).unwrap().punctuation;
+        assert_eq!(punctuation.em_dashes_per_paragraph, 0);
+        assert_eq!(punctuation.em_dash_fix, Some(EmDashFix::Hyphen));
+        let f = write_temp_config("version = 1\n[punctuation]\nem_dash_fix = \"colon\"\n");
+        assert!(Config::load(f.path()).is_err());
+    }
+
+    #[test]
+    fn parse_commit_section() {
+        let f = write_temp_config("version = 1\n");
+        assert_eq!(Config::load(f.path()).unwrap().commit.comment_char, "#");
+        let f = write_temp_config("version = 1\n[commit]\ncomment_char = \";\"\n");
+        assert_eq!(Config::load(f.path()).unwrap().commit.comment_char, ";");
+        let f = write_temp_config("version = 1\n[commit]\ncomment_char = \"\"\n");
+        let err = Config::load(f.path()).unwrap_err();
+        assert!(
+            err.to_string().contains("commit.comment_char"),
+            "got: {err}"
+        );
+    }
+
+    #[test]
+    fn parse_allow_phrases() {
+        let toml = "version = 1\n[[allow]]\nphrase = \"comprehensive income\"\n[[allow]]\nphrase = \"robust standard errors\"\n";
+        let f = write_temp_config(toml);
+        let config = Config::load(f.path()).unwrap();
+        let phrases: Vec<&str> = config.allow.iter().map(|a| a.phrase.as_str()).collect();
+        assert_eq!(phrases, ["comprehensive income", "robust standard errors"]);
+        let f = write_temp_config("version = 1\n[[allow]]\nphrase = \" \"\n");
+        assert!(Config::load(f.path()).is_err());
+    }
+
+    #[test]
+    fn parse_input_section() {
+        let f = write_temp_config("version = 1\n");
+        assert_eq!(Config::load(f.path()).unwrap().input.max_line_bytes, 10_000);
+        let f = write_temp_config("version = 1\n[input]\nmax_line_bytes = 0\n");
+        assert_eq!(Config::load(f.path()).unwrap().input.max_line_bytes, 0);
+        assert!(!Config::load(f.path()).unwrap().input.scan_frontmatter);
+        let f = write_temp_config("version = 1\n[input]\nscan_frontmatter = true\n");
+        assert!(Config::load(f.path()).unwrap().input.scan_frontmatter);
+    }
+
+    #[test]
+    fn parse_output_section() {
+        let toml = "version = 1\n[output]\nfinal_newline = \"always\"\nline_endings = \"crlf\"\n";
+        let f = write_temp_config(toml);
+        let output = Config::load(f.path()).unwrap().output;
+        assert_eq!(output.final_newline, FinalNewline::Always);
+        assert_eq!(output.line_endings, LineEndings::Crlf);
+        assert_eq!(output.max_matched_len, 200);
+        let f = write_temp_config("version = 1\n[output]\nline_endings = \"cr\"\n");
+        assert!(Config::load(f.path()).is_err());
+    }
+
+    #[test]
+    fn first_person_plural_max_out_of_range_rejected() {
+        let toml = "version = 1\n[structural]\nfirst_person_plural_max = 1.5\n";
+        let f = write_temp_config(toml);
+        let err = Config::load(f.path()).unwrap_err();
+        assert!(err.to_string().contains("between 0 and 1"), "got: {err}");
+    }
+
+    #[test]
+    fn structural_limits_read_and_validated() {
+        let toml =
+            "version = 1\n[structural]\nconnector_min_words = 0\nconnectors_per_100_words = 5.5\n";
+        let cfg = Config::load(write_temp_config(toml).path()).unwrap();
+        let limits = cfg.structural.limits();
+        assert_eq!(limits.connector_min_words, 0);
+        assert_eq!(limits.connectors_per_100_words, 5.5);
+        assert_eq!(
+            limits.uniformity_min_sentences,
+            StructuralLimits::default().uniformity_min_sentences
+        );
+
+        let toml = "version = 1\n[structural]\nconnectors_per_100_words = -1.0\n";
+        let err = Config::load(write_temp_config(toml).path()).unwrap_err();
+        assert!(err.to_string().contains("non-negative"), "got: {err}");
+
+        let toml = "version = 1\n[structural]\nheading_min_lines = 5\nlines_per_heading = 2.5\n";
+        let limits = Config::load(write_temp_config(toml).path())
+            .unwrap()
+            .structural
+            .limits();
+        assert_eq!(
+            (limits.heading_min_lines, limits.lines_per_heading),
+            (5, 2.5)
+        );
+        let toml = "version = 1\n[structural]\nlines_per_heading = -1.0\n";
+        let err = Config::load(write_temp_config(toml).path()).unwrap_err();
+        assert!(err.to_string().contains("lines_per_heading"), "got: {err}");
+    }
+
+    #[test]
+    fn invalid_version_returns_error() {
+        let f = write_temp_config("version = 99\n");
+        let err = Config::load(f.path()).unwrap_err();
+        let msg = err.to_string();
+        assert!(
+            msg.contains("unsupported version 99"),
+            "expected ConfigInvalid, got: {msg}"
+        );
+    }
+
+    #[test]
+    fn missing_file_returns_none() {
+        // load_from_cwd looks for ./unai.toml; run from a temp dir where it won't exist.
+        let _lock = CWD_LOCK.lock().unwrap();
+        let tmp = tempfile::tempdir().unwrap();
+        let original = std::env::current_dir().unwrap();
+        std::env::set_current_dir(tmp.path()).unwrap();
+        let result = Config::load_from_cwd();
+        std::env::set_current_dir(original).unwrap();
+        assert!(result.unwrap().is_none());
+    }
+
+    #[test]
+    fn empty_pattern_rejected() {
+        let toml = "version = 1\n[[rules]]\npattern = \"\"\n";
+        let f = write_temp_config(toml);
+        let err = Config::load(f.path()).unwrap_err();
+        assert!(err.to_string().contains("empty"), "got: {err}");
+    }
+
+    #[test]
+    fn rule_id_with_space_rejected() {
+        let toml = "version = 1\n[[rules]]\nid = \"house style\"\npattern = \"synergy\"\n";
+        let f = write_temp_config(toml);
+        let err = Config::load(f.path()).unwrap_err();
+        assert!(err.to_string().contains("rule id"), "got: {err}");
+    }
+
+    #[test]
+    fn invalid_rule_regex_rejected() {
+        let toml = "version = 1\n[[rules]]\npattern = \"synerg(y\"\nregex = true\n";
+        let f = write_temp_config(toml);
+        let err = Config::load(f.path()).unwrap_err().to_string();
+        assert!(err.contains("invalid regex 'synerg(y'"), "got: {err}");
+        assert!(err.contains("unclosed group"), "got: {err}");
+    }
+
+    #[test]
+    fn unknown_severity_rejected() {
+        let toml = "version = 1\n[[rules]]\npattern = \"synergize\"\nseverity = \"ultra\"\n";
+        let f = write_temp_config(toml);
+        let err = Config::load(f.path()).unwrap_err();
+        assert!(err.to_string().contains("unknown severity"), "got: {err}");
+    }
+
+    #[test]
+    fn config_too_large_rejected() {
+        let mut f = tempfile::NamedTempFile::new().unwrap();
+        // Write 1 MiB + 1 byte
+        let data = vec![b'#'; 1024 * 1024 + 1];
+        f.write_all(&data).unwrap();
+        let err = Config::load(f.path()).unwrap_err();
+        let msg = err.to_string();
+        assert!(
+            msg.contains("1 MiB") || msg.contains("size limit") || msg.contains("too large"),
+            "got: {msg}"
+        );
+    }
+
+    // Whitespace-only patterns are also rejected — they would match on every line.
+    #[test]
+    fn whitespace_only_pattern_rejected() {
+        let toml = "version = 1\n[[rules]]\npattern = \"   \"\n";
+        let f = write_temp_config(toml);
+        let err = Config::load(f.path()).unwrap_err();
+        assert!(err.to_string().contains("empty"), "got: {err}");
+    }
+
+    // load_from_cwd success path — finds and loads a valid unai.toml from the working directory.
+    #[test]
+    fn load_from_cwd_success() {
+        let _guard = CWD_LOCK.lock().unwrap();
+
+        let tmp = tempfile::tempdir().unwrap();
+        let original = std::env::current_dir().unwrap();
+        write_temp_config("version = 1\n")
+            .persist(tmp.path().join("unai.toml"))
+            .unwrap();
+        std::env::set_current_dir(tmp.path()).unwrap();
+        let result = Config::load_from_cwd();
+        std::env::set_current_dir(original).unwrap();
+        let cfg = result.unwrap().expect("should load the config");
+        assert_eq!(cfg.version, 1);
+    }
+
+    #[test]
+    fn default_config_round_trips() {
+        let f = write_temp_config(&default_config_toml());
+        assert_eq!(Config::load(f.path()).unwrap(), Config::default());
+    }
+
+    #[test]
+    fn default_config_documents_every_setting() {
+        let printed = default_config_toml();
+        let defaults = toml::Table::try_from(Config::default()).unwrap();
+        for (name, value) in &defaults {
+            let Some(table) = value.as_table() else {
+                continue;
+            };
+            let section = SECTIONS.iter().find(|s| s.name == name);
+            let section = section.unwrap_or_else(|| panic!("[{name}] is not documented"));
+            for key in table.keys() {
+                assert!(
+                    section.settings.iter().any(|s| s.key == key),
+                    "{name}.{key} is not documented"
+                );
+            }
+        }
+        // Uncommenting every example still parses, and sets each optional
+        // setting to a field that exists.
+        let uncommented: String = printed
+            .lines()
+            .map(|line| match line.strip_prefix("# ") {
+                Some(rest) if rest.starts_with("[[") || rest.contains(" = ") => rest,
+                _ => line,
+            })
+            .map(|line| format!("{line}\n"))
+            .collect();
+        let f = write_temp_config(&uncommented);
+        let loaded = Config::load(f.path()).unwrap();
+        let loaded = toml::Table::try_from(loaded).unwrap();
+        for section in SECTIONS {
+            let table = match &loaded[section.name] {
+                toml::Value::Array(entries) => entries[0].as_table().unwrap(),
+                value => value.as_table().unwrap(),
+            };
+            for setting in section.settings {
+                assert!(
+                    table.contains_key(setting.key.trim_matches('"')),
+                    "{}.{} is not a config field",
+                    section.name,
+                    setting.key
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,352 @@
+/// Mode of content being processed.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Mode {
+    Text,
+    /// Markdown: prose outside fenced code blocks, code inside them.
+    Markdown,
+    Code,
+    #[serde(rename = "commit")]
+    CommitMsg,
+}
+
+impl Mode {
+    pub const ALL: &'static [Mode] = &[Mode::Text, Mode::Markdown, Mode::Code, Mode::CommitMsg];
+
+    /// The name used for the mode in JSON and on the command line.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Mode::Text => "text",
+            Mode::Markdown => "markdown",
+            Mode::Code => "code",
+            Mode::CommitMsg => "commit",
+        }
+    }
+}
+
+impl std::str::FromStr for Mode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Mode::ALL
+            .iter()
+            .find(|m| m.label() == s)
+            .cloned()
+            .ok_or_else(|| format!("unknown mode '{s}'; valid: text, markdown, code, commit"))
+    }
+}
+
+const CODE_EXTENSIONS: &[&str] = &[
+    "py", "ts", "tsx", "js", "jsx", "rs", "go", "java", "kt", "swift", "c", "cpp", "h", "hpp",
+    "cs", "rb", "php", "sh", "bash", "zsh", "fish", "lua", "r", "scala", "hs", "ml", "ex", "exs",
+    "clj", "cljs", "dart", "nim", "zig",
+];
+
+/// Source files known by name rather than extension.
+const CODE_FILENAMES: &[&str] = &[
+    "Makefile",
+    "makefile",
+    "GNUmakefile",
+    "Dockerfile",
+    "Containerfile",
+    "Justfile",
+    "justfile",
+];
+
+/// Interpreters whose `#!` line marks a script as code, without any version
+/// suffix (`python3.12` is `python`).
+const SHEBANG_INTERPRETERS: &[&str] = &[
+    "bash", "sh", "zsh", "dash", "ksh", "fish", "python", "node", "deno", "ruby", "perl", "php",
+    "lua",
+];
+
+const MARKDOWN_EXTENSIONS: &[&str] = &["md", "mdx", "markdown"];
+
+const CODE_CONTENT_SIGNALS: &[&str] = &[
+    "def ",
+    "fn ",
+    "func ",
+    "function ",
+    "class ",
+    "import ",
+    "from ",
+    "use ",
+    "mod ",
+    "const ",
+    "let ",
+    "var ",
+    "type ",
+    "interface ",
+    "struct ",
+    "enum ",
+    "impl ",
+    "pub fn",
+    "async fn",
+    "pub struct",
+    "pub enum",
+    "pub trait",
+    "#include",
+    "package ",
+    "namespace ",
+];
+
+/// Why a mode was chosen, reported in the JSON `run` object and by `--verbose`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DetectionReason {
+    /// Set with `--mode`.
+    Flag,
+    /// Matched a `[detector] commit_files` pattern in the config.
+    ConfigPattern,
+    /// Set by the `mode` key of the file's inline `<!-- unai: ... -->` comment.
+    Inline,
+    /// A git commit message file such as COMMIT_EDITMSG.
+    CommitFile(String),
+    /// A source file known by name, such as Makefile.
+    CodeFile(String),
+    /// The interpreter named on a `#!` first line.
+    Shebang(String),
+    /// A source-code or Markdown file extension, without the dot.
+    Extension(String),
+    /// Number of code signals found in the content sample.
+    Signals(usize),
+}
+
+impl std::fmt::Display for DetectionReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Flag => write!(f, "flag:--mode"),
+            Self::ConfigPattern => write!(f, "config:commit_files"),
+            Self::Inline => write!(f, "inline:mode"),
+            Self::CommitFile(name) | Self::CodeFile(name) => write!(f, "filename:{name}"),
+            Self::Shebang(interpreter) => write!(f, "shebang:{interpreter}"),
+            Self::Extension(ext) => write!(f, "extension:.{ext}"),
+            Self::Signals(count) => write!(f, "signals:{count}"),
+        }
+    }
+}
+
+/// Mode for a file named `filename` holding `content`, and why.
+pub fn detect_mode(filename: Option<&str>, content: &str) -> (Mode, DetectionReason) {
+    if let Some(name) = filename {
+        let base = name.rsplit(['/', '\\']).next().unwrap_or(name);
+        if is_commit_msg_file(name) {
+            return (
+                Mode::CommitMsg,
+                DetectionReason::CommitFile(base.to_string()),
+            );
+        }
+        if CODE_FILENAMES.contains(&base) {
+            return (Mode::Code, DetectionReason::CodeFile(base.to_string()));
+        }
+        if let Some(ext) = extension_of(name) {
+            let lower = ext.to_lowercase();
+            if CODE_EXTENSIONS.contains(&lower.as_str()) {
+                return (Mode::Code, DetectionReason::Extension(ext.to_string()));
+            }
+            if MARKDOWN_EXTENSIONS.contains(&lower.as_str()) {
+                return (Mode::Markdown, DetectionReason::Extension(ext.to_string()));
+            }
+        }
+    }
+
+    detect_from_content(content)
+}
+
+pub fn is_commit_msg_file(filename: &str) -> bool {
+    // Split on both separators: `Path` only knows `\` on Windows.
+    let base = filename.rsplit(['/', '\\']).next().unwrap_or(filename);
+    base == "COMMIT_EDITMSG" || base == "MERGE_MSG" || base == "SQUASH_MSG"
+}
+
+fn extension_of(filename: &str) -> Option<&str> {
+    std::path::Path::new(filename)
+        .extension()
+        .and_then(|e| e.to_str())
+}
+
+/// The interpreter of a `#!` first line, through `env` if need be, when it
+/// is one of [`SHEBANG_INTERPRETERS`].
+fn shebang_interpreter(content: &str) -> Option<&str> {
+    let line = content.lines().next()?.strip_prefix("#!")?;
+    let mut words = line.split_whitespace();
+    let mut program = words.next()?.rsplit('/').next()?;
+    if program == "env" {
+        // `#!/usr/bin/env -S python3 -u`: the first word that is not a flag.
+        program = words.find(|w| !w.starts_with('-'))?;
+    }
+    let name = program.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.');
+    SHEBANG_INTERPRETERS.contains(&name).then_some(program)
+}
+
+fn detect_from_content(content: &str) -> (Mode, DetectionReason) {
+    if let Some(interpreter) = shebang_interpreter(content) {
+        return (
+            Mode::Code,
+            DetectionReason::Shebang(interpreter.to_string()),
+        );
+    }
+    // Sample the first 50 non-empty lines for efficiency on large files.
+    let sample: String = content
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .take(50)
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let signal_count = CODE_CONTENT_SIGNALS
+        .iter()
+        .filter(|&&sig| sample.contains(sig))
+        .count();
+
+    let mode = if signal_count >= 2 {
+        Mode::Code
+    } else {
+        Mode::Text
+    };
+    (mode, DetectionReason::Signals(signal_count))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_code_by_extension() {
+        assert_eq!(detect_mode(Some("main.rs"), "hello world").0, Mode::Code);
+        assert_eq!(detect_mode(Some("script.py"), "hello world").0, Mode::Code);
+        assert_eq!(detect_mode(Some("app.ts"), "hello world").0, Mode::Code);
+    }
+
+    #[test]
+    fn detects_text_by_extension_absence() {
+        assert_eq!(detect_mode(Some("notes.txt"), "hello world").0, Mode::Text);
+    }
+
+    #[test]
+    fn detects_markdown_by_extension() {
+        for name in ["README.md", "docs/page.mdx", "CHANGES.Markdown"] {
+            assert_eq!(
+                detect_mode(
+                    Some(name),
+                    "use std::io;
+fn main() {}"
+                )
+                .0,
+                Mode::Markdown
+            );
+        }
+    }
+
+    #[test]
+    fn detects_code_by_content_signals() {
+        let python = "def foo():\n    import os\n    return True";
+        assert_eq!(detect_mode(None, python).0, Mode::Code);
+    }
+
+    #[test]
+    fn detects_text_without_signals() {
+        let prose = "This is a blog post about dogs. Dogs are great.";
+        assert_eq!(detect_mode(None, prose).0, Mode::Text);
+    }
+
+    #[test]
+    fn detects_scripts_by_shebang() {
+        let script = "#!/usr/bin/env python3\nprint('Deploying the release now.')\n";
+        assert_eq!(
+            detect_mode(Some("bin/deploy"), script),
+            (Mode::Code, DetectionReason::Shebang("python3".to_string()))
+        );
+        for first in [
+            "#!/bin/bash",
+            "#!/usr/bin/env -S node --no-warnings",
+            "#!/usr/bin/perl -w",
+        ] {
+            let content = format!("{first}\necho hello\n");
+            assert_eq!(detect_mode(None, &content).0, Mode::Code, "{first}");
+        }
+        assert_eq!(
+            detect_mode(None, "#!/usr/bin/awk -f\nhello\n").0,
+            Mode::Text
+        );
+        assert_eq!(detect_mode(None, "Hello\n#!/bin/sh\n").0, Mode::Text);
+    }
+
+    #[test]
+    fn detects_code_by_filename() {
+        assert_eq!(
+            detect_mode(Some("build/Makefile"), "all:\n\techo done\n"),
+            (
+                Mode::Code,
+                DetectionReason::CodeFile("Makefile".to_string())
+            )
+        );
+        assert_eq!(detect_mode(Some("Dockerfile"), "FROM rust").0, Mode::Code);
+        assert_eq!(
+            detect_mode(Some("justfile"), "test:\n  cargo test").0,
+            Mode::Code
+        );
+    }
+
+    #[test]
+    fn commit_msg_file_is_commit_msg() {
+        assert_eq!(
+            detect_mode(Some("COMMIT_EDITMSG"), "feat: add thing").0,
+            Mode::CommitMsg
+        );
+        assert_eq!(
+            detect_mode(Some("MERGE_MSG"), "Merge branch foo").0,
+            Mode::CommitMsg
+        );
+    }
+
+    #[test]
+    fn detection_reasons() {
+        assert_eq!(
+            detect_mode(Some("src/main.rs"), "").1.to_string(),
+            "extension:.rs"
+        );
+        assert_eq!(
+            detect_mode(Some(".git/COMMIT_EDITMSG"), "").1.to_string(),
+            "filename:COMMIT_EDITMSG"
+        );
+        let python = "def foo():\n    import os\n    return True";
+        assert_eq!(
+            detect_mode(None, python),
+            (Mode::Code, DetectionReason::Signals(2))
+        );
+    }
+
+    #[test]
+    fn commit_msg_file_with_windows_separators() {
+        assert!(is_commit_msg_file(".git\\COMMIT_EDITMSG"));
+        assert!(is_commit_msg_file("C:\\repo\\.git\\MERGE_MSG"));
+        assert!(!is_commit_msg_file("docs\\COMMIT_EDITMSG.md"));
+    }
+
+    #[test]
+    fn never_infer_commit_mode_from_content() {
+        // This was the bug: short first line triggered commit mode
+        assert_eq!(detect_mode(None, "Of course!").0, Mode::Text);
+        assert_eq!(
+            detect_mode(None, "Of course! Let me help you.").0,
+            Mode::Text
+        );
+        assert_eq!(detect_mode(None, "wip").0, Mode::Text);
+    }
+
+    #[test]
+    fn commit_mode_only_by_filename() {
+        assert_eq!(
+            detect_mode(Some("COMMIT_EDITMSG"), "anything").0,
+            Mode::CommitMsg
+        );
+        assert_eq!(
+            detect_mode(Some("MERGE_MSG"), "anything").0,
+            Mode::CommitMsg
+        );
+        assert_eq!(
+            detect_mode(Some("SQUASH_MSG"), "anything").0,
+            Mode::CommitMsg
+        );
+    }
+}
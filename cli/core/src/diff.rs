@@ -60,4 +60,53 @@ mod tests {
     fn diff_empty_inputs() {
         assert_eq!(unified_diff("", "", "a", "b"), "");
     }
+
+    #[test]
+    fn diff_marks_missing_final_newline() {
+        let diff = unified_diff("a\nutilize b", "a\nuse b\n", "original", "cleaned");
+        assert!(
+            diff.ends_with("-utilize b\n\\ No newline at end of file\n+use b\n"),
+            "{diff}"
+        );
+    }
+
+    #[test]
+    fn diff_crlf_shows_only_changed_lines() {
+        let orig = "line 1\r\nWe should utilize this.\r\nline 3\r\n";
+        let modified = "line 1\r\nWe should use this.\r\nline 3\r\n";
+        let diff = unified_diff(orig, modified, "original", "cleaned");
+        let changed: Vec<&str> = diff
+            .split('\n')
+            .filter(|l| l.starts_with(['-', '+']) && !l.starts_with("---") && !l.starts_with("+++"))
+            .collect();
+        assert_eq!(
+            changed,
+            ["-We should utilize this.\r", "+We should use this.\r"]
+        );
+    }
+
+    #[test]
+    fn diff_large_inputs_with_few_changes() {
+        let orig: String = (0..100_000).map(|i| format!("line {i}\n")).collect();
+        let modified = orig
+            .replace("line 10\n", "line ten\n")
+            .replace("line 50000\n", "line fifty thousand\n")
+            .replace("line 99999\n", "");
+        let started = std::time::Instant::now();
+        let diff = unified_diff(&orig, &modified, "original", "cleaned");
+        assert!(
+            started.elapsed() < std::time::Duration::from_secs(10),
+            "took {:?}",
+            started.elapsed()
+        );
+        assert_eq!(diff.lines().filter(|l| l.starts_with("@@ ")).count(), 3);
+        for line in [
+            "+line ten",
+            "-line 50000",
+            "+line fifty thousand",
+            "-line 99999",
+        ] {
+            assert!(diff.contains(&format!("\n{line}\n")), "{line} missing");
+        }
+    }
 }
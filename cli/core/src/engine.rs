@@ -0,0 +1,662 @@
+//! The analysis pipeline: runs the rules a mode enables over one document,
+//! then marks what the config and ignore directives suppress.
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::path::Path;
+use std::time::Instant;
+
+use crate::config::{Config, EmDashFix};
+use crate::detector::{is_commit_msg_file, Mode};
+use crate::inline::inline_settings;
+use crate::rules::{
+    allowed_spans, apply_code_rules_until, apply_punctuation_rules, apply_string_rules,
+    apply_structural_rules_until, apply_text_rules_until, apply_user_rules, apply_whitespace_rules,
+    blank_lines, check_first_person_plural, check_headings, code_blocks, collect_ignored_lines,
+    commit_region, dedup_findings, dedup_overlapping, frontmatter_end, generated_commit_lines,
+    ignore_markers, is_ignored, mask_long_lines, registry, CodeRule, Deadline, Finding,
+    LineContext, Pass, StructuralLimits,
+};
+
+/// Lints documents with one config.
+///
+/// ```
+/// use unai_core::{clean, Config, Engine, Mode};
+///
+/// let engine = Engine::new(Config::default());
+/// let findings = engine.analyze("We should utilize this.\n", Mode::Text);
+/// assert_eq!(findings[0].rule_id, "text/utilize");
+/// assert_eq!(clean("We should utilize this.\n", &findings), "We should use this.\n");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Engine {
+    config: Config,
+    code_rules: Vec<CodeRule>,
+    deadline: Deadline,
+}
+
+/// Why a finding was filtered out of the results.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SuppressCause {
+    /// The matched text is in the config's `[ignore] words`.
+    IgnoreWord,
+    /// The match lies inside an `[[allow]]` phrase.
+    AllowPhrase,
+    /// An `unai-ignore` directive covers the line.
+    IgnoreDirective,
+    /// Below the minimum severity asked for.
+    MinSeverity,
+    /// Outside the lines a diff changed.
+    UnchangedLine,
+}
+
+impl SuppressCause {
+    pub const ALL: [SuppressCause; 5] = [
+        SuppressCause::IgnoreWord,
+        SuppressCause::AllowPhrase,
+        SuppressCause::IgnoreDirective,
+        SuppressCause::MinSeverity,
+        SuppressCause::UnchangedLine,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::IgnoreWord => "ignore-word",
+            Self::AllowPhrase => "allow-phrase",
+            Self::IgnoreDirective => "ignore-directive",
+            Self::MinSeverity => "min-severity",
+            Self::UnchangedLine => "unchanged-line",
+        }
+    }
+}
+
+/// Everything [`Engine::analysis`] found in one document.
+#[derive(Debug)]
+pub struct Analysis {
+    /// Every finding in report order, with the cause that suppresses it, if
+    /// any. The engine sets only the config and directive causes.
+    pub findings: Vec<(Finding, Option<SuppressCause>)>,
+    /// Rule ids that did not run: turned off by the config or an inline
+    /// settings comment, or not used in this mode.
+    pub disabled: HashSet<String>,
+    /// The engine's deadline passed before the analysis finished; `findings`
+    /// is empty.
+    pub timed_out: bool,
+}
+
+impl Engine {
+    /// An engine applying `config`, with every code rule category.
+    pub fn new(config: Config) -> Engine {
+        Engine {
+            config,
+            code_rules: Vec::new(),
+            deadline: Deadline::NONE,
+        }
+    }
+
+    /// Limit code mode to these categories; empty means all of them.
+    pub fn with_code_rules(mut self, code_rules: Vec<CodeRule>) -> Engine {
+        self.code_rules = code_rules;
+        self
+    }
+
+    /// Give up on a document still being analyzed at `deadline`: the rule
+    /// passes stop where they are and [`Engine::analysis`] reports it timed
+    /// out.
+    pub fn with_deadline(mut self, deadline: Instant) -> Engine {
+        self.deadline = Deadline::at(deadline);
+        self
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// The findings in `content` that nothing suppresses.
+    pub fn analyze(&self, content: &str, mode: Mode) -> Vec<Finding> {
+        self.analysis(content, &mode, None)
+            .findings
+            .into_iter()
+            .filter_map(|(f, cause)| cause.is_none().then_some(f))
+            .collect()
+    }
+
+    /// Every finding in `content`, suppressed ones included. `filename`
+    /// picks the code rules for a commit message file and the string rules'
+    /// language.
+    pub fn analysis(&self, content: &str, mode: &Mode, filename: Option<&str>) -> Analysis {
+        let cfg = &self.config;
+        let inline = inline_settings(content);
+        let rules = registry();
+        // Rules the config turns off, and those that do not apply in this mode.
+        let mut disabled: HashSet<String> = cfg.disabled_rules.iter().cloned().collect();
+        disabled.extend(inline.disabled.iter().cloned());
+        disabled.extend(
+            rules
+                .iter()
+                .filter(|r| !r.modes.contains(mode))
+                .map(|r| r.id.clone()),
+        );
+
+        // Rules see `analyzed`, where over-long lines are blank; fixes and the
+        // whitespace rules still work on `content`.
+        let (analyzed, long_lines) = mask_long_lines(content, cfg.input.max_line_bytes);
+        // Commit rules check only what the author wrote, not git's comments and logs.
+        let generated = if *mode == Mode::CommitMsg {
+            generated_commit_lines(content, &cfg.commit.comment_char)
+        } else {
+            Default::default()
+        };
+        // Frontmatter is site metadata, not prose.
+        let frontmatter = match mode {
+            Mode::Text | Mode::Markdown if !cfg.input.scan_frontmatter => {
+                frontmatter_end(content.lines()).unwrap_or(0)
+            }
+            _ => 0,
+        };
+        // The inline settings comment is not text to lint.
+        let hidden: HashSet<usize> = generated
+            .iter()
+            .copied()
+            .chain(inline.line)
+            .chain(1..=frontmatter)
+            .collect();
+        let analyzed = blank_lines(analyzed, &hidden);
+        let lines = LineContext::split(&analyzed);
+        let limits = cfg.structural.limits();
+        let mut all_findings = gather_findings(
+            &analyzed,
+            &lines,
+            mode,
+            &effective_code_rules(&self.code_rules, filename),
+            &limits,
+            &disabled,
+            self.deadline,
+        );
+        let timed_out = || Analysis {
+            findings: Vec::new(),
+            disabled: disabled.clone(),
+            timed_out: true,
+        };
+        if self.deadline.passed() {
+            return timed_out();
+        }
+        all_findings.extend(long_lines);
+        if *mode != Mode::Code {
+            let markdown = matches!(mode, Mode::Text | Mode::Markdown);
+            let mut whitespace =
+                apply_whitespace_rules(content, cfg.whitespace.max_blank_lines, markdown);
+            if *mode == Mode::CommitMsg {
+                whitespace.retain(|f| !generated.contains(&f.line));
+                for f in &mut whitespace {
+                    f.region = Some(commit_region(f.line));
+                }
+            }
+            all_findings.extend(whitespace);
+            let punctuation = &cfg.punctuation;
+            let mut typographic = apply_punctuation_rules(
+                &analyzed,
+                punctuation.em_dashes_per_paragraph,
+                punctuation.em_dash_fix.map(EmDashFix::replacement),
+                *mode == Mode::CommitMsg,
+            );
+            if *mode == Mode::CommitMsg {
+                for f in &mut typographic {
+                    f.region = Some(commit_region(f.line));
+                }
+            }
+            all_findings.extend(typographic);
+        }
+        if matches!(mode, Mode::Text | Mode::Markdown) {
+            if let Some(max) = cfg.structural.first_person_plural_max {
+                all_findings.extend(check_first_person_plural(&analyzed, max));
+            }
+        }
+        if *mode == Mode::Code && cfg.code.check_strings {
+            let extension = filename
+                .and_then(|n| Path::new(n).extension())
+                .and_then(|e| e.to_str());
+            all_findings.extend(apply_string_rules(
+                &analyzed,
+                extension,
+                cfg.code.fix_strings,
+            ));
+        }
+        if self.deadline.passed() {
+            return timed_out();
+        }
+        // The passes above that take no disabled set, such as headings and
+        // whitespace, are filtered here.
+        all_findings.retain(|f| !disabled.contains(&f.rule_id));
+        // Overrides apply before any filtering, so a minimum severity and
+        // `--fail` see the configured severity.
+        for f in &mut all_findings {
+            if let Some(severity) = cfg
+                .severity_overrides
+                .get(&f.rule_id)
+                .and_then(|s| s.parse().ok())
+            {
+                f.severity = severity;
+            }
+        }
+        all_findings.extend(apply_user_rules(&lines, Some(cfg)));
+        if self.deadline.passed() {
+            return timed_out();
+        }
+        if let Some(line) = inline.line {
+            all_findings.retain(|f| !(f.line..=f.last_line()).contains(&line));
+        }
+        let markers = ignore_markers(content);
+        all_findings.retain(|f| markers.get(&f.line).is_none_or(|&col| f.col < col));
+        dedup_findings(&mut all_findings);
+        dedup_overlapping(&mut all_findings);
+
+        let ignore = &cfg.ignore;
+        let ignored_words: HashSet<String> =
+            ignore.words.iter().map(|w| ignore.word_key(w)).collect();
+        let allow_phrases: Vec<&str> = cfg.allow.iter().map(|a| a.phrase.as_str()).collect();
+        let allowed = allowed_spans(content, &allow_phrases);
+        let ignored_lines = collect_ignored_lines(content);
+        let findings = all_findings
+            .into_iter()
+            .map(|f| {
+                let cause = if ignored_words.contains(&ignore.word_key(&f.matched)) {
+                    Some(SuppressCause::IgnoreWord)
+                } else if f.last_line() == f.line
+                    && allowed.get(&f.line).is_some_and(|spans| {
+                        spans
+                            .iter()
+                            .any(|&(start, end)| start <= f.col && f.end_col() <= end)
+                    })
+                {
+                    Some(SuppressCause::AllowPhrase)
+                } else if is_ignored(&ignored_lines, &f) {
+                    Some(SuppressCause::IgnoreDirective)
+                } else {
+                    None
+                };
+                (f, cause)
+            })
+            .collect();
+        Analysis {
+            findings,
+            disabled,
+            timed_out: false,
+        }
+    }
+}
+
+/// Built-in rule findings for `content`; `lines` is its [`LineContext::split`],
+/// folded once and shared by the per-line rule passes, and `code_rules` is
+/// the set from [`effective_code_rules`]. Once `deadline` has passed, the
+/// passes stop and what they found so far is returned.
+pub fn gather_findings(
+    content: &str,
+    lines: &[LineContext],
+    mode: &Mode,
+    code_rules: &[CodeRule],
+    limits: &StructuralLimits,
+    disabled: &HashSet<String>,
+    deadline: Deadline,
+) -> Vec<Finding> {
+    match mode {
+        Mode::Text => {
+            let mut findings = apply_text_rules_until(lines, disabled, deadline);
+            findings.extend(apply_structural_rules_until(
+                content, limits, disabled, deadline,
+            ));
+            findings.extend(check_headings(content, limits));
+            findings
+        }
+        // Splitting out the prose and the code folds the document again.
+        Mode::Markdown if deadline.passed() => Vec::new(),
+        Mode::Markdown => {
+            // Prose rules see the document with its code blocks blanked, and
+            // code rules see only the code, both at the original line numbers.
+            let blocks = code_blocks(content);
+            let code: HashSet<usize> = blocks.fences.union(&blocks.bodies).copied().collect();
+            let prose = blank_lines(Cow::Borrowed(content), &code);
+            let mut findings =
+                apply_text_rules_until(&LineContext::split(&prose), disabled, deadline);
+            findings.extend(apply_structural_rules_until(
+                &prose, limits, disabled, deadline,
+            ));
+            // Blanking and folding the code is a pass over the whole document.
+            if deadline.passed() {
+                return findings;
+            }
+            findings.extend(check_headings(&prose, limits));
+            let outside: HashSet<usize> = (1..=lines.len())
+                .filter(|line| !blocks.bodies.contains(line))
+                .collect();
+            let code = blank_lines(Cow::Borrowed(content), &outside);
+            findings.extend(apply_code_rules_until(
+                &LineContext::split(&code),
+                code_rules,
+                disabled,
+                deadline,
+            ));
+            findings
+        }
+        Mode::CommitMsg => {
+            let mut findings = apply_text_rules_until(lines, disabled, deadline);
+            findings.extend(apply_structural_rules_until(
+                content, limits, disabled, deadline,
+            ));
+            for f in &mut findings {
+                f.region = Some(commit_region(f.line));
+            }
+            findings.extend(apply_code_rules_until(
+                lines,
+                &[CodeRule::Commits],
+                disabled,
+                deadline,
+            ));
+            findings
+        }
+        Mode::Code => apply_code_rules_until(lines, code_rules, disabled, deadline),
+    }
+}
+
+/// Code rule categories code mode runs for `filename` given the `--rules`
+/// selection.
+pub fn effective_code_rules(code_rules: &[CodeRule], filename: Option<&str>) -> Vec<CodeRule> {
+    let is_commit_file = filename.map(is_commit_msg_file).unwrap_or(false);
+    // When no explicit rules are given ("all"), exclude commit-message rules for
+    // non-commit files — they produce false positives on line 1 of arbitrary code.
+    let mut effective_rules: Vec<CodeRule> = if code_rules.is_empty() {
+        vec![
+            CodeRule::Comments,
+            CodeRule::Naming,
+            CodeRule::Docstrings,
+            CodeRule::Tests,
+            CodeRule::Errors,
+            CodeRule::Api,
+        ]
+    } else {
+        code_rules.to_vec()
+    };
+    // Commit message files always get the commit rules, whatever the selection.
+    // Folding them into the one rule set runs each category once.
+    if is_commit_file && !effective_rules.contains(&CodeRule::Commits) {
+        effective_rules.push(CodeRule::Commits);
+    }
+    effective_rules
+}
+
+/// Whether the pipeline runs `pass` in `mode`; `code_rules` is the set from
+/// [`effective_code_rules`].
+pub fn pass_runs(
+    pass: &Pass,
+    mode: &Mode,
+    code_rules: &[CodeRule],
+    first_person: bool,
+    lint_config: bool,
+) -> bool {
+    match pass {
+        Pass::Suppressions => lint_config,
+        Pass::Text | Pass::Structural | Pass::Whitespace | Pass::Punctuation => *mode != Mode::Code,
+        Pass::Headings => matches!(mode, Mode::Text | Mode::Markdown),
+        Pass::FirstPerson => matches!(mode, Mode::Text | Mode::Markdown) && first_person,
+        Pass::Code(rule) => match mode {
+            Mode::Code | Mode::Markdown => code_rules.contains(rule),
+            Mode::CommitMsg => *rule == CodeRule::Commits,
+            Mode::Text => false,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::Region;
+
+    /// `gather_findings` with no explicit code rules, no filename, the default
+    /// structural limits, no disabled rules and no deadline.
+    fn gather(content: &str, mode: Mode) -> Vec<Finding> {
+        let lines = LineContext::split(content);
+        gather_findings(
+            content,
+            &lines,
+            &mode,
+            &effective_code_rules(&[], None),
+            &Default::default(),
+            &HashSet::new(),
+            Deadline::NONE,
+        )
+    }
+
+    #[test]
+    fn gather_findings_commit_file_runs_commit_rules_once() {
+        let content = "Added retry logic\n";
+        let lines = LineContext::split(content);
+        for rules in [
+            vec![CodeRule::Commits, CodeRule::Comments],
+            vec![CodeRule::Comments],
+            vec![],
+        ] {
+            let findings = gather_findings(
+                content,
+                &lines,
+                &Mode::Code,
+                &effective_code_rules(&rules, Some("COMMIT_EDITMSG")),
+                &Default::default(),
+                &HashSet::new(),
+                Deadline::NONE,
+            );
+            let past_tense = findings
+                .iter()
+                .filter(|f| f.message.starts_with("Past tense"))
+                .count();
+            assert_eq!(past_tense, 1, "rules {:?}", rules);
+        }
+    }
+
+    #[test]
+    fn gather_findings_markdown_splits_prose_and_code() {
+        let content = "# Setup\n\
+                       \n\
+                       Run the installer to utilize the cache:\n\
+                       \n\
+                       ~~~python\n\
+                       # TODO: fix this\n\
+                       x = utilize(cache)\n\
+                       ~~~\n\
+                       \n\
+                       - Then build:\n\
+                       \x20 ```sh\n\
+                       \x20 # ===== Build =====\n\
+                       \x20 make\n\
+                       \x20 ```\n";
+        let mut found: Vec<(usize, String)> = gather(content, Mode::Markdown)
+            .into_iter()
+            .map(|f| (f.line, f.rule_id))
+            .collect();
+        found.sort();
+        assert_eq!(
+            found,
+            [
+                (3, "text/utilize".to_string()),
+                (6, "code/bare-todo".to_string()),
+                (12, "code/section-header".to_string()),
+            ]
+        );
+        // Text mode runs no code rules, inside fences or out.
+        let text: Vec<String> = gather(content, Mode::Text)
+            .into_iter()
+            .map(|f| f.rule_id)
+            .collect();
+        assert!(!text.iter().any(|id| id.starts_with("code/")), "{text:?}");
+    }
+
+    #[test]
+    fn gather_findings_commit_msg_fires_commit_rules() {
+        let findings = gather("wip", Mode::CommitMsg);
+        assert!(
+            findings.iter().any(|f| f.message.contains("Vague commit")),
+            "commit rules should fire for CommitMsg mode"
+        );
+    }
+
+    #[test]
+    fn gather_findings_commit_msg_fires_both_text_and_commit_rules() {
+        let findings = gather("Added utilize to the codebase", Mode::CommitMsg);
+        assert!(
+            findings
+                .iter()
+                .any(|f| f.matched.to_lowercase().contains("utilize")),
+            "text rules should fire in CommitMsg mode"
+        );
+        assert!(
+            findings
+                .iter()
+                .any(|f| f.message.contains("imperative mood")),
+            "commit past-tense rule should fire in CommitMsg mode"
+        );
+    }
+
+    #[test]
+    fn gather_findings_commit_msg_tags_subject_and_body() {
+        let findings = gather(
+            "Added retry logic\n\nWe utilize a backoff.\n",
+            Mode::CommitMsg,
+        );
+        let region =
+            |pred: &dyn Fn(&Finding) -> bool| findings.iter().find(|f| pred(f)).unwrap().region;
+        assert_eq!(
+            region(&|f| f.message.contains("imperative mood")),
+            Some(Region::CommitSubject)
+        );
+        assert_eq!(
+            region(&|f| f.matched.to_lowercase() == "utilize"),
+            Some(Region::CommitBody)
+        );
+    }
+
+    #[test]
+    fn passed_deadline_stops_every_pass() {
+        let past = Deadline::at(Instant::now());
+        let prose = "We delve into it.\n\nMoreover, it is robust. Furthermore, it is pivotal. Additionally, it is.\n";
+        let lines = LineContext::split(prose);
+        assert!(apply_text_rules_until(&lines, &HashSet::new(), past).is_empty());
+        assert!(apply_structural_rules_until(
+            prose,
+            &StructuralLimits::NONE,
+            &HashSet::new(),
+            past
+        )
+        .is_empty());
+        let code = LineContext::split("// TODO: fix this\nlet userManager = 1;\n");
+        assert!(apply_code_rules_until(&code, &[], &HashSet::new(), past).is_empty());
+
+        let engine = Engine::new(Config::default());
+        let analysis = engine.analysis(prose, &Mode::Markdown, None);
+        assert!(!analysis.timed_out && !analysis.findings.is_empty());
+        let analysis = engine
+            .with_deadline(Instant::now())
+            .analysis(prose, &Mode::Markdown, None);
+        assert!(analysis.timed_out && analysis.findings.is_empty());
+    }
+
+    #[test]
+    fn expired_deadline_cuts_every_mode_short() {
+        let paragraph =
+            "// We delve into the robust landscape. Moreover, the tapestry is pivotal. ";
+        let doc = format!("// TODO\n{}\n\n", paragraph.repeat(4)).repeat(50);
+        let engine = Engine::new(Config::default());
+        for mode in Mode::ALL {
+            let full = engine.analysis(&doc, mode, None);
+            assert!(!full.timed_out && !full.findings.is_empty(), "{mode:?}");
+            let cut = engine
+                .clone()
+                .with_deadline(Instant::now())
+                .analysis(&doc, mode, None);
+            assert!(cut.timed_out && cut.findings.is_empty(), "{mode:?}");
+        }
+    }
+
+    #[test]
+    fn pass_runs_follows_mode_and_config() {
+        let code = effective_code_rules(&[], Some("COMMIT_EDITMSG"));
+        assert!(pass_runs(
+            &Pass::Code(CodeRule::Commits),
+            &Mode::Code,
+            &code,
+            false,
+            false
+        ));
+        let code = effective_code_rules(&[], Some("main.rs"));
+        assert!(!pass_runs(
+            &Pass::Code(CodeRule::Commits),
+            &Mode::Code,
+            &code,
+            false,
+            false
+        ));
+        assert!(pass_runs(
+            &Pass::Code(CodeRule::Commits),
+            &Mode::CommitMsg,
+            &[],
+            false,
+            false
+        ));
+        assert!(!pass_runs(
+            &Pass::Headings,
+            &Mode::CommitMsg,
+            &[],
+            false,
+            false
+        ));
+        assert!(!pass_runs(
+            &Pass::FirstPerson,
+            &Mode::Text,
+            &[],
+            false,
+            false
+        ));
+        assert!(pass_runs(&Pass::FirstPerson, &Mode::Text, &[], true, false));
+        assert!(pass_runs(
+            &Pass::Suppressions,
+            &Mode::Code,
+            &[],
+            false,
+            true
+        ));
+    }
+
+    #[test]
+    fn analysis_marks_config_suppressions() {
+        let mut config = Config::default();
+        config.ignore.words.push("utilize".to_string());
+        let analysis = Engine::new(config).analysis(
+            "We utilize it. <!-- unai-ignore -->\nWe utilize it.\nWe leverage it.\n",
+            &Mode::Text,
+            None,
+        );
+        let causes: Vec<(usize, Option<SuppressCause>)> = analysis
+            .findings
+            .iter()
+            .map(|(f, cause)| (f.line, *cause))
+            .collect();
+        assert_eq!(
+            causes,
+            [
+                (1, Some(SuppressCause::IgnoreWord)),
+                (2, Some(SuppressCause::IgnoreWord)),
+                (3, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn analyze_returns_unsuppressed_findings() {
+        let engine = Engine::new(Config::default());
+        let content = "We utilize it.\nWe leverage it. <!-- unai-ignore -->\n";
+        let ids: Vec<String> = engine
+            .analyze(content, Mode::Markdown)
+            .into_iter()
+            .map(|f| f.rule_id)
+            .collect();
+        assert_eq!(ids, ["text/utilize"]);
+    }
+}
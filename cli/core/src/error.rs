@@ -0,0 +1,24 @@
+use std::path::PathBuf;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Cannot read '{path}': {source}")]
+    FileRead {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Cannot parse config at '{path}': {source}")]
+    ConfigParse {
+        path: PathBuf,
+        #[source]
+        source: Box<toml::de::Error>,
+    },
+
+    #[error("Invalid config: {0}")]
+    ConfigInvalid(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
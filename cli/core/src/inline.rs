@@ -0,0 +1,159 @@
+//! Per-file settings from a `<!-- unai: ... -->` comment at the top of an
+//! input, for vendored or generated files whose owners cannot change the
+//! repository's unai.toml.
+
+use globset::Glob;
+
+use crate::detector::Mode;
+use crate::rules::{registry, Severity};
+
+/// How many lines from the top are searched for the comment.
+const MAX_LINES: usize = 10;
+
+/// Settings from an inline comment such as
+/// `<!-- unai: min-severity=high disable=text/robust,structural/* mode=text -->`.
+#[derive(Debug, Default, PartialEq)]
+pub struct InlineSettings {
+    /// 1-based line of the comment, if the input has one.
+    pub line: Option<usize>,
+    pub min_severity: Option<Severity>,
+    /// Built-in rule ids to turn off, with `disable` globs expanded.
+    pub disabled: Vec<String>,
+    pub mode: Option<Mode>,
+    /// Keys and values that were skipped, and why.
+    pub warnings: Vec<String>,
+}
+
+/// The settings of the first `<!-- unai: ... -->` line among the first
+/// `MAX_LINES` (10) lines of `content`. Settings are `key=value` pairs separated
+/// by spaces; an unknown key or a bad value is skipped with a warning, and the
+/// rest still apply.
+pub fn inline_settings(content: &str) -> InlineSettings {
+    let mut settings = InlineSettings::default();
+    let Some((idx, body)) = content
+        .lines()
+        .take(MAX_LINES)
+        .enumerate()
+        .find_map(|(idx, line)| {
+            let body = line
+                .trim()
+                .strip_prefix("<!-- unai:")?
+                .strip_suffix("-->")?;
+            Some((idx, body))
+        })
+    else {
+        return settings;
+    };
+    let lineno = idx + 1;
+    settings.line = Some(lineno);
+    let mut warn = |message: String| {
+        settings
+            .warnings
+            .push(format!("line {lineno}: {message} in the unai comment"))
+    };
+    let mut min_severity = None;
+    let mut disabled = Vec::new();
+    let mut mode = None;
+    for setting in body.split_whitespace() {
+        let Some((key, value)) = setting.split_once('=') else {
+            warn(format!("'{setting}' is not key=value"));
+            continue;
+        };
+        match key {
+            "min-severity" => match value.parse::<Severity>() {
+                Ok(severity) => min_severity = Some(severity),
+                Err(e) => warn(e),
+            },
+            "mode" => match value {
+                "text" => mode = Some(Mode::Text),
+                "markdown" => mode = Some(Mode::Markdown),
+                "code" => mode = Some(Mode::Code),
+                "commit" => mode = Some(Mode::CommitMsg),
+                other => warn(format!(
+                    "unknown mode '{other}'; valid: text, markdown, code, commit"
+                )),
+            },
+            "disable" => {
+                for pattern in value.split(',').filter(|p| !p.is_empty()) {
+                    let matcher = match Glob::new(pattern) {
+                        Ok(glob) => glob.compile_matcher(),
+                        Err(e) => {
+                            warn(format!("invalid rule pattern '{pattern}': {e}"));
+                            continue;
+                        }
+                    };
+                    let before = disabled.len();
+                    disabled.extend(
+                        registry()
+                            .into_iter()
+                            .filter(|rule| matcher.is_match(&rule.id))
+                            .map(|rule| rule.id),
+                    );
+                    if disabled.len() == before {
+                        warn(format!("no built-in rule matches '{pattern}'"));
+                    }
+                }
+            }
+            other => warn(format!(
+                "unknown key '{other}'; valid: min-severity, disable, mode"
+            )),
+        }
+    }
+    settings.min_severity = min_severity;
+    settings.disabled = disabled;
+    settings.mode = mode;
+    settings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_each_key() {
+        let settings = inline_settings(
+            "# Title\n<!-- unai: min-severity=high disable=text/robust mode=markdown -->\nBody\n",
+        );
+        assert_eq!(settings.line, Some(2));
+        assert_eq!(settings.min_severity, Some(Severity::High));
+        assert_eq!(settings.disabled, ["text/robust"]);
+        assert_eq!(settings.mode, Some(Mode::Markdown));
+        assert!(settings.warnings.is_empty(), "{:?}", settings.warnings);
+    }
+
+    #[test]
+    fn disable_expands_globs() {
+        let settings = inline_settings("<!-- unai: disable=structural/* -->\n");
+        assert!(settings.disabled.len() > 1);
+        assert!(settings
+            .disabled
+            .iter()
+            .all(|id| id.starts_with("structural/")));
+    }
+
+    #[test]
+    fn bad_keys_and_values_warn_and_are_skipped() {
+        let settings = inline_settings(
+            "<!-- unai: min-severity=urgent colour=red mode=code disable=text/nope stray -->\n",
+        );
+        assert_eq!(settings.min_severity, None);
+        assert_eq!(settings.mode, Some(Mode::Code));
+        assert!(settings.disabled.is_empty());
+        let warnings = settings.warnings.join("\n");
+        assert_eq!(settings.warnings.len(), 4, "{warnings}");
+        for needle in ["'urgent'", "'colour'", "'text/nope'", "'stray'"] {
+            assert!(
+                warnings.contains(needle),
+                "{needle} missing from {warnings}"
+            );
+        }
+    }
+
+    #[test]
+    fn only_the_first_lines_are_searched() {
+        let late = format!("{}<!-- unai: mode=code -->\n", "line\n".repeat(MAX_LINES));
+        assert_eq!(inline_settings(&late), InlineSettings::default());
+        let unrelated = "<!-- unai-ignore -->\n<!-- a comment -->\n";
+        assert_eq!(inline_settings(unrelated), InlineSettings::default());
+    }
+}
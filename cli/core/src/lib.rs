@@ -0,0 +1,48 @@
+//! Detect and remove LLM-isms from text, code comments, and commit messages.
+//!
+//! This is the library behind the `unai` command. [`Engine`] runs the rules
+//! over a document and [`clean`] applies the fixes the findings carry:
+//!
+//! ```
+//! use unai_core::{clean, Config, Engine, Mode, Severity};
+//!
+//! let text = "Great question! We leverage the cache.\n";
+//! let findings = Engine::new(Config::default()).analyze(text, Mode::Text);
+//! assert!(findings.iter().any(|f| f.severity >= Severity::High));
+//! assert_eq!(clean(text, &findings), "We use the cache.\n");
+//! ```
+//!
+//! A config is usually read from an `unai.toml` with [`Config::load`].
+//!
+//! # Stability
+//!
+//! unai-core is versioned together with the `unai` binary and follows semver
+//! as Cargo reads it for 0.x versions: a patch release (0.3.x) keeps every
+//! public item working, and a minor release (0.x.0) may break the API. Which
+//! findings a document produces is not part of that promise: rules are added
+//! and tuned in patch releases, so pin an exact version when a test depends
+//! on specific findings.
+//!
+//! The crate root is the supported entry point, and [`report::JsonFinding`]
+//! and [`score::Scores`] are the JSON shapes `unai --format json` reports. The [`config`],
+//! [`detector`], [`diff`], [`inline`] and [`rules`] modules are public so
+//! the `unai` binary can build on them; their lower-level items change more
+//! freely.
+
+pub mod config;
+pub mod detector;
+pub mod diff;
+mod engine;
+mod error;
+pub mod inline;
+pub mod report;
+pub mod rules;
+pub mod score;
+
+pub use config::Config;
+pub use detector::Mode;
+pub use engine::{
+    effective_code_rules, gather_findings, pass_runs, Analysis, Engine, SuppressCause,
+};
+pub use error::{Error, Result};
+pub use rules::{clean, CodeRule, Finding, Severity};
@@ -0,0 +1,79 @@
+//! The JSON shape of a finding, shared by `unai --format json` and the
+//! `unai-wasm` bindings so one schema describes both.
+
+use serde::Serialize;
+
+use crate::detector::Mode;
+use crate::rules::{Finding, FixSafety, Region, Severity, Source};
+
+/// One entry of the `findings` array.
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonFinding {
+    /// Input path, set in a multi-file scan.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub end_column: usize,
+    pub matched: String,
+    /// Length in chars of `matched` before it was cut to `max_matched_len`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matched_len: Option<usize>,
+    pub message: String,
+    /// Stable id of the reporting rule, as in `unai rules`.
+    pub rule_id: String,
+    pub severity: Severity,
+    pub replacement: Option<String>,
+    pub fix_safety: Option<FixSafety>,
+    pub source: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<Region>,
+    /// The fix lost to a conflicting fix on the same line and is not applied.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub superseded: bool,
+    /// Last line of a multi-line finding; `end_column` then refers to it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<usize>,
+    /// Alternative rewrites; never applied by the fixer.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub suggestions: Vec<String>,
+    /// Research the rule is grounded in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_ref: Option<Source>,
+}
+
+impl JsonFinding {
+    /// `f` as read in `mode`, with `matched` cut to `max_matched` chars; 0
+    /// keeps it whole.
+    pub fn new(f: &Finding, mode: &Mode, max_matched: usize) -> JsonFinding {
+        let head = truncated(&f.matched, max_matched);
+        JsonFinding {
+            file: None,
+            line: f.line,
+            column: f.col,
+            end_column: f.end_col(),
+            matched: head.map_or_else(|| f.matched.clone(), |h| format!("{}…", h)),
+            matched_len: head.map(|_| f.matched.chars().count()),
+            message: f.message.clone(),
+            rule_id: f.rule_id.clone(),
+            severity: f.severity,
+            replacement: f.replacement.clone(),
+            fix_safety: f.replacement.as_ref().map(|_| f.fix_safety),
+            source: mode.label().to_string(),
+            region: f.region,
+            superseded: false,
+            end_line: (f.last_line() > f.line).then_some(f.last_line()),
+            suggestions: f.suggestions.clone(),
+            source_ref: f.source,
+        }
+    }
+}
+
+/// The first `max` chars of `matched` when it is longer; `None` when it fits
+/// or `max` is 0.
+pub fn truncated(matched: &str, max: usize) -> Option<&str> {
+    if max == 0 {
+        return None;
+    }
+    matched.char_indices().nth(max).map(|(i, _)| &matched[..i])
+}
@@ -0,0 +1,414 @@
+//! API design rules for source code. Signatures and bodies are found with
+//! line-based heuristics on the code outside comments and strings, not with a
+//! parser, so each rule only fires on the plain shapes it recognizes.
+
+use std::collections::HashSet;
+
+use regex::Regex;
+
+use super::line::LineContext;
+use super::strings::code_only;
+use super::{Category, Finding, Severity};
+
+/// Parameter names that stand for a bag of settings.
+const OPTION_NAMES: &[&str] = &["options", "config", "params"];
+
+/// Lines a signature or body may span before the search gives up.
+const MAX_SPAN: usize = 30;
+
+/// A function parameter as written.
+struct Param {
+    name: String,
+    /// 0-based line and byte offset of the name.
+    line: usize,
+    col: usize,
+    typed: bool,
+    /// Typed `bool`/`boolean`, or defaulting to a boolean literal.
+    boolean: bool,
+    /// Before any `*` or `*args` that makes the rest keyword-only.
+    positional: bool,
+}
+
+/// A function definition: its name and where it sits, its parameters, and
+/// the position just past the closing parenthesis.
+struct Signature {
+    name: String,
+    line: usize,
+    col: usize,
+    params: Vec<Param>,
+    /// `*args`, rest parameters, or patterns were left out of `params`.
+    skipped: bool,
+    end: (usize, usize),
+}
+
+/// Append the API findings for `lines`, skipping rules whose ids are in
+/// `disabled`.
+pub(super) fn check_api(
+    lines: &[LineContext],
+    disabled: &HashSet<String>,
+    findings: &mut Vec<Finding>,
+) {
+    let content = lines
+        .iter()
+        .map(|ctx| ctx.original)
+        .collect::<Vec<_>>()
+        .join("\n");
+    let code = code_only(&content, None);
+    let definition = Regex::new(
+        r"\b(?:def|fn|function|func)\s+(?:\([^)]*\)\s*)?([A-Za-z_]\w*)\s*(?:<[^(]*>)?\(",
+    )
+    .expect("valid regex");
+    for (idx, line) in code.iter().enumerate() {
+        for caps in definition.captures_iter(line) {
+            let (name, whole) = (caps.get(1).unwrap(), caps.get(0).unwrap());
+            let Some(sig) = signature(&code, idx, whole.end(), name.as_str(), name.start()) else {
+                continue;
+            };
+            if !disabled.contains("api/boolean-params") {
+                check_boolean_params(&sig, findings);
+            }
+            if !disabled.contains("api/untyped-options") {
+                check_untyped_options(&sig, findings);
+            }
+            if !disabled.contains("api/thin-wrapper") {
+                check_thin_wrapper(&code, &sig, findings);
+            }
+        }
+    }
+}
+
+/// The signature whose parameter list opens just before `code[line][from]`,
+/// or `None` when its closing parenthesis is not found.
+fn signature(
+    code: &[String],
+    line: usize,
+    from: usize,
+    name: &str,
+    col: usize,
+) -> Option<Signature> {
+    let mut sig = Signature {
+        name: name.to_string(),
+        line,
+        col,
+        params: Vec::new(),
+        skipped: false,
+        end: (0, 0),
+    };
+    let mut positional = true;
+    // The parameter being read: its text and the position of its first byte.
+    let mut text = String::new();
+    let mut start = None;
+    let mut depth = 0usize;
+    let mut finish =
+        |text: &mut String, start: &mut Option<(usize, usize)>, sig: &mut Signature| {
+            if let Some((line, col)) = start.take() {
+                if is_receiver(text.trim()) {
+                    text.clear();
+                    return;
+                }
+                match param(text.trim(), line, col, &mut positional) {
+                    Some(p) => sig.params.push(p),
+                    None => sig.skipped = true,
+                }
+            }
+            text.clear();
+        };
+    for (l, code_line) in code.iter().enumerate().skip(line).take(MAX_SPAN) {
+        let from = if l == line { from } else { 0 };
+        let mut prev = ' ';
+        for (i, c) in code_line[from..].char_indices() {
+            let i = from + i;
+            match c {
+                '(' | '[' | '{' => depth += 1,
+                '<' if prev.is_alphanumeric() || prev == '_' || prev == ':' => depth += 1,
+                '>' if depth > 0 && prev != '-' && prev != '=' => depth -= 1,
+                ')' | ']' | '}' if depth > 0 => depth -= 1,
+                ')' => {
+                    finish(&mut text, &mut start, &mut sig);
+                    sig.end = (l, i + 1);
+                    return Some(sig);
+                }
+                ',' if depth == 0 => {
+                    finish(&mut text, &mut start, &mut sig);
+                    prev = c;
+                    continue;
+                }
+                _ => {}
+            }
+            if start.is_none() && !c.is_whitespace() {
+                start = Some((l, i));
+            }
+            if start.is_some() {
+                text.push(c);
+            }
+            prev = c;
+        }
+        text.push(' ');
+    }
+    None
+}
+
+/// Whether the parameter `text` is the receiver: `self`, `&mut self`,
+/// `cls`, or a TypeScript `this`.
+fn is_receiver(text: &str) -> bool {
+    let text = text.trim_start_matches('&');
+    // A lifetime, as in `&'a self`.
+    let text = match text.strip_prefix('\'') {
+        Some(rest) => rest.split_once(' ').map_or("", |(_, rest)| rest),
+        None => text,
+    };
+    let text = text.strip_prefix("mut ").unwrap_or(text);
+    let name_end = text
+        .find(|c: char| !c.is_alphanumeric() && c != '_')
+        .unwrap_or(text.len());
+    ["self", "cls", "this"].contains(&&text[..name_end])
+}
+
+/// One parameter from its trimmed `raw` text, which starts at `line`, `col`.
+/// `None` for parameters the rules do not judge: `*args`, rest parameters,
+/// and patterns. A bare `*` or `*args` turns `positional` off for the
+/// parameters after it.
+fn param(raw: &str, line: usize, col: usize, positional: &mut bool) -> Option<Param> {
+    if raw.starts_with('*') {
+        *positional = false;
+        return None;
+    }
+    let text = raw.strip_prefix("mut ").unwrap_or(raw);
+    let name_len = text
+        .find(|c: char| !c.is_alphanumeric() && c != '_')
+        .unwrap_or(text.len());
+    let name = &text[..name_len];
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    let rest = text[name_len..].trim_start();
+    let rest = rest.strip_prefix('?').unwrap_or(rest);
+    let (annotation, default) = match rest.split_once('=') {
+        Some((annotation, default)) => (annotation.trim(), default.trim()),
+        None => (rest.trim(), ""),
+    };
+    // `name: Type` in most languages, `name Type` in Go.
+    let ty = annotation.strip_prefix(':').unwrap_or(annotation).trim();
+    Some(Param {
+        name: name.to_string(),
+        line,
+        col: col + raw.len() - text.len(),
+        typed: !ty.is_empty(),
+        boolean: ["bool", "boolean"].contains(&ty)
+            || ["True", "False", "true", "false"].contains(&default),
+        positional: *positional,
+    })
+}
+
+fn check_boolean_params(sig: &Signature, findings: &mut Vec<Finding>) {
+    let count = sig
+        .params
+        .iter()
+        .filter(|p| p.boolean && p.positional)
+        .count();
+    if count > 1 {
+        findings.push(Finding {
+            line: sig.line + 1,
+            col: sig.col,
+            matched: sig.name.clone(),
+            message: format!(
+                "{count} boolean parameters on '{}': split the function or take an enum",
+                sig.name
+            ),
+            rule_id: "api/boolean-params".to_string(),
+            severity: Severity::High,
+            category: Category::Code,
+            ..Default::default()
+        });
+    }
+}
+
+fn check_untyped_options(sig: &Signature, findings: &mut Vec<Finding>) {
+    for p in &sig.params {
+        if !p.typed && OPTION_NAMES.contains(&p.name.as_str()) {
+            findings.push(Finding {
+                line: p.line + 1,
+                col: p.col,
+                matched: p.name.clone(),
+                message: format!(
+                    "Untyped '{}' parameter: give it a type that names its settings",
+                    p.name
+                ),
+                rule_id: "api/untyped-options".to_string(),
+                severity: Severity::Medium,
+                category: Category::Code,
+                ..Default::default()
+            });
+        }
+    }
+}
+
+/// A function whose whole body is one call passing its parameters through
+/// unchanged, such as `def get_user(id): return fetch_user(id)`.
+fn check_thin_wrapper(code: &[String], sig: &Signature, findings: &mut Vec<Finding>) {
+    if sig.params.is_empty() || sig.skipped {
+        return;
+    }
+    let Some(statement) = single_statement(code, sig) else {
+        return;
+    };
+    let call = Regex::new(r"^(?:return\s+)?(?:await\s+)?([A-Za-z_][\w.:]*)\s*\(([^()]*)\)\s*;?$")
+        .expect("valid regex");
+    let Some(caps) = call.captures(&statement) else {
+        return;
+    };
+    let callee = &caps[1];
+    let args: Vec<&str> = caps[2]
+        .split(',')
+        .map(str::trim)
+        .filter(|a| !a.is_empty())
+        .collect();
+    let names: Vec<&str> = sig.params.iter().map(|p| p.name.as_str()).collect();
+    if callee == sig.name || args != names {
+        return;
+    }
+    findings.push(Finding {
+        line: sig.line + 1,
+        col: sig.col,
+        matched: sig.name.clone(),
+        message: format!(
+            "Thin wrapper: '{}' only passes its arguments to '{callee}'; call it directly",
+            sig.name
+        ),
+        rule_id: "api/thin-wrapper".to_string(),
+        severity: Severity::Medium,
+        category: Category::Code,
+        ..Default::default()
+    });
+}
+
+/// The body of the function after `sig` when it holds exactly one statement:
+/// a Python body after `:`, inline or indented, or a braced body.
+fn single_statement(code: &[String], sig: &Signature) -> Option<String> {
+    let (end_line, end_col) = sig.end;
+    let rest = &code[end_line][end_col..];
+    let colon = Regex::new(r"^\s*(?:->[^:{]*)?:([^{]*)$").expect("valid regex");
+    if let Some(caps) = colon.captures(rest) {
+        let inline = caps[1].trim();
+        if !inline.is_empty() {
+            return Some(inline.to_string());
+        }
+        let indent = |line: &str| line.len() - line.trim_start().len();
+        let outer = indent(&code[sig.line]);
+        let body: Vec<&str> = code[end_line + 1..]
+            .iter()
+            .filter(|line| !line.trim().is_empty())
+            .take_while(|line| indent(line) > outer)
+            .map(|line| line.trim())
+            .collect();
+        return match body[..] {
+            [statement] => Some(statement.to_string()),
+            _ => None,
+        };
+    }
+    // A braced body: everything between the first `{` and its match.
+    let mut body = String::new();
+    let mut depth = 0usize;
+    for (l, line) in code.iter().enumerate().skip(end_line).take(MAX_SPAN) {
+        let from = if l == end_line { end_col } else { 0 };
+        for c in line[from..].chars() {
+            match c {
+                ';' if depth == 0 => return None,
+                '{' => {
+                    depth += 1;
+                    if depth == 1 {
+                        continue;
+                    }
+                }
+                '}' => {
+                    depth = depth.checked_sub(1)?;
+                    if depth == 0 {
+                        let statements: Vec<&str> = body
+                            .split([';', '\n'])
+                            .map(str::trim)
+                            .filter(|s| !s.is_empty())
+                            .collect();
+                        return match statements[..] {
+                            [statement] => Some(statement.to_string()),
+                            _ => None,
+                        };
+                    }
+                }
+                _ => {}
+            }
+            if depth > 0 {
+                body.push(c);
+            }
+        }
+        if depth > 0 {
+            body.push('\n');
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(content: &str) -> Vec<String> {
+        let mut findings = Vec::new();
+        check_api(&LineContext::split(content), &HashSet::new(), &mut findings);
+        findings.into_iter().map(|f| f.rule_id).collect()
+    }
+
+    #[test]
+    fn boolean_params_counts_positional_flags() {
+        let py = "def get_user(user_id, include_deleted=False, as_dict: bool = False):\n    pass\n";
+        assert_eq!(ids(py), ["api/boolean-params"]);
+        let rs = "pub fn render(text: &str, color: bool, wrap: bool) -> String {\n    todo()\n}\n";
+        assert_eq!(ids(rs), ["api/boolean-params"]);
+        let keyword_only = "def get_user(user_id, *, deleted=False, as_dict=False):\n    pass\n";
+        assert!(ids(keyword_only).is_empty());
+        let one = "function show(node, visible = true) {\n  draw(node);\n  log(node);\n}\n";
+        assert!(ids(one).is_empty());
+    }
+
+    #[test]
+    fn multi_line_signatures_are_read() {
+        let py = "def get_user(\n    user_id: str,\n    include_deleted: bool = False,\n    \
+                  with_permissions: bool = True,\n) -> User:\n    return load(user_id)\n";
+        assert_eq!(ids(py), ["api/boolean-params"]);
+    }
+
+    #[test]
+    fn thin_wrappers_pass_arguments_through() {
+        assert_eq!(
+            ids("def get_user(id): return fetch_user(id)\n"),
+            ["api/thin-wrapper"]
+        );
+        assert_eq!(
+            ids("def get_user(id):\n    \"\"\"Get a user.\"\"\"\n    return fetch_user(id)\n"),
+            ["api/thin-wrapper"]
+        );
+        assert_eq!(
+            ids("fn get(&self, id: u32) -> User {\n    self.repo.get(id)\n}\n"),
+            ["api/thin-wrapper"]
+        );
+        assert!(ids("def get_user(id):\n    return fetch_user(id, deleted=False)\n").is_empty());
+        assert!(ids("def get_user(id):\n    user = fetch_user(id)\n    return user\n").is_empty());
+        assert!(ids("fn depth(n: u32) -> u32 {\n    depth(n)\n}\n").is_empty());
+    }
+
+    #[test]
+    fn untyped_options_params() {
+        assert_eq!(
+            ids("def connect(host, options):\n    pass\n"),
+            ["api/untyped-options"]
+        );
+        assert!(ids("def connect(host: str, options: ConnectOptions):\n    pass\n").is_empty());
+        assert!(ids("fn connect(host: &str, config: Config) {}\n").is_empty());
+    }
+
+    #[test]
+    fn comments_and_strings_are_ignored() {
+        assert!(ids("# def get_user(id): return fetch_user(id)\n").is_empty());
+        assert!(ids("let s = \"fn f(a: bool, b: bool) {}\";\n").is_empty());
+        assert!(ids("/* def connect(options): pass */\n").is_empty());
+    }
+}
@@ -0,0 +1,629 @@
+use std::collections::HashSet;
+
+use super::api::check_api;
+use super::commit::check_commit_patterns;
+use super::docstring::check_docstring_sections;
+use super::line::LineContext;
+use super::{Category, Deadline, Finding, FixSafety, Region, Severity};
+
+/// Which code rule categories to apply.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CodeRule {
+    Comments,
+    Naming,
+    Commits,
+    Docstrings,
+    Tests,
+    Errors,
+    Api,
+}
+
+impl std::str::FromStr for CodeRule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "comments" => Ok(Self::Comments),
+            "naming" => Ok(Self::Naming),
+            "commits" => Ok(Self::Commits),
+            "docstrings" => Ok(Self::Docstrings),
+            "tests" => Ok(Self::Tests),
+            "errors" => Ok(Self::Errors),
+            "api" => Ok(Self::Api),
+            _ => Err(format!(
+                "unknown rule '{}'. Valid: comments, naming, commits, docstrings, tests, errors, api",
+                s
+            )),
+        }
+    }
+}
+
+/// Apply the `enabled` code rules (all when empty) to `lines`, as folded by
+/// [`LineContext::split`], skipping the rules whose ids are in `disabled`.
+pub fn apply_code_rules(
+    lines: &[LineContext],
+    enabled: &[CodeRule],
+    disabled: &HashSet<String>,
+) -> Vec<Finding> {
+    apply_code_rules_until(lines, enabled, disabled, Deadline::NONE)
+}
+
+/// [`apply_code_rules`], stopping at the first line or multi-line pass
+/// reached after `deadline` with the findings so far.
+pub fn apply_code_rules_until(
+    lines: &[LineContext],
+    enabled: &[CodeRule],
+    disabled: &HashSet<String>,
+    deadline: Deadline,
+) -> Vec<Finding> {
+    let all = enabled.is_empty();
+    let on = |id: &str| !disabled.contains(id);
+    let mut findings = Vec::new();
+
+    for (idx, ctx) in lines.iter().enumerate() {
+        if deadline.passed() {
+            return findings;
+        }
+        let line = ctx.original;
+        let trimmed = line.trim();
+        let line_lower = ctx.lower.trim();
+        let lineno = idx + 1;
+
+        if all || enabled.contains(&CodeRule::Comments) {
+            if on("code/section-header") && is_section_header(trimmed) {
+                findings.push(Finding {
+                    line: lineno,
+                    col: 0,
+                    matched: trimmed.to_string(),
+                    message: "Section header comment: dividers add noise without value".to_string(),
+                    rule_id: "code/section-header".to_string(),
+                    replacement: None,
+                    severity: Severity::High,
+                    category: Category::Code,
+                    region: Some(Region::CodeComment),
+                    ..Default::default()
+                });
+            }
+
+            if on("code/bare-todo") && is_bare_todo(line_lower) {
+                findings.push(Finding {
+                    line: lineno,
+                    col: 0,
+                    matched: trimmed.to_string(),
+                    message: "Bare TODO without context or ticket reference".to_string(),
+                    rule_id: "code/bare-todo".to_string(),
+                    replacement: None,
+                    severity: Severity::Critical,
+                    category: Category::Code,
+                    region: Some(Region::CodeComment),
+                    ..Default::default()
+                });
+            }
+        }
+
+        if (all || enabled.contains(&CodeRule::Docstrings)) && on("code/docstring-boilerplate") {
+            let docstring_phrases = [
+                "this function serves as",
+                "this class represents",
+                "this method handles",
+                "this module provides",
+            ];
+            for phrase in &docstring_phrases {
+                if let Some(col) = line_lower.find(phrase) {
+                    findings.push(Finding {
+                        line: lineno,
+                        col,
+                        matched: phrase.to_string(),
+                        message: format!("LLM docstring boilerplate: '{phrase}'"),
+                        rule_id: "code/docstring-boilerplate".to_string(),
+                        replacement: None,
+                        severity: Severity::High,
+                        category: Category::Code,
+                        region: Some(Region::CodeComment),
+                        ..Default::default()
+                    });
+                }
+            }
+        }
+
+        if all || enabled.contains(&CodeRule::Naming) {
+            check_naming(ctx, lineno, disabled, &mut findings);
+        }
+
+        if all || enabled.contains(&CodeRule::Commits) {
+            check_commit_patterns(trimmed, line_lower, lineno, disabled, &mut findings);
+        }
+    }
+
+    // Restated comments are judged against the line after them.
+    if deadline.passed() {
+        return findings;
+    }
+    if all || enabled.contains(&CodeRule::Comments) {
+        check_restated_comments(lines, disabled, &mut findings);
+    }
+
+    // Docstring sections run from a header to the end of its indented block.
+    if deadline.passed() {
+        return findings;
+    }
+    if all || enabled.contains(&CodeRule::Docstrings) {
+        check_docstring_sections(lines, disabled, &mut findings);
+    }
+
+    // API rules read signatures and bodies that span lines.
+    if deadline.passed() {
+        return findings;
+    }
+    if all || enabled.contains(&CodeRule::Api) {
+        check_api(lines, disabled, &mut findings);
+    }
+
+    findings
+}
+
+fn is_section_header(line: &str) -> bool {
+    if !line.starts_with('#') && !line.starts_with("//") && !line.starts_with("--") {
+        return false;
+    }
+
+    let after_marker = line
+        .trim_start_matches('#')
+        .trim_start_matches('/')
+        .trim_start();
+
+    if after_marker
+        .chars()
+        .all(|c| c == '-' || c == '=' || c == ' ')
+        && after_marker.len() >= 3
+    {
+        return true;
+    }
+
+    if after_marker.starts_with("---") || after_marker.starts_with("===") {
+        return true;
+    }
+    if after_marker.ends_with("---") || after_marker.ends_with("===") {
+        return true;
+    }
+
+    let words: Vec<&str> = after_marker.split_whitespace().collect();
+    if !words.is_empty()
+        && words.iter().all(|w| {
+            let trimmed = w.trim_end_matches(':');
+            trimmed.len() > 1 && trimmed.chars().all(|c| c.is_uppercase() || c == '_')
+        })
+    {
+        return true;
+    }
+
+    false
+}
+
+/// Comments with more words than this explain something, so they are kept.
+const RESTATED_MAX_WORDS: usize = 10;
+
+/// Share of a comment's words that the next line must account for.
+const RESTATED_OVERLAP: f64 = 0.75;
+
+/// Words a comment can leave out or add without saying anything new.
+const RESTATED_FILLER: &[&str] = &[
+    "a", "an", "the", "to", "of", "and", "for", "with", "this", "that", "it", "is", "in", "on",
+    "we", "our", "now", "then", "here", "new",
+];
+
+/// Verbs and nouns that narrate what a line does: "call", "the variable".
+const RESTATED_NARRATION: &[&str] = &[
+    "call",
+    "calls",
+    "increment",
+    "increments",
+    "decrement",
+    "set",
+    "sets",
+    "get",
+    "gets",
+    "return",
+    "returns",
+    "create",
+    "creates",
+    "initialize",
+    "initialise",
+    "init",
+    "add",
+    "adds",
+    "update",
+    "updates",
+    "assign",
+    "define",
+    "declare",
+    "import",
+    "print",
+    "loop",
+    "iterate",
+    "function",
+    "method",
+    "variable",
+    "value",
+    "instance",
+    "object",
+];
+
+/// The text of a full-line `#`, `//` or `--` comment, without its marker.
+/// Doc comments (`///`, `//!`), attributes and shebangs are not comments
+/// here.
+fn line_comment(trimmed: &str) -> Option<&str> {
+    if trimmed.starts_with("///") || trimmed.starts_with("//!") {
+        return None;
+    }
+    let rest = ["//", "#", "--"]
+        .iter()
+        .find_map(|marker| trimmed.strip_prefix(marker))?;
+    (rest.is_empty() || rest.starts_with([' ', '\t'])).then(|| rest.trim())
+}
+
+/// Whether `text` is a numbered step: "Step 1:", "step 2.".
+fn is_step_comment(text: &str) -> bool {
+    let lower = text.to_lowercase();
+    let Some(rest) = lower.strip_prefix("step ") else {
+        return false;
+    };
+    let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+    digits > 0 && rest[digits..].starts_with([':', '.', ')', ' '])
+}
+
+/// Lowercase words of the identifiers on `code`, split on `_` and at
+/// camelCase humps: `userCount += 1` gives "user" and "count".
+fn code_words(code: &str) -> HashSet<String> {
+    let mut words = HashSet::new();
+    for ident in code.split(|c: char| !c.is_alphanumeric()) {
+        let mut word = String::new();
+        let mut prev_lower = false;
+        for c in ident.chars() {
+            if c.is_uppercase() && prev_lower {
+                words.insert(std::mem::take(&mut word));
+            }
+            prev_lower = c.is_lowercase();
+            word.extend(c.to_lowercase());
+        }
+        words.insert(word);
+    }
+    words.remove("");
+    words
+}
+
+/// Whether a comment of `words` only says what `code` does: every word but
+/// filler is narration or names something on the line, in the share
+/// `RESTATED_OVERLAP`, and at least one names something.
+fn restates(words: &[String], code: &str) -> bool {
+    let code = code_words(code);
+    let named = |w: &String| code.contains(w) || code.contains(w.trim_end_matches('s'));
+    let content: Vec<&String> = words
+        .iter()
+        .filter(|w| !RESTATED_FILLER.contains(&w.as_str()))
+        .collect();
+    let covered = content
+        .iter()
+        .filter(|w| named(w) || RESTATED_NARRATION.contains(&w.as_str()))
+        .count();
+    !content.is_empty()
+        && content.iter().any(|w| named(w))
+        && covered as f64 / content.len() as f64 >= RESTATED_OVERLAP
+}
+
+/// Flag a lone comment line that restates the code line after it
+/// (`# increment the counter` above `counter += 1`) or numbers a step
+/// (`# Step 1:`). The fix deletes the comment line.
+fn check_restated_comments(
+    lines: &[LineContext],
+    disabled: &HashSet<String>,
+    findings: &mut Vec<Finding>,
+) {
+    if disabled.contains("code/restated-comment") {
+        return;
+    }
+    let comment = |i: usize| line_comment(lines[i].original.trim());
+    for idx in 0..lines.len() {
+        let Some(text) = comment(idx) else {
+            continue;
+        };
+        if is_section_header(lines[idx].original.trim()) {
+            continue;
+        }
+        // Part of a comment block, which reads as one explanation.
+        if idx > 0 && comment(idx - 1).is_some() {
+            continue;
+        }
+        let Some(next) = (idx + 1..lines.len()).find(|&i| !lines[i].original.trim().is_empty())
+        else {
+            continue;
+        };
+        if comment(next).is_some() {
+            continue;
+        }
+        let words: Vec<String> = text
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+        if words.len() > RESTATED_MAX_WORDS {
+            continue;
+        }
+        let message = if is_step_comment(text) {
+            "Numbered step comment: the code already runs in order"
+        } else if restates(&words, lines[next].original) {
+            "Comment restates the next line: say why, or drop it"
+        } else {
+            continue;
+        };
+        let line = lines[idx].original;
+        let trimmed = line.trim();
+        findings.push(Finding {
+            line: idx + 1,
+            col: line.len() - line.trim_start().len(),
+            matched: trimmed.to_string(),
+            message: message.to_string(),
+            rule_id: "code/restated-comment".to_string(),
+            replacement: Some(String::new()),
+            fix_safety: FixSafety::Caution,
+            severity: Severity::Medium,
+            category: Category::Code,
+            region: Some(Region::CodeComment),
+            ..Default::default()
+        });
+    }
+}
+
+/// `lower` is the trimmed, lowercased line.
+fn is_bare_todo(lower: &str) -> bool {
+    let todo_prefixes = ["# todo:", "// todo:", "-- todo:", "/* todo:"];
+    for prefix in &todo_prefixes {
+        if let Some(rest) = lower.strip_prefix(prefix) {
+            let rest = rest.trim();
+            let bare_messages = [
+                "",
+                "add error handling",
+                "fix this",
+                "handle this",
+                "implement",
+                "add tests",
+                "clean up",
+                "refactor",
+            ];
+            if bare_messages.contains(&rest) {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+fn check_naming(
+    ctx: &LineContext,
+    lineno: usize,
+    disabled: &HashSet<String>,
+    findings: &mut Vec<Finding>,
+) {
+    let line = ctx.original;
+    let suffixes = ["Manager", "Handler", "Helper", "Util", "Utility", "Service"];
+    if !disabled.contains("code/anemic-suffix") {
+        for suffix in &suffixes {
+            if let Some(pos) = find_suffix_token(line, suffix) {
+                // Suggest the identifier with the suffix dropped: userManager → user.
+                let stem_start = line[..pos]
+                    .char_indices()
+                    .rev()
+                    .take_while(|&(_, c)| c.is_alphanumeric() || c == '_')
+                    .last()
+                    .map_or(pos, |(i, _)| i);
+                findings.push(Finding {
+                    line: lineno,
+                    col: pos,
+                    matched: suffix.to_string(),
+                    message: format!(
+                        "Anemic type suffix '{}': name the responsibility, not the role",
+                        suffix
+                    ),
+                    rule_id: "code/anemic-suffix".to_string(),
+                    replacement: None,
+                    severity: Severity::High,
+                    category: Category::Code,
+                    suggestions: vec![line[stem_start..pos].to_string()],
+                    ..Default::default()
+                });
+            }
+        }
+    }
+
+    let redundant = [
+        ("userDataObject", "user"),
+        ("configurationSettings", "config"),
+        ("errorMessageString", "message"),
+        ("listOfUsers", "users"),
+    ];
+    let line_lower = &ctx.lower;
+    if !disabled.contains("code/type-in-name") {
+        for (bad, suggestion) in &redundant {
+            if let Some(col) = line_lower.find(&bad.to_lowercase()) {
+                findings.push(Finding {
+                    line: lineno,
+                    col,
+                    matched: bad.to_string(),
+                    message: format!("Type-in-name anti-pattern: use '{}' instead", suggestion),
+                    rule_id: "code/type-in-name".to_string(),
+                    replacement: None,
+                    severity: Severity::Medium,
+                    category: Category::Code,
+                    ..Default::default()
+                });
+            }
+        }
+    }
+}
+
+fn find_suffix_token(line: &str, suffix: &str) -> Option<usize> {
+    let mut search_start = 0;
+    while search_start < line.len() {
+        let slice = &line[search_start..];
+        let pos = slice.find(suffix)?;
+        let abs_pos = search_start + pos;
+        let end = abs_pos + suffix.len();
+
+        let after_ok = line[end..]
+            .chars()
+            .next()
+            .map(|c| !c.is_alphanumeric() && c != '_')
+            .unwrap_or(true);
+
+        let before_ok = abs_pos > 0
+            && line[..abs_pos]
+                .chars()
+                .last()
+                .map(|c| c.is_alphanumeric() || c == '_')
+                .unwrap_or(false);
+
+        if after_ok && before_ok {
+            return Some(abs_pos);
+        }
+        search_start = abs_pos + suffix.len();
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn section_header_detected() {
+        let findings = apply_code_rules(
+            &LineContext::split("# --- Setup ---\nfn main() {}"),
+            &[CodeRule::Comments],
+            &HashSet::new(),
+        );
+        assert!(findings
+            .iter()
+            .any(|f| f.message.contains("Section header")));
+    }
+
+    #[test]
+    fn bare_todo_detected() {
+        let findings = apply_code_rules(
+            &LineContext::split("# TODO: add error handling"),
+            &[CodeRule::Comments],
+            &HashSet::new(),
+        );
+        assert!(findings.iter().any(|f| f.message.contains("Bare TODO")));
+    }
+
+    #[test]
+    fn restated_comment_dropped_by_clean() {
+        let code = "fn main() {\n    // call the helper function\n    helper();\n    # increment the counter\n    counter += 1\n    # Step 2: load the data\n    let rows = load(path);\n}\n";
+        let findings = apply_code_rules(
+            &LineContext::split(code),
+            &[CodeRule::Comments],
+            &HashSet::new(),
+        );
+        let lines: Vec<usize> = findings.iter().map(|f| f.line).collect();
+        assert_eq!(lines, [2, 4, 6], "{findings:?}");
+        assert_eq!(findings[0].matched, "// call the helper function");
+        assert_eq!(
+            (findings[0].severity, findings[0].col),
+            (Severity::Medium, 4)
+        );
+        assert!(findings[2].message.starts_with("Numbered step"));
+        assert_eq!(
+            crate::rules::clean(code, &findings),
+            "fn main() {\n    helper();\n    counter += 1\n    let rows = load(path);\n}\n"
+        );
+    }
+
+    #[test]
+    fn explaining_comments_are_kept() {
+        let code = "/// Increment the counter.\nfn bump() {}\n\
+            # Retry once: the server drops the first request after idling\nclient.retry()\n\
+            # read the config\n# before the args\nconfig = read()\n\
+            # why: counters wrap at 2^32 so we reset them early\ncounter = 0\n";
+        let findings = apply_code_rules(
+            &LineContext::split(code),
+            &[CodeRule::Comments],
+            &HashSet::new(),
+        );
+        assert!(findings.is_empty(), "{findings:?}");
+    }
+
+    #[test]
+    fn naming_suffix_detected() {
+        let findings = apply_code_rules(
+            &LineContext::split("let userManager = ..."),
+            &[CodeRule::Naming],
+            &HashSet::new(),
+        );
+        assert!(findings.iter().any(|f| f.matched == "Manager"));
+    }
+
+    #[test]
+    fn severity_critical_for_bare_todo() {
+        let findings = apply_code_rules(
+            &LineContext::split("# TODO: fix this"),
+            &[CodeRule::Comments],
+            &HashSet::new(),
+        );
+        let f = findings
+            .iter()
+            .find(|f| f.message.contains("Bare TODO"))
+            .unwrap();
+        assert_eq!(f.severity, Severity::Critical);
+    }
+
+    #[test]
+    fn severity_high_for_section_header() {
+        let findings = apply_code_rules(
+            &LineContext::split("# --- Setup ---\nfn main() {}"),
+            &[CodeRule::Comments],
+            &HashSet::new(),
+        );
+        let f = findings
+            .iter()
+            .find(|f| f.message.contains("Section header"))
+            .unwrap();
+        assert_eq!(f.severity, Severity::High);
+    }
+
+    #[test]
+    fn severity_high_for_anemic_suffix() {
+        let findings = apply_code_rules(
+            &LineContext::split("let userManager = ..."),
+            &[CodeRule::Naming],
+            &HashSet::new(),
+        );
+        let f = findings.iter().find(|f| f.matched == "Manager").unwrap();
+        assert_eq!(f.severity, Severity::High);
+    }
+
+    #[test]
+    fn anemic_suffix_suggests_stem() {
+        let findings = apply_code_rules(
+            &LineContext::split("let userManager = ..."),
+            &[CodeRule::Naming],
+            &HashSet::new(),
+        );
+        let f = findings.iter().find(|f| f.matched == "Manager").unwrap();
+        assert_eq!(f.suggestions, vec!["user"]);
+        assert_eq!(f.replacement, None);
+    }
+
+    #[test]
+    fn severity_medium_for_type_in_name() {
+        let findings = apply_code_rules(
+            &LineContext::split("let userDataObject = ..."),
+            &[CodeRule::Naming],
+            &HashSet::new(),
+        );
+        let f = findings
+            .iter()
+            .find(|f| f.message.contains("Type-in-name"))
+            .unwrap();
+        assert_eq!(f.severity, Severity::Medium);
+    }
+}
@@ -0,0 +1,707 @@
+use std::collections::HashSet;
+
+use super::punctuation::emoji_end;
+use super::{Category, Confidence, Finding, Region, Severity, Source};
+
+/// Region of a commit message line: the first line is the subject.
+pub(crate) fn commit_region(lineno: usize) -> Region {
+    if lineno == 1 {
+        Region::CommitSubject
+    } else {
+        Region::CommitBody
+    }
+}
+
+/// Study behind the commit-body rule.
+pub(super) const COMMIT_FINGERPRINT: Source = Source {
+    citation: "arxiv:2601.17406",
+    confidence: Confidence::Low,
+};
+
+/// Opens the log `git merge --squash` appends to SQUASH_MSG.
+const SQUASH_LOG_HEADER: &str = "Squashed commit of the following:";
+
+/// 1-based lines of a commit message that git generated rather than the
+/// author wrote: comment lines, which start with `comment_char` (`#` unless
+/// `core.commentChar` says otherwise), everything below the `>8` scissors
+/// line that `commit --verbose` adds, the squashed-commit log of SQUASH_MSG,
+/// and `* commit <hash> …` squash-log bullets.
+pub(crate) fn generated_commit_lines(content: &str, comment_char: &str) -> HashSet<usize> {
+    let mut generated = HashSet::new();
+    let mut rest_generated = false;
+    for (idx, line) in content.lines().enumerate() {
+        let lineno = idx + 1;
+        if rest_generated {
+            generated.insert(lineno);
+            continue;
+        }
+        let trimmed = line.trim_end();
+        let comment = trimmed.starts_with(comment_char);
+        if trimmed == SQUASH_LOG_HEADER || (comment && trimmed.contains(" >8 ")) {
+            rest_generated = true;
+            generated.insert(lineno);
+        } else if comment || is_squash_bullet(trimmed) {
+            generated.insert(lineno);
+        }
+    }
+    generated
+}
+
+/// `* commit 1234abc …`, one entry of a squashed-commit list.
+fn is_squash_bullet(line: &str) -> bool {
+    line.strip_prefix("* commit ")
+        .and_then(|rest| rest.split_whitespace().next())
+        .is_some_and(|hash| hash.len() >= 7 && hash.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Verbs that open a commit subject in the past tense, each with the
+/// imperative form the fix puts in its place.
+const PAST_TENSE_VERBS: &[(&str, &str)] = &[
+    ("added", "add"),
+    ("fixed", "fix"),
+    ("updated", "update"),
+    ("changed", "change"),
+    ("removed", "remove"),
+    ("modified", "modify"),
+    ("implemented", "implement"),
+    ("refactored", "refactor"),
+    ("created", "create"),
+    ("deleted", "delete"),
+    ("moved", "move"),
+    ("improved", "improve"),
+    ("enhanced", "enhance"),
+    ("cleaned", "clean"),
+    ("bumped", "bump"),
+    ("dropped", "drop"),
+    ("replaced", "replace"),
+    ("resolved", "resolve"),
+    ("addressed", "address"),
+    ("reverted", "revert"),
+];
+
+/// Imperative verbs that open a change in a commit subject: the present forms
+/// in [`PAST_TENSE_VERBS`] and a few more.
+const IMPERATIVE_VERBS: &[&str] = &[
+    "add",
+    "fix",
+    "update",
+    "change",
+    "remove",
+    "modify",
+    "implement",
+    "refactor",
+    "create",
+    "delete",
+    "move",
+    "improve",
+    "enhance",
+    "clean",
+    "bump",
+    "drop",
+    "replace",
+    "resolve",
+    "address",
+    "revert",
+    "rename",
+    "document",
+    "handle",
+    "simplify",
+    "extract",
+    "introduce",
+];
+
+/// Words that join one change to the next in a subject; a trailing comma
+/// does too.
+const CHANGE_JOINERS: &[&str] = &["and", "plus", "also", "&"];
+
+/// Noun phrases with a joiner inside, which name one thing.
+const JOINED_NOUNS: &[&str] = &[
+    "drag and drop",
+    "copy and paste",
+    "find and replace",
+    "search and replace",
+    "read and write",
+    "pros and cons",
+];
+
+/// Number of changes a lowercase subject lists: verb phrases at its start
+/// and after each joiner. Words inside quotes or backticks and the
+/// conventional-commit prefix are not counted.
+fn count_subject_changes(lower: &str) -> usize {
+    let mut subject = join_quoted(strip_conventional_prefix(lower));
+    for noun in JOINED_NOUNS {
+        subject = subject.replace(noun, &noun.replace(' ', "_"));
+    }
+    let mut changes = 0;
+    let mut at_start = true;
+    for word in subject.split_whitespace() {
+        let bare = word.trim_matches(|c: char| !c.is_alphanumeric() && c != '&');
+        if CHANGE_JOINERS.contains(&bare) {
+            at_start = true;
+            continue;
+        }
+        if at_start && (IMPERATIVE_VERBS.contains(&bare) || imperative_of(bare).is_some()) {
+            changes += 1;
+        }
+        at_start = word.ends_with(',');
+    }
+    changes
+}
+
+/// Imperative form of a past-tense verb from [`PAST_TENSE_VERBS`].
+fn imperative_of(lower_word: &str) -> Option<&'static str> {
+    PAST_TENSE_VERBS
+        .iter()
+        .find(|(past, _)| *past == lower_word)
+        .map(|(_, imperative)| *imperative)
+}
+
+/// Byte offset and text of each whitespace-separated word of `line`.
+fn words(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    line.split_whitespace()
+        .map(move |word| (word.as_ptr() as usize - line.as_ptr() as usize, word))
+}
+
+/// Whether `word` is only emoji, or a `:shortcode:` such as `:sparkles:`.
+fn is_gitmoji(word: &str) -> bool {
+    if let Some(name) = word.strip_prefix(':').and_then(|w| w.strip_suffix(':')) {
+        return !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '+' | '-'));
+    }
+    let mut col = 0;
+    while col < word.len() {
+        match emoji_end(word, col) {
+            Some(end) => col = end,
+            None => return false,
+        }
+    }
+    col > 0
+}
+
+/// `subject` without a leading `type(scope)!: ` conventional-commit prefix.
+fn strip_conventional_prefix(subject: &str) -> &str {
+    let Some((prefix, rest)) = subject.split_once(": ") else {
+        return subject;
+    };
+    let kind = prefix.split('(').next().unwrap_or_default();
+    let kind = kind.strip_suffix('!').unwrap_or(kind);
+    if !kind.is_empty() && kind.chars().all(|c| c.is_ascii_alphabetic()) {
+        rest
+    } else {
+        subject
+    }
+}
+
+/// `subject` with the spaces inside quoted or backticked names replaced by
+/// `_`, so a name like "Tom and Jerry" reads as one word.
+fn join_quoted(subject: &str) -> String {
+    let mut out = String::with_capacity(subject.len());
+    let mut open: Option<char> = None;
+    let mut prev = ' ';
+    for c in subject.chars() {
+        match open {
+            Some(q) if c == q => open = None,
+            Some(_) if c.is_whitespace() => {
+                out.push('_');
+                prev = c;
+                continue;
+            }
+            // An apostrophe after a letter is a contraction, not a quote.
+            None if matches!(c, '"' | '`') || (c == '\'' && !prev.is_alphanumeric()) => {
+                open = Some(c)
+            }
+            _ => {}
+        }
+        out.push(c);
+        prev = c;
+    }
+    out
+}
+
+/// Names AI assistants sign commits with, lowercase.
+const AI_NAMES: &[&str] = &["claude", "chatgpt", "copilot", "gpt", "gemini", "codex"];
+
+/// Email domains of AI assistants' commit identities.
+const AI_DOMAINS: &[&str] = &["anthropic.com", "openai.com"];
+
+/// Words that may follow an AI name to make a model name, as in
+/// `Claude Opus 4` or `Gemini Code Assist`. Numbers also may.
+const MODEL_WORDS: &[&str] = &[
+    "code", "opus", "sonnet", "haiku", "pro", "flash", "ultra", "assist", "agent", "chat",
+];
+
+/// Whether lowercase `line` credits an AI assistant: a `*-by:` trailer such
+/// as `Co-Authored-By: Claude <noreply@anthropic.com>` whose author is a
+/// known AI name or domain, or a `Generated with ...` footer naming one.
+fn is_ai_attribution(lower: &str) -> bool {
+    if let Some((key, value)) = lower.split_once(':') {
+        let is_trailer_key =
+            key.ends_with("-by") && key.chars().all(|c| c.is_ascii_alphabetic() || c == '-');
+        if is_trailer_key {
+            let (name, email) = match value.split_once('<') {
+                Some((name, email)) => (name.trim(), email.trim_end_matches('>')),
+                None => (value.trim(), ""),
+            };
+            let ai_domain = email.rsplit_once('@').is_some_and(|(_, domain)| {
+                AI_DOMAINS
+                    .iter()
+                    .any(|d| domain == *d || domain.ends_with(&format!(".{d}")))
+            });
+            return ai_domain || is_ai_name(name);
+        }
+    }
+    let footer = ["generated with", "generated by", "generated using"]
+        .iter()
+        .any(|phrase| lower.contains(phrase));
+    footer
+        && (AI_DOMAINS.iter().any(|d| lower.contains(d))
+            || lower
+                .split(|c: char| !c.is_alphanumeric())
+                .any(|word| AI_NAMES.contains(&word)))
+}
+
+/// `Claude`, `ChatGPT`, `GPT-4o`, or `Claude Opus 4.6`, but not a person
+/// who shares a first name with an assistant, such as `Claude Monet`.
+fn is_ai_name(name: &str) -> bool {
+    let mut words = name.split(|c: char| c.is_whitespace() || c == '-');
+    let Some(first) = words.next() else {
+        return false;
+    };
+    AI_NAMES.contains(&first)
+        && words.all(|w| {
+            w.is_empty() || MODEL_WORDS.contains(&w) || w.starts_with(|c: char| c.is_ascii_digit())
+        })
+}
+
+/// `line` is the trimmed line and `lower` its lowercase fold. Rules whose
+/// ids are in `disabled` are skipped.
+pub(crate) fn check_commit_patterns(
+    line: &str,
+    lower: &str,
+    lineno: usize,
+    disabled: &HashSet<String>,
+    findings: &mut Vec<Finding>,
+) {
+    let on = |id: &str| !disabled.contains(id);
+
+    // AI attribution trailer or footer, on any line — Critical
+    if on("commit/ai-attribution") && is_ai_attribution(lower) {
+        findings.push(Finding {
+            line: lineno,
+            col: 0,
+            matched: line.to_string(),
+            message: "AI attribution in commit message: remove the trailer".to_string(),
+            rule_id: "commit/ai-attribution".to_string(),
+            replacement: Some(String::new()),
+            severity: Severity::Critical,
+            category: Category::Code,
+            region: Some(commit_region(lineno)),
+            ..Default::default()
+        });
+    }
+
+    // Vague commit verbs — Low
+    let vague = [
+        "update stuff",
+        "fix things",
+        "wip",
+        "misc changes",
+        "minor fixes",
+    ];
+    if on("commit/vague-message") {
+        for phrase in &vague {
+            if let Some(col) = lower.find(phrase) {
+                findings.push(Finding {
+                    line: lineno,
+                    col,
+                    matched: phrase.to_string(),
+                    message: format!("Vague commit message: '{}'", phrase),
+                    rule_id: "commit/vague-message".to_string(),
+                    replacement: None,
+                    severity: Severity::Low,
+                    category: Category::Code,
+                    region: Some(commit_region(lineno)),
+                    ..Default::default()
+                });
+            }
+        }
+    }
+
+    // Past tense in subject line — High
+    // source: lopes2024 icse — human commits use imperative; LLM commits use past tense
+    if lineno == 1 && on("commit/past-tense") {
+        // Skip a conventional commit prefix ("feat: added" -> check "added")
+        // and gitmoji ("✨ added", ":sparkles: added"); the fix rewrites the
+        // verb and leaves the rest alone.
+        let mut subject = words(line).filter(|(_, word)| !is_gitmoji(word));
+        let verb = match subject.next() {
+            Some((_, prefix)) if prefix.ends_with(':') => subject.next(),
+            first => first,
+        };
+        let past = verb.and_then(|(col, word)| {
+            imperative_of(&word.to_lowercase()).map(|imperative| (col, word, imperative))
+        });
+        if let Some((col, word, imperative)) = past {
+            findings.push(Finding {
+                line: lineno,
+                col,
+                matched: word.to_string(),
+                message: "Past tense in commit subject: use imperative mood ('add' not 'added')"
+                    .to_string(),
+                rule_id: "commit/past-tense".to_string(),
+                replacement: Some(imperative.to_string()),
+                severity: Severity::High,
+                category: Category::Code,
+                region: Some(commit_region(lineno)),
+                ..Default::default()
+            });
+        }
+    }
+
+    // Vague scope words in subject line — High
+    // source: lopes2024 — human commits name one specific thing
+    if lineno == 1 && on("commit/vague-scope") {
+        let vague_scope = ["various", "several", "multiple", "many"];
+        for word in &vague_scope {
+            let mut start = 0;
+            while let Some(pos) = lower[start..].find(word) {
+                let abs = start + pos;
+                let end = abs + word.len();
+                let before_ok =
+                    abs == 0 || !lower[..abs].chars().last().unwrap_or(' ').is_alphanumeric();
+                let after_ok = end >= lower.len()
+                    || !lower[end..].chars().next().unwrap_or(' ').is_alphanumeric();
+                if before_ok && after_ok {
+                    findings.push(Finding {
+                        line: lineno,
+                        col: abs,
+                        matched: word.to_string(),
+                        message: "Vague scope in commit subject: name the specific change"
+                            .to_string(),
+                        rule_id: "commit/vague-scope".to_string(),
+                        replacement: None,
+                        severity: Severity::High,
+                        category: Category::Code,
+                        region: Some(commit_region(lineno)),
+                        ..Default::default()
+                    });
+                    break; // one finding per word
+                }
+                start = end;
+            }
+        }
+    }
+
+    // Several changes joined in one subject — Medium
+    if lineno == 1 && on("commit/multiple-changes") {
+        let changes = count_subject_changes(lower);
+        if changes >= 2 {
+            findings.push(Finding {
+                line: lineno,
+                col: 0,
+                matched: line.to_string(),
+                message: format!(
+                    "Commit subject lists {} changes: make one commit per change",
+                    changes
+                ),
+                rule_id: "commit/multiple-changes".to_string(),
+                replacement: None,
+                severity: Severity::Medium,
+                category: Category::Code,
+                region: Some(commit_region(lineno)),
+                ..Default::default()
+            });
+        }
+    }
+
+    // Title-case subject line — Medium
+    if lineno == 1 && on("commit/title-case") {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        // Skip conventional commit prefix (word ending in ':')
+        let content_words: Vec<&str> = words
+            .iter()
+            .skip_while(|w| w.ends_with(':'))
+            .copied()
+            .collect();
+        let capitalized_count = content_words
+            .iter()
+            .filter(|w| w.chars().next().map(|c| c.is_uppercase()).unwrap_or(false))
+            .count();
+        if content_words.len() >= 3 && capitalized_count >= 3 {
+            findings.push(Finding {
+                line: lineno,
+                col: 0,
+                matched: line.to_string(),
+                message: "Title-case commit subject: use sentence case".to_string(),
+                rule_id: "commit/title-case".to_string(),
+                replacement: None,
+                severity: Severity::Medium,
+                category: Category::Code,
+                region: Some(commit_region(lineno)),
+                ..Default::default()
+            });
+        }
+    }
+
+    // Multiline body on single-purpose fix — Low
+    // source: arxiv2601.17406 — multiline commit ratio top fingerprint feature
+    if lineno == 3 && !line.trim().is_empty() && on("commit/body") {
+        findings.push(Finding {
+            line: lineno,
+            col: 0,
+            matched: line.to_string(),
+            message: "Commit body on single-purpose change may over-explain".to_string(),
+            rule_id: "commit/body".to_string(),
+            replacement: None,
+            severity: Severity::Low,
+            category: Category::Code,
+            region: Some(commit_region(lineno)),
+            source: Some(COMMIT_FINGERPRINT),
+            ..Default::default()
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::Severity;
+    use super::super::{apply_code_rules, CodeRule, LineContext};
+    use super::{generated_commit_lines, HashSet};
+
+    #[test]
+    fn generated_lines_cover_comments_and_squash_logs() {
+        let msg = "Add retry budget\n\
+            \n\
+            * commit 9f8e7d6 Updated various things\n\
+            * commit is not a hash\n\
+            # Conflicts:\n\
+            #\tsrc/client.rs\n\
+            Squashed commit of the following:\n\
+            \n\
+            commit 1234abc\n\
+            \x20   wip\n";
+        let mut lines: Vec<usize> = generated_commit_lines(msg, "#").into_iter().collect();
+        lines.sort();
+        assert_eq!(lines, vec![3, 5, 6, 7, 8, 9, 10]);
+    }
+
+    #[test]
+    fn scissors_line_ends_the_message() {
+        let msg =
+            "Add cache\n# ------------------------ >8 ------------------------\ndiff --git a b\n";
+        let mut lines: Vec<usize> = generated_commit_lines(msg, "#").into_iter().collect();
+        lines.sort();
+        assert_eq!(lines, vec![2, 3]);
+    }
+
+    #[test]
+    fn comment_char_is_configurable() {
+        let msg = "Add cache\n# not a comment here\n; Please enter the commit message\n\
+                   ; ------------------------ >8 ------------------------\ndiff --git a b\n";
+        let mut lines: Vec<usize> = generated_commit_lines(msg, ";").into_iter().collect();
+        lines.sort();
+        assert_eq!(lines, vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn commit_past_tense_fires() {
+        let findings = apply_code_rules(
+            &LineContext::split("Added authentication logic"),
+            &[CodeRule::Commits],
+            &HashSet::new(),
+        );
+        assert!(
+            findings
+                .iter()
+                .any(|f| f.message.contains("imperative mood")),
+            "past tense should fire, got: {:?}",
+            findings.iter().map(|f| &f.message).collect::<Vec<_>>()
+        );
+        let f = findings
+            .iter()
+            .find(|f| f.message.contains("imperative"))
+            .unwrap();
+        assert_eq!(f.severity, Severity::High);
+    }
+
+    #[test]
+    fn commit_imperative_no_fire() {
+        let findings = apply_code_rules(
+            &LineContext::split("Add authentication logic"),
+            &[CodeRule::Commits],
+            &HashSet::new(),
+        );
+        assert!(
+            !findings
+                .iter()
+                .any(|f| f.message.contains("imperative mood")),
+            "imperative mood should not fire for 'Add'"
+        );
+    }
+
+    #[test]
+    fn commit_conventional_prefix_past_tense_fires() {
+        let findings = apply_code_rules(
+            &LineContext::split("feat: added authentication logic"),
+            &[CodeRule::Commits],
+            &HashSet::new(),
+        );
+        assert!(
+            findings
+                .iter()
+                .any(|f| f.message.contains("imperative mood")),
+            "past tense should fire even with conventional commit prefix, got: {:?}",
+            findings.iter().map(|f| &f.message).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn commit_past_tense_after_gitmoji_fires() {
+        for subject in [
+            "✨ Added parser",
+            ":sparkles: Added parser",
+            "feat: 🐛 fixed parser",
+        ] {
+            let findings = apply_code_rules(
+                &LineContext::split(subject),
+                &[CodeRule::Commits],
+                &HashSet::new(),
+            );
+            assert!(
+                findings.iter().any(|f| f.rule_id == "commit/past-tense"),
+                "{subject}: {findings:?}"
+            );
+        }
+        assert_eq!(fix_past_tense("✨ Added parser"), "✨ Add parser");
+        assert_eq!(
+            fix_past_tense(":sparkles: Added parser"),
+            ":sparkles: Add parser"
+        );
+    }
+
+    /// `subject` with only the past-tense fix applied.
+    fn fix_past_tense(subject: &str) -> String {
+        let findings: Vec<_> = apply_code_rules(
+            &LineContext::split(subject),
+            &[CodeRule::Commits],
+            &HashSet::new(),
+        )
+        .into_iter()
+        .filter(|f| f.rule_id == "commit/past-tense")
+        .collect();
+        super::super::clean(subject, &findings)
+    }
+
+    #[test]
+    fn past_tense_fix_rewrites_the_verb() {
+        assert_eq!(
+            fix_past_tense("Added authentication logic"),
+            "Add authentication logic"
+        );
+        assert_eq!(fix_past_tense("dropped python 3.8"), "drop python 3.8");
+        assert_eq!(
+            fix_past_tense("Bumped serde to 1.0.200"),
+            "Bump serde to 1.0.200"
+        );
+        assert_eq!(
+            fix_past_tense("Addressed review comments"),
+            "Address review comments"
+        );
+    }
+
+    #[test]
+    fn past_tense_fix_keeps_the_conventional_prefix() {
+        let findings = apply_code_rules(
+            &LineContext::split("feat(auth): Implemented login"),
+            &[CodeRule::Commits],
+            &HashSet::new(),
+        );
+        let f = findings
+            .iter()
+            .find(|f| f.rule_id == "commit/past-tense")
+            .unwrap();
+        assert_eq!((f.col, f.matched.as_str()), (12, "Implemented"));
+        assert_eq!(
+            fix_past_tense("feat(auth): Implemented login"),
+            "feat(auth): Implement login"
+        );
+        assert_eq!(
+            fix_past_tense("fix: fixed fixed-width parsing"),
+            "fix: fix fixed-width parsing"
+        );
+    }
+
+    fn subject_changes(subject: &str) -> usize {
+        apply_code_rules(
+            &LineContext::split(subject),
+            &[CodeRule::Commits],
+            &HashSet::new(),
+        )
+        .iter()
+        .find(|f| f.message.starts_with("Commit subject lists"))
+        .map_or(1, |f| f.message.split(' ').nth(3).unwrap().parse().unwrap())
+    }
+
+    #[test]
+    fn multi_change_subject_fires() {
+        assert_eq!(
+            subject_changes("Add login page and fix navbar and update deps"),
+            3
+        );
+        assert_eq!(subject_changes("feat(ui): add dark mode, fix contrast"), 2);
+        assert_eq!(subject_changes("Fixed the parser & added tests"), 2);
+    }
+
+    #[test]
+    fn single_change_subject_no_fire() {
+        assert_eq!(subject_changes("Add drag and drop support"), 1);
+        assert_eq!(subject_changes("Add 'add and remove' buttons"), 1);
+        assert_eq!(subject_changes("fix(add and remove): keep order"), 1);
+        assert_eq!(subject_changes("Add login page and navbar"), 1);
+    }
+
+    #[test]
+    fn commit_vague_scope_fires() {
+        let findings = apply_code_rules(
+            &LineContext::split("Updated several files for release"),
+            &[CodeRule::Commits],
+            &HashSet::new(),
+        );
+        assert!(
+            findings.iter().any(|f| f.message.contains("Vague scope")),
+            "vague scope should fire, got: {:?}",
+            findings.iter().map(|f| &f.message).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn ai_attribution_trailers_fire_on_any_line() {
+        let msg = "Add retry budget\n\n\
+            Caps retries per request.\n\n\
+            🤖 Generated with [Claude Code](https://claude.com/claude-code)\n\
+            Co-Authored-By: Claude <noreply@anthropic.com>\n\
+            Co-authored-by: Claude Opus 4.6 <noreply@example.com>\n\
+            Signed-off-by: ChatGPT\n\
+            Co-authored-by: Claude Monet <claude@example.com>\n\
+            Co-authored-by: Ada Lovelace <ada@example.com>\n\
+            Reviewed-by: Grace Hopper <grace@openai.example>\n\
+            Generated by the protobuf compiler\n";
+        let findings = apply_code_rules(
+            &LineContext::split(msg),
+            &[CodeRule::Commits],
+            &HashSet::new(),
+        );
+        let lines: Vec<usize> = findings
+            .iter()
+            .filter(|f| f.rule_id == "commit/ai-attribution")
+            .map(|f| f.line)
+            .collect();
+        assert_eq!(lines, vec![5, 6, 7, 8]);
+        let cleaned = super::super::clean(msg, &findings);
+        assert!(!cleaned.contains("Claude Code"), "{cleaned}");
+        assert!(!cleaned.contains("ChatGPT"), "{cleaned}");
+        assert!(cleaned.contains("Claude Monet"), "{cleaned}");
+    }
+}
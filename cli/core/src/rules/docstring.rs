@@ -0,0 +1,314 @@
+//! Python docstrings, found by tracking `"""` and `'''` from line to line,
+//! and the Google-style sections inside them (`Args:`, `Returns:`,
+//! `Raises:`) that carry no information.
+
+use std::collections::HashSet;
+
+use super::line::LineContext;
+use super::{Category, Finding, Region, Severity};
+
+/// Descriptions that say nothing about what they describe, once lowercased,
+/// without a trailing full stop and without a leading article.
+const VACUOUS_DESCRIPTIONS: &[&str] = &["", "result", "value", "none", "nothing"];
+
+/// `Raises:` descriptions that fit any exception.
+const VACUOUS_RAISES: &[&str] = &[
+    "",
+    "if an error occurs",
+    "when an error occurs",
+    "if any error occurs",
+    "if something goes wrong",
+    "on error",
+    "on failure",
+];
+
+/// A docstring: its 0-based first line and the text of each of its lines
+/// with the quotes removed.
+pub(super) struct Docstring<'a> {
+    pub first: usize,
+    /// Each line's byte offset of the text after any opening quotes, and the
+    /// text up to any closing quotes.
+    pub body: Vec<(usize, &'a str)>,
+}
+
+/// Docstrings in `lines`: text between a `"""` or `'''` and the next of the
+/// same kind. A quote inside the other kind of docstring does not count.
+pub(super) fn docstrings<'a>(lines: &[LineContext<'a>]) -> Vec<Docstring<'a>> {
+    let mut found = Vec::new();
+    let mut open: Option<(&str, Docstring<'a>)> = None;
+    for (idx, ctx) in lines.iter().enumerate() {
+        let line = ctx.original;
+        let mut pos = 0;
+        loop {
+            match open.take() {
+                Some((quote, mut doc)) => match line[pos..].find(quote) {
+                    Some(end) => {
+                        doc.body.push((pos, &line[pos..pos + end]));
+                        found.push(doc);
+                        pos += end + quote.len();
+                    }
+                    None => {
+                        doc.body.push((pos, &line[pos..]));
+                        open = Some((quote, doc));
+                        break;
+                    }
+                },
+                None => {
+                    let next = ["\"\"\"", "'''"]
+                        .iter()
+                        .filter_map(|q| line[pos..].find(q).map(|i| (i, *q)))
+                        .min();
+                    let Some((start, quote)) = next else {
+                        break;
+                    };
+                    pos += start + quote.len();
+                    open = Some((
+                        quote,
+                        Docstring {
+                            first: idx,
+                            body: Vec::new(),
+                        },
+                    ));
+                }
+            }
+        }
+    }
+    found
+}
+
+/// The sections checked, by the header that opens them.
+#[derive(Clone, Copy, PartialEq)]
+enum Section {
+    Args,
+    Returns,
+    Raises,
+}
+
+fn section(header: &str) -> Option<Section> {
+    match header {
+        "Args" | "Arguments" | "Parameters" | "Params" => Some(Section::Args),
+        "Returns" | "Return" | "Yields" => Some(Section::Returns),
+        "Raises" => Some(Section::Raises),
+        _ => None,
+    }
+}
+
+/// `text` lowercased, without a trailing full stop or a leading article.
+fn normalize(text: &str) -> String {
+    let lower = text.trim().trim_end_matches('.').trim().to_lowercase();
+    ["the ", "a ", "an "]
+        .iter()
+        .find_map(|a| lower.strip_prefix(a))
+        .unwrap_or(&lower)
+        .trim()
+        .to_string()
+}
+
+/// An entry of a section: `name (type): description`, with its
+/// continuation lines joined onto the description.
+struct Entry {
+    /// 0-based line and byte offset where the entry starts.
+    line: usize,
+    col: usize,
+    text: String,
+    head: String,
+    description: String,
+}
+
+/// Flag each `Args:` entry whose description is empty, "the value" or "the
+/// result", or repeats the parameter name; a `Returns:` section that says
+/// nothing more than "None" or "the result"; and each `Raises:` entry that
+/// fits any exception, such as "Exception: if an error occurs". Each finding
+/// is at the entry's own line.
+pub(super) fn check_docstring_sections(
+    lines: &[LineContext],
+    disabled: &HashSet<String>,
+    findings: &mut Vec<Finding>,
+) {
+    if disabled.contains("code/vacuous-docstring") {
+        return;
+    }
+    for doc in docstrings(lines) {
+        let mut current: Option<(Section, usize, Vec<Entry>)> = None;
+        for (i, &(offset, text)) in doc.body.iter().enumerate() {
+            let line = doc.first + i;
+            let indent = text.len() - text.trim_start().len();
+            let trimmed = text.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+            if let Some((kind, header_indent, entries)) = &mut current {
+                if indent > *header_indent {
+                    match entries.last_mut() {
+                        Some(last) if offset + indent > last.col => {
+                            last.description.push(' ');
+                            last.description.push_str(trimmed);
+                        }
+                        _ => entries.push(entry(line, offset + indent, trimmed)),
+                    }
+                    continue;
+                }
+                report(*kind, std::mem::take(entries), findings);
+                current = None;
+            }
+            let (header, inline) = trimmed.split_once(':').unwrap_or((trimmed, ""));
+            if let Some(kind) = section(header) {
+                let mut entries = Vec::new();
+                if !inline.trim().is_empty() {
+                    let col = offset + indent + header.len() + 1;
+                    let col = col + (inline.len() - inline.trim_start().len());
+                    entries.push(entry(line, col, inline.trim()));
+                }
+                current = Some((kind, indent, entries));
+            }
+        }
+        if let Some((kind, _, entries)) = current {
+            report(kind, entries, findings);
+        }
+    }
+}
+
+fn entry(line: usize, col: usize, text: &str) -> Entry {
+    let (head, description) = text.split_once(':').unwrap_or((text, ""));
+    Entry {
+        line,
+        col,
+        text: text.to_string(),
+        head: head.trim().to_string(),
+        description: description.trim().to_string(),
+    }
+}
+
+fn report(kind: Section, entries: Vec<Entry>, findings: &mut Vec<Finding>) {
+    let mut flag = |e: &Entry, message: String| {
+        findings.push(Finding {
+            line: e.line + 1,
+            col: e.col,
+            matched: e.text.clone(),
+            message,
+            rule_id: "code/vacuous-docstring".to_string(),
+            replacement: None,
+            severity: Severity::Medium,
+            category: Category::Code,
+            region: Some(Region::CodeComment),
+            ..Default::default()
+        });
+    };
+    match kind {
+        Section::Args => {
+            for e in &entries {
+                let name = e.head.split(['(', ' ']).next().unwrap_or_default();
+                let described = normalize(&e.description);
+                if VACUOUS_DESCRIPTIONS.contains(&described.as_str())
+                    || described == name.to_lowercase().replace('_', " ")
+                {
+                    flag(
+                        e,
+                        format!("Docstring entry for '{name}' says nothing about it: describe what it is for, or drop it"),
+                    );
+                }
+            }
+        }
+        Section::Returns => {
+            // "bool: The result." carries its type before a colon.
+            let said: Vec<String> = entries
+                .iter()
+                .map(|e| match e.description.is_empty() {
+                    true => normalize(&e.head),
+                    false => normalize(&e.description),
+                })
+                .collect();
+            if let Some(first) = entries.first() {
+                if said
+                    .iter()
+                    .all(|s| VACUOUS_DESCRIPTIONS.contains(&s.as_str()))
+                {
+                    flag(
+                        first,
+                        "Docstring Returns section says nothing: describe the value, or drop the section".to_string(),
+                    );
+                }
+            }
+        }
+        Section::Raises => {
+            for e in &entries {
+                if VACUOUS_RAISES.contains(&normalize(&e.description).as_str()) {
+                    flag(
+                        e,
+                        format!("Docstring Raises entry for '{}' fits any error: say when it is raised, or drop it", e.head),
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vacuous(code: &str) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        check_docstring_sections(&LineContext::split(code), &HashSet::new(), &mut findings);
+        findings
+    }
+
+    #[test]
+    fn docstrings_span_lines_and_quote_kinds() {
+        let code = "x = 1\ndef f():\n    \"\"\"One line.\"\"\"\n    '''Two\n    lines with \"\"\" inside.\n    '''\n";
+        let lines = LineContext::split(code);
+        let found: Vec<(usize, usize)> = docstrings(&lines)
+            .iter()
+            .map(|d| (d.first, d.first + d.body.len() - 1))
+            .collect();
+        assert_eq!(found, [(2, 2), (3, 5)]);
+        assert_eq!(docstrings(&lines)[0].body, [(7, "One line.")]);
+    }
+
+    #[test]
+    fn vacuous_sections_flagged_at_their_lines() {
+        let code = r#"def get_user(user_id, retries, cache):
+    """Fetch a user.
+
+    Args:
+        user_id (int): The user ID.
+        retries (int): How many times to try
+            before giving up.
+        cache: The value.
+
+    Returns:
+        None.
+
+    Raises:
+        Exception: if an error occurs.
+        KeyError: when the user is unknown.
+    """
+"#;
+        let findings = vacuous(code);
+        let at: Vec<(usize, usize, &str)> = findings
+            .iter()
+            .map(|f| (f.line, f.col, f.matched.as_str()))
+            .collect();
+        assert_eq!(
+            at,
+            [
+                (5, 8, "user_id (int): The user ID."),
+                (8, 8, "cache: The value."),
+                (11, 8, "None."),
+                (14, 8, "Exception: if an error occurs."),
+            ]
+        );
+        assert!(findings[0].message.contains("'user_id'"), "{findings:?}");
+        assert!(findings.iter().all(|f| f.severity == Severity::Medium));
+    }
+
+    #[test]
+    fn informative_sections_are_kept() {
+        let code = "def f(path):\n    \"\"\"Read a file.\n\n    Args:\n        path: Where the config lives.\n\n    Returns: bool: True when the file was parsed.\n    \"\"\"\n";
+        assert!(vacuous(code).is_empty());
+        let inline = "def f():\n    \"\"\"Do it.\n\n    Returns: None.\n    \"\"\"\n";
+        assert_eq!(vacuous(inline)[0].col, 13);
+        let outside = "# Args:\n#     x: The x.\n";
+        assert!(vacuous(outside).is_empty());
+    }
+}
@@ -0,0 +1,489 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{is_word_boundary, Category, Finding, LineContext, Severity};
+
+/// An ignore directive and the lines it hides.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IgnoreDirective {
+    /// 1-based line of the directive itself (the opening line of a block).
+    pub line: usize,
+    /// Byte offset of the directive on its line.
+    pub col: usize,
+    /// The directive as written, trimmed.
+    pub text: String,
+    /// Rule ids the directive is scoped to; empty hides every rule.
+    pub rules: Vec<String>,
+    /// 1-based lines the directive covers. An unclosed block runs to the end.
+    pub covers: Vec<usize>,
+}
+
+impl IgnoreDirective {
+    /// Whether the directive hides `finding`.
+    fn hides(&self, finding: &Finding) -> bool {
+        (finding.line..=finding.last_line()).any(|line| self.covers.contains(&line))
+            && (self.rules.is_empty() || self.rules.contains(&finding.rule_id))
+    }
+}
+
+/// A whole-line directive, parsed.
+#[derive(Debug, PartialEq)]
+enum Directive {
+    HtmlStart(Vec<String>),
+    HtmlEnd,
+    BlockStart(Vec<String>),
+    BlockEnd,
+    NextLine(Vec<String>),
+}
+
+/// Every ignore directive in `content`, in order.
+///
+/// Supported directives:
+/// - `<!-- unai-ignore -->` ... `<!-- /unai-ignore -->` (HTML block)
+/// - `// unai-ignore-start` / `// unai-ignore-end` (code block, also `#` prefix)
+/// - `// unai-ignore-next-line` / `# unai-ignore-next-line` (next line only)
+/// - a trailing `<!-- unai-ignore -->`, `// unai-ignore` or `# unai-ignore`
+///   after text on the same line (that line only)
+///
+/// Any opening form takes a rule list to hide only those rules, as in
+/// `// unai-ignore-next-line: text/robust, text/comprehensive` or
+/// `<!-- unai-ignore: text/robust -->`.
+pub fn ignore_directives(content: &str) -> Vec<IgnoreDirective> {
+    let mut directives: Vec<IgnoreDirective> = Vec::new();
+    // Index into `directives` of the open HTML block, code block, and
+    // next-line directive.
+    let mut html_block: Option<usize> = None;
+    let mut code_block: Option<usize> = None;
+    let mut next_line: Option<usize> = None;
+
+    for (idx, line) in content.lines().enumerate() {
+        let lineno = idx + 1;
+        let trimmed = line.trim();
+
+        if let Some(open) = next_line.take() {
+            directives[open].covers.push(lineno);
+            continue;
+        }
+
+        let mut open = |slot: &mut Option<usize>, rules: Vec<String>| {
+            *slot = Some(directives.len());
+            directives.push(IgnoreDirective {
+                line: lineno,
+                col: line.len() - line.trim_start().len(),
+                text: trimmed.to_string(),
+                rules,
+                covers: Vec::new(),
+            });
+        };
+        match parse_directive(trimmed) {
+            Some(Directive::HtmlStart(rules)) => open(&mut html_block, rules),
+            Some(Directive::BlockStart(rules)) => open(&mut code_block, rules),
+            Some(Directive::NextLine(rules)) => open(&mut next_line, rules),
+            Some(Directive::HtmlEnd) => html_block = None,
+            Some(Directive::BlockEnd) => code_block = None,
+            None => {
+                for open in [html_block, code_block].into_iter().flatten() {
+                    directives[open].covers.push(lineno);
+                }
+                if let Some((col, rules)) = trailing_marker(line) {
+                    directives.push(IgnoreDirective {
+                        line: lineno,
+                        col,
+                        text: line[col..].trim_end().to_string(),
+                        rules,
+                        covers: vec![lineno],
+                    });
+                }
+            }
+        }
+    }
+
+    directives
+}
+
+/// The directive on a trimmed line, or `None` when the line is not one.
+fn parse_directive(trimmed: &str) -> Option<Directive> {
+    if let Some(inner) = html_comment(trimmed) {
+        if inner == "/unai-ignore" {
+            return Some(Directive::HtmlEnd);
+        }
+        return scoped(inner, "unai-ignore").map(Directive::HtmlStart);
+    }
+    let rest = ["// ", "# "]
+        .iter()
+        .find_map(|prefix| trimmed.strip_prefix(prefix))?;
+    match rest {
+        "unai-ignore-end" => Some(Directive::BlockEnd),
+        _ => scoped(rest, "unai-ignore-start")
+            .map(Directive::BlockStart)
+            .or_else(|| scoped(rest, "unai-ignore-next-line").map(Directive::NextLine)),
+    }
+}
+
+/// The text inside `<!-- ... -->`, trimmed.
+fn html_comment(text: &str) -> Option<&str> {
+    let inner = text.strip_prefix("<!--")?.strip_suffix("-->")?;
+    Some(inner.trim())
+}
+
+/// The rule list of `text` when it is `name` or `name: rule, rule`. An empty
+/// list means every rule.
+fn scoped(text: &str, name: &str) -> Option<Vec<String>> {
+    let rest = text.strip_prefix(name)?;
+    if rest.is_empty() {
+        return Some(Vec::new());
+    }
+    let list = rest.strip_prefix(':')?;
+    Some(
+        list.split(',')
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .map(str::to_string)
+            .collect(),
+    )
+}
+
+/// Byte offset and rule list of the end-of-line ignore marker on `line`, when
+/// the marker follows other text.
+fn trailing_marker(line: &str) -> Option<(usize, Vec<String>)> {
+    let body = line.trim_end();
+    let html = body
+        .ends_with("-->")
+        .then(|| body.rfind("<!--"))
+        .flatten()
+        .and_then(|col| Some((col, scoped(html_comment(&body[col..])?, "unai-ignore")?)));
+    let comment = || {
+        ["// ", "# "].iter().find_map(|prefix| {
+            let col = body.rfind(&format!("{prefix}unai-ignore"))?;
+            Some((col, scoped(&body[col + prefix.len()..], "unai-ignore")?))
+        })
+    };
+    html.or_else(comment)
+        .filter(|&(col, _)| !line[..col].trim().is_empty())
+}
+
+/// Byte offset of each end-of-line ignore marker, keyed by 1-based line
+/// number. Rules that match inside a marker's rule list are not findings.
+pub fn ignore_markers(content: &str) -> HashMap<usize, usize> {
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(idx, line)| Some((idx + 1, trailing_marker(line)?.0)))
+        .collect()
+}
+
+/// `line` without its end-of-line ignore marker and the whitespace before it,
+/// so the suppression comment stays out of cleaned text.
+pub fn strip_ignore_marker(line: &str) -> &str {
+    trailing_marker(line).map_or(line, |(col, _)| line[..col].trim_end())
+}
+
+/// Lines hidden by ignore directives, keyed by 1-based line number, with the
+/// rule ids hidden on each. An empty set hides every rule. See
+/// `ignore_directives` for the supported forms.
+pub fn collect_ignored_lines(content: &str) -> HashMap<usize, HashSet<String>> {
+    let mut ignored: HashMap<usize, HashSet<String>> = HashMap::new();
+    for d in ignore_directives(content) {
+        for line in d.covers {
+            match ignored.get_mut(&line) {
+                // Already hiding every rule.
+                Some(rules) if rules.is_empty() => {}
+                Some(_) if d.rules.is_empty() => {
+                    ignored.insert(line, HashSet::new());
+                }
+                Some(rules) => rules.extend(d.rules.iter().cloned()),
+                None => {
+                    ignored.insert(line, d.rules.iter().cloned().collect());
+                }
+            }
+        }
+    }
+    ignored
+}
+
+/// Whether `ignored`, from [`collect_ignored_lines`], hides `finding`. A
+/// finding spanning several lines is hidden when any of its lines is.
+pub fn is_ignored(ignored: &HashMap<usize, HashSet<String>>, finding: &Finding) -> bool {
+    (finding.line..=finding.last_line()).any(|line| {
+        ignored
+            .get(&line)
+            .is_some_and(|rules| rules.is_empty() || rules.contains(&finding.rule_id))
+    })
+}
+
+/// Low meta findings for the directives in `content` that hide none of
+/// `hidden`, the findings a directive suppressed.
+pub fn unused_directives<'a>(
+    content: &str,
+    hidden: impl IntoIterator<Item = &'a Finding>,
+) -> Vec<Finding> {
+    let hidden: Vec<&Finding> = hidden.into_iter().collect();
+    ignore_directives(content)
+        .into_iter()
+        .filter(|d| !hidden.iter().any(|f| d.hides(f)))
+        .map(|d| Finding {
+            line: d.line,
+            col: d.col,
+            message: format!("{UNUSED_SUPPRESSION}: the directive hides no finding, remove it"),
+            rule_id: "meta/unused-suppression".to_string(),
+            matched: d.text,
+            severity: Severity::Low,
+            category: Category::Meta,
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// A Low meta finding for a config entry that suppressed nothing. Config
+/// entries have no line in the input, so the finding's line is 0.
+pub fn unused_config_entry(setting: &str, value: &str) -> Finding {
+    Finding {
+        line: 0,
+        matched: value.to_string(),
+        message: format!(
+            "{UNUSED_SUPPRESSION}: {setting} entry '{value}' hides no finding, remove it"
+        ),
+        rule_id: "meta/unused-suppression".to_string(),
+        severity: Severity::Low,
+        category: Category::Meta,
+        ..Default::default()
+    }
+}
+
+/// Message prefix shared by the unused-suppression findings.
+const UNUSED_SUPPRESSION: &str = "Unused suppression";
+
+/// Byte ranges of each allow-phrase occurrence, keyed by 1-based line number.
+/// Phrases match case-insensitively on word boundaries.
+pub fn allowed_spans(content: &str, phrases: &[&str]) -> HashMap<usize, Vec<(usize, usize)>> {
+    let mut spans: HashMap<usize, Vec<(usize, usize)>> = HashMap::new();
+    if phrases.is_empty() {
+        return spans;
+    }
+    let phrases: Vec<String> = phrases.iter().map(|p| p.trim().to_lowercase()).collect();
+    for (idx, line) in content.lines().enumerate() {
+        let ctx = LineContext::new(line);
+        for phrase in &phrases {
+            for (start, _) in ctx.lower.match_indices(phrase.as_str()) {
+                let end = start + phrase.len();
+                if !is_word_boundary(&ctx.lower, start, end) {
+                    continue;
+                }
+                let span = ctx
+                    .to_original(start, end)
+                    .filter(|&(s, e)| is_word_boundary(ctx.original, s, e));
+                if let Some(span) = span {
+                    spans.entry(idx + 1).or_default().push(span);
+                }
+            }
+        }
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn html_block_ignore() {
+        let content =
+            "line 1\n<!-- unai-ignore -->\nline 3\nline 4\n<!-- /unai-ignore -->\nline 6\n";
+        let ignored = collect_ignored_lines(content);
+        assert!(!ignored.contains_key(&1));
+        assert!(ignored.contains_key(&3));
+        assert!(ignored.contains_key(&4));
+        assert!(!ignored.contains_key(&6));
+    }
+
+    #[test]
+    fn next_line_ignore() {
+        let content = "line 1\n# unai-ignore-next-line\nline 3\nline 4\n";
+        let ignored = collect_ignored_lines(content);
+        assert!(!ignored.contains_key(&1));
+        assert!(!ignored.contains_key(&2));
+        assert!(ignored.contains_key(&3));
+        assert!(!ignored.contains_key(&4));
+    }
+
+    #[test]
+    fn start_end_block() {
+        let content = "line 1\n// unai-ignore-start\nline 3\nline 4\n// unai-ignore-end\nline 6\n";
+        let ignored = collect_ignored_lines(content);
+        assert!(!ignored.contains_key(&1));
+        assert!(ignored.contains_key(&3));
+        assert!(ignored.contains_key(&4));
+        assert!(!ignored.contains_key(&6));
+    }
+
+    #[test]
+    fn hash_start_end_block() {
+        let content = "line 1\n# unai-ignore-start\nline 3\nline 4\n# unai-ignore-end\nline 6\n";
+        let ignored = collect_ignored_lines(content);
+        assert!(!ignored.contains_key(&1));
+        assert!(ignored.contains_key(&3));
+        assert!(ignored.contains_key(&4));
+        assert!(!ignored.contains_key(&6));
+    }
+
+    #[test]
+    fn slash_next_line_ignore() {
+        let content = "line 1\n// unai-ignore-next-line\nline 3\nline 4\n";
+        let ignored = collect_ignored_lines(content);
+        assert!(!ignored.contains_key(&1));
+        assert!(!ignored.contains_key(&2));
+        assert!(ignored.contains_key(&3));
+        assert!(!ignored.contains_key(&4));
+    }
+
+    #[test]
+    fn empty_content() {
+        assert!(collect_ignored_lines("").is_empty());
+    }
+
+    #[test]
+    fn directive_lines_not_ignored() {
+        let content = "<!-- unai-ignore -->\nline 2\n<!-- /unai-ignore -->\n";
+        let ignored = collect_ignored_lines(content);
+        assert!(!ignored.contains_key(&1));
+        assert!(ignored.contains_key(&2));
+        assert!(!ignored.contains_key(&3));
+    }
+
+    #[test]
+    fn next_line_at_end_of_file() {
+        let content = "line 1\n# unai-ignore-next-line\n";
+        let ignored = collect_ignored_lines(content);
+        assert!(ignored.is_empty() || !ignored.contains_key(&1));
+    }
+
+    #[test]
+    fn overlapping_blocks_both_cover() {
+        let content =
+            "<!-- unai-ignore -->\n# unai-ignore-start\nline 3\n# unai-ignore-end\nline 5\n";
+        let directives = ignore_directives(content);
+        assert_eq!(directives.len(), 2);
+        assert_eq!(directives[0].covers, vec![3, 5]);
+        assert_eq!(directives[1].covers, vec![3]);
+    }
+
+    #[test]
+    fn unused_directive_reported_at_its_line() {
+        let content = "a\n<!-- unai-ignore -->\nclean\n<!-- /unai-ignore -->\n  # unai-ignore-next-line\nhit\n";
+        let hit = |line| Finding {
+            line,
+            rule_id: "text/delve".to_string(),
+            ..Default::default()
+        };
+        let unused = unused_directives(content, &[hit(6)]);
+        assert_eq!(unused.len(), 1);
+        assert_eq!(unused[0].line, 2);
+        assert_eq!(unused[0].matched, "<!-- unai-ignore -->");
+        assert_eq!(unused[0].category, Category::Meta);
+        assert!(unused_directives(content, &[hit(3), hit(6)]).is_empty());
+    }
+
+    #[test]
+    fn trailing_markers_ignore_their_own_line() {
+        let content = "We need a robust API. <!-- unai-ignore -->\n\
+                       let userManager = x; // unai-ignore\n\
+                       robust = True  # unai-ignore\n\
+                       robust\n\
+                       # unai-ignore\n\
+                       robust\n";
+        let mut ignored: Vec<usize> = collect_ignored_lines(content).into_keys().collect();
+        ignored.sort();
+        assert_eq!(ignored, vec![1, 2, 3]);
+        let directives = ignore_directives(content);
+        assert_eq!(directives[1].col, 21);
+        assert_eq!(directives[1].text, "// unai-ignore");
+    }
+
+    #[test]
+    fn trailing_marker_needs_an_exact_suffix() {
+        let content = "a // unai-ignore-start\nb // unai-ignored\n";
+        assert!(collect_ignored_lines(content).is_empty());
+    }
+
+    #[test]
+    fn strip_ignore_marker_drops_marker_and_padding() {
+        assert_eq!(
+            strip_ignore_marker("We need a robust API. <!-- unai-ignore -->"),
+            "We need a robust API."
+        );
+        assert_eq!(
+            strip_ignore_marker("let x = 1; // unai-ignore  "),
+            "let x = 1;"
+        );
+        assert_eq!(strip_ignore_marker("# unai-ignore"), "# unai-ignore");
+        assert_eq!(strip_ignore_marker("plain"), "plain");
+    }
+
+    fn finding(line: usize, rule_id: &str) -> Finding {
+        Finding {
+            line,
+            rule_id: rule_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn scoped_directives_hide_only_listed_rules() {
+        let content = "A robust plan. // unai-ignore: text/robust, text/comprehensive\n\
+                       <!-- unai-ignore: text/delve -->\n\
+                       Let me delve.\n\
+                       <!-- /unai-ignore -->\n\
+                       # unai-ignore-next-line:text/robust\n\
+                       robust\n\
+                       // unai-ignore-start: text/robust\n\
+                       robust\n\
+                       // unai-ignore-end\n";
+        let ignored = collect_ignored_lines(content);
+        assert!(is_ignored(&ignored, &finding(1, "text/robust")));
+        assert!(is_ignored(&ignored, &finding(1, "text/comprehensive")));
+        assert!(!is_ignored(&ignored, &finding(1, "text/delve")));
+        assert!(is_ignored(&ignored, &finding(3, "text/delve")));
+        assert!(!is_ignored(&ignored, &finding(3, "text/robust")));
+        assert!(is_ignored(&ignored, &finding(6, "text/robust")));
+        assert!(is_ignored(&ignored, &finding(8, "text/robust")));
+        assert!(!is_ignored(&ignored, &finding(8, "text/delve")));
+        assert_eq!(ignored.len(), 4);
+    }
+
+    #[test]
+    fn blanket_directive_wins_over_scoped_one() {
+        let content = "<!-- unai-ignore -->\nrobust // unai-ignore: text/robust\n";
+        let ignored = collect_ignored_lines(content);
+        assert!(ignored[&2].is_empty());
+        assert!(is_ignored(&ignored, &finding(2, "text/delve")));
+    }
+
+    #[test]
+    fn scoped_directive_unused_when_only_other_rules_hit() {
+        let content = "// unai-ignore-next-line: text/robust\nLet me delve.\n";
+        let unused = unused_directives(content, &[finding(2, "text/delve")]);
+        assert_eq!(unused.len(), 1);
+        assert!(unused_directives(content, &[finding(2, "text/robust")]).is_empty());
+    }
+
+    #[test]
+    fn strip_ignore_marker_drops_scoped_markers() {
+        assert_eq!(
+            strip_ignore_marker("A robust API. <!-- unai-ignore: text/robust -->"),
+            "A robust API."
+        );
+        assert_eq!(
+            strip_ignore_marker("x = 1  # unai-ignore: text/robust"),
+            "x = 1"
+        );
+    }
+
+    #[test]
+    fn allow_phrase_spans_case_insensitive() {
+        let spans = allowed_spans(
+            "Comprehensive Income rose.\nA comprehensive incomes table.\n",
+            &["comprehensive income"],
+        );
+        assert_eq!(spans.get(&1), Some(&vec![(0, 20)]));
+        assert_eq!(spans.get(&2), None);
+    }
+}
@@ -0,0 +1,268 @@
+//! Properties `clean()` keeps for any input and any well-formed findings.
+//! Checked by the proptest suite below under `cargo test`, and by the fuzz
+//! target in `fuzz/` for longer local runs.
+//!
+//! Documents containing `\r` are out of scope: `str::lines` strips a
+//! trailing one, and line endings are `[output]` normalization's job.
+
+use std::collections::HashSet;
+
+use crate::rules::{apply_text_rules, clean_checked, Finding, Severity};
+
+/// Findings built from raw numbers, so both proptest and the fuzzer can
+/// drive them. Offsets are snapped to char boundaries of `content`; a
+/// `stale` finding claims text that is not there, as after an edit.
+#[derive(Debug, Clone)]
+pub struct RawFix {
+    pub line: usize,
+    pub start: usize,
+    pub len: usize,
+    /// Extra lines the match runs over; 0 for a single-line finding.
+    pub extra_lines: usize,
+    pub replacement: Option<String>,
+    pub severity: u8,
+    pub recapitalize_next: bool,
+    pub stale: bool,
+}
+
+impl RawFix {
+    /// The finding this describes in `content`, or `None` for an empty
+    /// document. Replacements never contain a newline.
+    pub fn to_finding(&self, content: &str) -> Option<Finding> {
+        let lines: Vec<&str> = content.lines().collect();
+        if lines.is_empty() {
+            return None;
+        }
+        let first = self.line % lines.len();
+        let last = (first + self.extra_lines).min(lines.len() - 1);
+        let block = lines[first..=last].join("\n");
+        let head = char_offsets(lines[first]);
+        let col = head[self.start % head.len()];
+        let last_start = block.len() - lines[last].len();
+        let tail: Vec<usize> = char_offsets(lines[last])
+            .into_iter()
+            .map(|o| last_start + o)
+            .filter(|&o| o >= col)
+            .collect();
+        let end = tail[self.len % tail.len()];
+        let mut matched = block[col..end].to_string();
+        if self.stale {
+            matched.push('~');
+        }
+        let severity = match self.severity % 4 {
+            0 => Severity::Low,
+            1 => Severity::Medium,
+            2 => Severity::High,
+            _ => Severity::Critical,
+        };
+        Some(Finding {
+            line: first + 1,
+            col,
+            end_line: (last > first).then_some(last + 1),
+            matched,
+            message: format!("fix {}", self.severity % 3),
+            replacement: self.replacement.as_ref().map(|r| r.replace('\n', " ")),
+            recapitalize_next: self.recapitalize_next,
+            severity,
+            ..Default::default()
+        })
+    }
+}
+
+/// Byte offsets of every char boundary in `line`, end included.
+fn char_offsets(line: &str) -> Vec<usize> {
+    line.char_indices()
+        .map(|(i, _)| i)
+        .chain([line.len()])
+        .collect()
+}
+
+/// Check one `clean()` run. The output is a `String`, so it is valid UTF-8 by
+/// construction; beyond that:
+/// - only lines a deleting fix touches can disappear, and the blank line
+///   above one that reaches the end of the document; no line appears;
+/// - lines no finding touches come through byte-identical, in order;
+/// - a trailing newline is kept.
+pub fn check_clean(content: &str, findings: &[Finding]) -> Result<(), String> {
+    let (cleaned, _) = clean_checked(content, findings, None);
+    if content.contains('\r') {
+        return Ok(());
+    }
+    let input: Vec<&str> = content.lines().collect();
+    let mut touched = HashSet::new();
+    let mut deleting = HashSet::new();
+    for f in findings.iter().filter(|f| f.replacement.is_some()) {
+        let lines = f.line..=f.last_line();
+        touched.extend(lines.clone());
+        if f.replacement.as_deref() == Some("") || f.last_line() > f.line {
+            deleting.extend(lines);
+        }
+    }
+    // Deleting the end of the document takes the blank line above it along.
+    if deleting.contains(&input.len()) {
+        let tail = (1..=input.len())
+            .rev()
+            .find(|line| !deleting.contains(line));
+        if let Some(line) = tail.filter(|&line| input[line - 1].trim().is_empty()) {
+            touched.insert(line);
+            deleting.insert(line);
+        }
+    }
+    // `clean` joins the kept lines, so split on every newline to get them
+    // back. An empty result is one blank line when some line could not be
+    // dropped, and no line otherwise.
+    let body = match content.ends_with('\n') {
+        true => cleaned.strip_suffix('\n').unwrap_or(&cleaned),
+        false => &cleaned,
+    };
+    let kept_some = (1..=input.len()).any(|line| !deleting.contains(&line));
+    let output: Vec<&str> = match body {
+        "" if !kept_some => Vec::new(),
+        body => body.split('\n').collect(),
+    };
+    if output.len() > input.len() {
+        return Err(format!("{} lines became {}", input.len(), output.len()));
+    }
+    let dropped = input.len() - output.len();
+    if dropped > deleting.len() {
+        return Err(format!(
+            "{dropped} lines dropped, but only {} lines have a deleting fix",
+            deleting.len()
+        ));
+    }
+
+    let mut rest = output.iter();
+    for (idx, line) in input.iter().enumerate() {
+        if touched.contains(&(idx + 1)) {
+            continue;
+        }
+        if !rest.any(|out| out == line) {
+            return Err(format!(
+                "untouched line {} {line:?} changed or moved",
+                idx + 1
+            ));
+        }
+    }
+
+    if content.ends_with('\n') && !cleaned.ends_with('\n') {
+        return Err("trailing newline changed".to_string());
+    }
+    Ok(())
+}
+
+/// Passes [`check_converges`] allows before calling it a loop.
+const MAX_PASSES: usize = 4;
+
+/// Cleaning with the built-in text rules, rerun on its own output, settles
+/// within a few passes and then changes nothing. One pass is not always a
+/// fixed point: deleting "It is worth noting that" can leave a closer such as
+/// "I hope this helps." standing alone, which the next pass removes.
+pub fn check_converges(content: &str) -> Result<(), String> {
+    if content.contains('\r') {
+        return Ok(());
+    }
+    let mut text = content.to_string();
+    for _ in 0..MAX_PASSES {
+        let next = clean_checked(&text, &apply_text_rules(&text), None).0;
+        if next == text {
+            return Ok(());
+        }
+        text = next;
+    }
+    Err(format!(
+        "still changing after {MAX_PASSES} passes: {content:?} became {text:?}"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Words the rules react to, mixed with arbitrary text so the fixes
+    /// land next to multi-byte characters and odd whitespace.
+    const VOCAB: &[&str] = &[
+        "delve",
+        "Certainly!",
+        "utilize",
+        "It is worth noting that",
+        "Furthermore,",
+        "robust",
+        "I hope this helps.",
+        "In conclusion,",
+        "tapestry",
+        "—",
+        "é",
+        "日本",
+        "the",
+        "data.",
+        "  ",
+    ];
+
+    fn line() -> impl Strategy<Value = String> {
+        let word = prop_oneof![
+            3 => prop::sample::select(VOCAB).prop_map(str::to_string),
+            1 => "[^\r\n]{0,6}",
+        ];
+        prop::collection::vec(word, 0..8).prop_map(|words| words.join(" "))
+    }
+
+    fn document() -> impl Strategy<Value = String> {
+        (prop::collection::vec(line(), 0..8), any::<bool>()).prop_map(|(lines, newline)| {
+            let mut doc = lines.join("\n");
+            if newline {
+                doc.push('\n');
+            }
+            doc
+        })
+    }
+
+    fn raw_fix() -> impl Strategy<Value = RawFix> {
+        (
+            (any::<usize>(), any::<usize>(), any::<usize>()),
+            prop_oneof![4 => Just(0usize), 1 => 1usize..3],
+            prop::option::of(prop_oneof![Just(String::new()), "[^\r\n]{0,8}"]),
+            (any::<u8>(), any::<bool>(), prop::bool::weighted(0.1)),
+        )
+            .prop_map(
+                |((line, start, len), extra_lines, replacement, (severity, recap, stale))| RawFix {
+                    line,
+                    start,
+                    len,
+                    extra_lines,
+                    replacement,
+                    severity,
+                    recapitalize_next: recap,
+                    stale,
+                },
+            )
+    }
+
+    proptest! {
+        #[test]
+        fn clean_keeps_invariants(
+            content in document(),
+            raw in prop::collection::vec(raw_fix(), 0..6),
+        ) {
+            let findings: Vec<Finding> =
+                raw.iter().filter_map(|r| r.to_finding(&content)).collect();
+            if let Err(e) = check_clean(&content, &findings) {
+                prop_assert!(false, "{e}\nfindings: {findings:#?}");
+            }
+        }
+
+        #[test]
+        fn text_rules_clean_converges(content in document()) {
+            if let Err(e) = check_converges(&content) {
+                prop_assert!(false, "{e}");
+            }
+        }
+
+        #[test]
+        fn text_rule_fixes_keep_invariants(content in document()) {
+            if let Err(e) = check_clean(&content, &apply_text_rules(&content)) {
+                prop_assert!(false, "{e}");
+            }
+        }
+    }
+}
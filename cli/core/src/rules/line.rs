@@ -0,0 +1,112 @@
+/// One input line with its lowercase fold and offset tables, built once per
+/// document and shared by the text, user, and code rule passes.
+///
+/// `.to_lowercase()` can change byte lengths for some Unicode characters
+/// (e.g. 'İ' → 'i̇'), so a byte offset found in `lower` must go through
+/// [`LineContext::to_original`] before it is used to slice `original`.
+pub struct LineContext<'a> {
+    pub original: &'a str,
+    pub lower: String,
+    /// `(lower, original)` byte offsets at the start of each original char,
+    /// plus a one-past-the-end sentinel. Sorted by both components.
+    boundaries: Vec<(usize, usize)>,
+    /// Byte ranges of `original` between an opening backtick and the next
+    /// backtick (or the end of the line when unclosed), backticks excluded.
+    backtick_spans: Vec<(usize, usize)>,
+}
+
+impl<'a> LineContext<'a> {
+    pub fn new(original: &'a str) -> Self {
+        let lower = original.to_lowercase();
+        LineContext {
+            boundaries: boundaries(original),
+            backtick_spans: backtick_spans(original),
+            original,
+            lower,
+        }
+    }
+
+    /// Contexts for every line of `content`, as split by `str::lines`.
+    pub fn split(content: &'a str) -> Vec<Self> {
+        content.lines().map(LineContext::new).collect()
+    }
+
+    /// Map the byte range `[start, end)` of `lower` to the same characters in
+    /// `original`. `None` when either offset falls inside the fold of a
+    /// single original char.
+    pub fn to_original(&self, start: usize, end: usize) -> Option<(usize, usize)> {
+        let original = |byte: usize| {
+            let i = self.boundaries.partition_point(|&(b, _)| b < byte);
+            self.boundaries
+                .get(i)
+                .filter(|&&(b, _)| b == byte)
+                .map(|&(_, o)| o)
+        };
+        Some((original(start)?, original(end)?))
+    }
+
+    /// Whether the byte range `[start, end)` of `original` falls inside a
+    /// single inline backtick span.
+    pub fn in_backtick_span(&self, start: usize, end: usize) -> bool {
+        self.backtick_spans
+            .iter()
+            .any(|&(s, e)| s <= start && start < e && end <= e)
+    }
+}
+
+/// A char can lowercase to several chars (e.g. 'İ' → 'i̇'), so offsets are
+/// tracked per original char rather than per char index.
+fn boundaries(original: &str) -> Vec<(usize, usize)> {
+    let mut lower_len = 0;
+    let mut boundaries = Vec::with_capacity(original.len() + 1);
+    for (i, c) in original.char_indices() {
+        boundaries.push((lower_len, i));
+        lower_len += c.to_lowercase().map(char::len_utf8).sum::<usize>();
+    }
+    boundaries.push((lower_len, original.len()));
+    boundaries
+}
+
+/// Backticks toggle in and out of a span one character at a time; an unclosed
+/// span runs to the end of the line.
+fn backtick_spans(line: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut open = None;
+    for (i, c) in line.char_indices() {
+        if c == '`' {
+            match open.take() {
+                Some(start) => spans.push((start, i)),
+                None => open = Some(i + 1),
+            }
+        }
+    }
+    if let Some(start) = open {
+        spans.push((start, line.len()));
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_lowercase_offsets_across_length_changes() {
+        // 'İ' (2 bytes) lowercases to "i̇" (3 bytes).
+        let ctx = LineContext::new("İx Utilize");
+        let start = ctx.lower.find("utilize").unwrap();
+        let (col, end) = ctx.to_original(start, start + "utilize".len()).unwrap();
+        assert_eq!(&ctx.original[col..end], "Utilize");
+        // Inside the expanded 'i̇' there is no matching original offset.
+        assert_eq!(ctx.to_original(1, 3), None);
+    }
+
+    #[test]
+    fn backtick_spans_exclude_backticks() {
+        let ctx = LineContext::new("a `code` b ``x `open");
+        assert!(ctx.in_backtick_span(3, 7));
+        assert!(!ctx.in_backtick_span(2, 7));
+        assert!(!ctx.in_backtick_span(3, 8));
+        assert!(ctx.in_backtick_span(16, 20));
+    }
+}
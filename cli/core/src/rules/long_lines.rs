@@ -0,0 +1,83 @@
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+use super::{Category, Finding, Severity};
+
+/// Blank out lines longer than `max_bytes` so the lexical and structural rules
+/// skip them, and report each such line once. Minified files and one-line
+/// dumps can run to megabytes, which the per-line rules are not built for.
+/// Line numbers are unchanged. A `max_bytes` of 0 keeps every line.
+pub fn mask_long_lines(content: &str, max_bytes: usize) -> (Cow<'_, str>, Vec<Finding>) {
+    if max_bytes == 0 {
+        return (Cow::Borrowed(content), Vec::new());
+    }
+    let findings: Vec<Finding> = content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| line.len() > max_bytes)
+        .map(|(idx, line)| Finding {
+            line: idx + 1,
+            col: 0,
+            matched: line.to_string(),
+            message: format!(
+                "Line too long to analyze ({} bytes, limit {}): rules skipped it",
+                line.len(),
+                max_bytes
+            ),
+            rule_id: "input/long-line".to_string(),
+            replacement: None,
+            severity: Severity::Low,
+            category: Category::Structural,
+            ..Default::default()
+        })
+        .collect();
+    let long: HashSet<usize> = findings.iter().map(|f| f.line).collect();
+    (blank_lines(Cow::Borrowed(content), &long), findings)
+}
+
+/// `content` with the 1-based `lines` emptied, keeping their line endings so
+/// every other line keeps its number.
+pub fn blank_lines<'a>(content: Cow<'a, str>, lines: &HashSet<usize>) -> Cow<'a, str> {
+    if lines.is_empty() {
+        return content;
+    }
+    let mut masked = String::with_capacity(content.len());
+    for (idx, raw) in content.split_inclusive('\n').enumerate() {
+        if !lines.contains(&(idx + 1)) {
+            masked.push_str(raw);
+            continue;
+        }
+        let line = raw.strip_suffix('\n').unwrap_or(raw);
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        masked.push_str(&raw[line.len()..]);
+    }
+    Cow::Owned(masked)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn long_lines_blanked_and_reported() {
+        let long = "utilize ".repeat(4);
+        let input = format!("Short.\r\n{}\r\nEnd", long);
+        let (masked, findings) = mask_long_lines(&input, 20);
+        assert_eq!(masked, "Short.\r\n\r\nEnd");
+        assert_eq!(findings.len(), 1);
+        assert_eq!(
+            (findings[0].line, findings[0].matched.as_str()),
+            (2, long.as_str())
+        );
+        assert!(findings[0].message.contains("32 bytes, limit 20"));
+    }
+
+    #[test]
+    fn short_input_borrowed() {
+        let (masked, findings) = mask_long_lines("a\nb\n", 20);
+        assert!(matches!(masked, Cow::Borrowed(_)));
+        assert!(findings.is_empty());
+        let long = "x".repeat(50);
+        assert!(mask_long_lines(&long, 0).1.is_empty());
+    }
+}
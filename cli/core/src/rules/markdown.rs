@@ -0,0 +1,427 @@
+use std::collections::HashSet;
+
+/// Byte ranges of `line` that belong to Markdown link syntax rather than
+/// prose: inline link destinations and titles, reference labels, reference
+/// definitions, and angle-bracket autolinks. Link text and image alt text
+/// stay outside the ranges so they are still linted.
+pub(super) fn link_skip_ranges(line: &str) -> Vec<(usize, usize)> {
+    if let Some(start) = reference_definition(line) {
+        return vec![(start, line.len())];
+    }
+    let bytes = line.as_bytes();
+    let mut ranges = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 1,
+            b'`' => i = code_span_end(bytes, i),
+            b'<' => {
+                if let Some(end) = autolink_end(line, i) {
+                    ranges.push((i, end));
+                    i = end;
+                    continue;
+                }
+            }
+            b'[' => {
+                if let Some(close) = matching(bytes, i, b'[', b']') {
+                    // Scan the link text itself on the next pass; only the part
+                    // after `]` is skipped.
+                    match bytes.get(close + 1) {
+                        Some(b'(') => {
+                            if let Some(end) = destination_end(bytes, close + 1) {
+                                ranges.push((close + 1, end));
+                            }
+                        }
+                        Some(b'[') => {
+                            if let Some(end) = matching(bytes, close + 1, b'[', b']') {
+                                ranges.push((close + 1, end + 1));
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    ranges
+}
+
+/// Whether `[start, end)` overlaps any of `ranges`.
+pub(super) fn overlaps(ranges: &[(usize, usize)], start: usize, end: usize) -> bool {
+    ranges.iter().any(|&(s, e)| start < e && s < end)
+}
+
+/// Start of a reference definition (`[label]: destination "title"`), which
+/// is link syntax from its opening bracket to the end of the line.
+fn reference_definition(line: &str) -> Option<usize> {
+    let indent = line.len() - line.trim_start_matches(' ').len();
+    if indent > 3 || !line[indent..].starts_with('[') {
+        return None;
+    }
+    let close = matching(line.as_bytes(), indent, b'[', b']')?;
+    let rest = line[close + 1..].strip_prefix(':')?;
+    (!rest.trim().is_empty()).then_some(indent)
+}
+
+/// Index just past an angle-bracket autolink (`<https://…>`, `<a@b.c>`)
+/// opening at `open`.
+fn autolink_end(line: &str, open: usize) -> Option<usize> {
+    let len = line[open + 1..].find(['>', '<', ' '])?;
+    let inner = &line[open + 1..open + 1 + len];
+    if !line[open + 1 + len..].starts_with('>') {
+        return None;
+    }
+    let is_uri = inner.split_once(':').is_some_and(|(scheme, _)| {
+        (2..=32).contains(&scheme.len())
+            && scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '.' | '-'))
+    });
+    let is_email = inner
+        .split_once('@')
+        .is_some_and(|(user, host)| !user.is_empty() && host.contains('.'));
+    (is_uri || is_email).then_some(open + len + 2)
+}
+
+/// Index just past the `)` closing an inline link destination that opens at
+/// `open`. Handles `<…>` destinations, balanced parentheses, quoted titles,
+/// and backslash escapes.
+fn destination_end(bytes: &[u8], open: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut i = open + 1;
+    if bytes.get(i) == Some(&b'<') {
+        i += bytes[i..].iter().position(|&b| b == b'>')? + 1;
+    }
+    while i < bytes.len() {
+        let b = bytes[i];
+        match (quote, b) {
+            (_, b'\\') => i += 1,
+            (Some(q), _) if b == q => quote = None,
+            (Some(_), _) => {}
+            (None, b'"' | b'\'') if bytes[i - 1].is_ascii_whitespace() => quote = Some(b),
+            (None, b'(') => depth += 1,
+            (None, b')') if depth == 0 => return Some(i + 1),
+            (None, b')') => depth -= 1,
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Index of the bracket closing the one at `open`, allowing nesting and
+/// backslash escapes.
+fn matching(bytes: &[u8], open: usize, left: u8, right: u8) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut i = open;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 1,
+            b if b == left => depth += 1,
+            b if b == right => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Index of the last backtick closing the code span that opens at `open`,
+/// or of the opening run itself when it is never closed.
+fn code_span_end(bytes: &[u8], open: usize) -> usize {
+    let run = bytes[open..].iter().take_while(|&&b| b == b'`').count();
+    let fence = &bytes[open..open + run];
+    let mut i = open + run;
+    while i + run <= bytes.len() {
+        if &bytes[i..i + run] == fence {
+            return i + run - 1;
+        }
+        i += 1;
+    }
+    open + run - 1
+}
+
+/// An ATX heading (`## Title`).
+pub(super) struct Heading<'a> {
+    /// 1-based line number.
+    pub line: usize,
+    /// Number of `#` marks, 1 to 6.
+    pub level: usize,
+    /// Byte offset of `text` within the line.
+    pub col: usize,
+    /// Heading text without the marks or a closing `#` sequence.
+    pub text: &'a str,
+}
+
+/// ATX headings in `content`, outside fenced code blocks.
+pub(super) fn headings(content: &str) -> Vec<Heading<'_>> {
+    let mut headings = Vec::new();
+    let mut fences = Fences::default();
+    for (idx, line) in content.lines().enumerate() {
+        if fences.in_code(line) {
+            continue;
+        }
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+        if indent > 3 {
+            continue;
+        }
+        let level = trimmed.bytes().take_while(|&b| b == b'#').count();
+        let rest = &trimmed[level..];
+        if !(1..=6).contains(&level) || !(rest.is_empty() || rest.starts_with([' ', '\t'])) {
+            continue;
+        }
+        let text = rest.trim();
+        // A closing sequence counts only when separated by a space.
+        let unclosed = text.trim_end_matches('#');
+        let text = if unclosed.is_empty() || unclosed.ends_with([' ', '\t']) {
+            unclosed.trim_end()
+        } else {
+            text
+        };
+        let col = if text.is_empty() {
+            line.len()
+        } else {
+            indent + level + (rest.len() - rest.trim_start().len())
+        };
+        headings.push(Heading {
+            line: idx + 1,
+            level,
+            col,
+            text,
+        });
+    }
+    headings
+}
+
+/// 1-based line closing the frontmatter block that opens on the first line:
+/// YAML between `---` fences (or closed by `...`), or TOML between `+++`
+/// fences. `None` when the document has no frontmatter.
+pub fn frontmatter_end<'a>(lines: impl IntoIterator<Item = &'a str>) -> Option<usize> {
+    let mut lines = lines.into_iter();
+    let closers: &[&str] = match lines.next()?.trim_end() {
+        "---" => &["---", "..."],
+        "+++" => &["+++"],
+        _ => return None,
+    };
+    lines
+        .position(|l| closers.contains(&l.trim_end()))
+        .map(|i| i + 2)
+}
+
+/// Lines of the fenced code blocks in a Markdown document.
+#[derive(Debug, Default, PartialEq)]
+pub struct CodeBlocks {
+    /// 1-based lines of the opening and closing fences.
+    pub fences: HashSet<usize>,
+    /// 1-based lines between the fences.
+    pub bodies: HashSet<usize>,
+}
+
+/// Where a line stands relative to the fenced code blocks around it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Fenced {
+    Outside,
+    Fence,
+    Body,
+}
+
+/// Fenced code block state fed one line at a time. A fence is a run of three
+/// or more backticks or tildes, indented or not so that fences inside list
+/// items count, followed by an optional info string (`rust`, `python
+/// title="x"`). The block closes at a run of the same character at least as
+/// long, with nothing after it; an unclosed block runs to the end.
+#[derive(Debug, Default)]
+pub(super) struct Fences {
+    /// Fence character and run length of the open block.
+    open: Option<(u8, usize)>,
+}
+
+impl Fences {
+    /// Classify the next line, updating the open block.
+    fn line(&mut self, line: &str) -> Fenced {
+        let trimmed = line.trim_start();
+        let marker = trimmed.bytes().next().filter(|b| matches!(b, b'`' | b'~'));
+        let run = marker.map_or(0, |m| trimmed.bytes().take_while(|&b| b == m).count());
+        match self.open {
+            Some((m, len)) => {
+                if marker == Some(m) && run >= len && trimmed[run..].trim().is_empty() {
+                    self.open = None;
+                    Fenced::Fence
+                } else {
+                    Fenced::Body
+                }
+            }
+            None => {
+                let Some(m) = marker.filter(|_| run >= 3) else {
+                    return Fenced::Outside;
+                };
+                // A backtick info string cannot hold a backtick, so
+                // "```code``` inline" is not a fence.
+                if m == b'`' && trimmed[run..].contains('`') {
+                    return Fenced::Outside;
+                }
+                self.open = Some((m, run));
+                Fenced::Fence
+            }
+        }
+    }
+
+    /// Whether the next line is a fence or inside a block.
+    pub(super) fn in_code(&mut self, line: &str) -> bool {
+        self.line(line) != Fenced::Outside
+    }
+}
+
+/// The fenced code blocks of `content`, as [`Fences`] reads them.
+pub fn code_blocks(content: &str) -> CodeBlocks {
+    let mut blocks = CodeBlocks::default();
+    let mut fences = Fences::default();
+    for (idx, line) in content.lines().enumerate() {
+        match fences.line(line) {
+            Fenced::Outside => {}
+            Fenced::Fence => {
+                blocks.fences.insert(idx + 1);
+            }
+            Fenced::Body => {
+                blocks.bodies.insert(idx + 1);
+            }
+        }
+    }
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::apply_text_rules;
+    use super::*;
+
+    fn flagged(input: &str) -> Vec<String> {
+        apply_text_rules(input)
+            .into_iter()
+            .map(|f| f.matched.to_lowercase())
+            .collect()
+    }
+
+    #[test]
+    fn inline_link_text_linted_destination_skipped() {
+        let input = "See [a comprehensive guide](https://x/utilize-now) today.";
+        assert_eq!(flagged(input), vec!["comprehensive"]);
+    }
+
+    #[test]
+    fn link_title_and_nested_brackets() {
+        let input = "Read [the [robust] notes](<a b/utilize> \"a seamlessly (title)\") here.";
+        assert_eq!(flagged(input), vec!["robust"]);
+        let input = "Use [docs](https://x/(utilize)) to delve.";
+        assert_eq!(flagged(input), vec!["delve"]);
+    }
+
+    #[test]
+    fn reference_links_and_definitions() {
+        let input = "The [robust setup][utilize] works.\n\n[utilize]: https://x/seamlessly \"comprehensive\"\n";
+        assert_eq!(flagged(input), vec!["robust"]);
+    }
+
+    #[test]
+    fn autolinks_skipped() {
+        let input = "Mail <utilize@robust.io> or open <https://x/comprehensive> to delve.";
+        assert_eq!(flagged(input), vec!["delve"]);
+        assert_eq!(flagged("a <utilize> tag"), vec!["utilize"]);
+    }
+
+    #[test]
+    fn blockquotes_skipped() {
+        let input =
+            "> We delve into a robust plan.\n  >> nested utilize\n>\nMy own robust reply.\n";
+        assert_eq!(flagged(input), vec!["robust"]);
+        assert_eq!(apply_text_rules(input)[0].line, 4);
+    }
+
+    #[test]
+    fn github_alerts_still_linted() {
+        let input = "> [!NOTE]\n> A robust default.\n\n> Quoted robust text.\n";
+        let lines: Vec<usize> = apply_text_rules(input).iter().map(|f| f.line).collect();
+        assert_eq!(lines, vec![2]);
+    }
+
+    #[test]
+    fn reference_definition_with_nested_parentheses() {
+        let input = "[1]: https://x/(comprehensive)-delve \"robust\"\nSee [the guide][1].\n";
+        assert!(flagged(input).is_empty());
+        let input = "[a seamless guide](https://x/a_(utilize_(robust))) ok";
+        assert_eq!(flagged(input), vec!["seamless"]);
+    }
+
+    #[test]
+    fn image_alt_linted_source_and_title_skipped() {
+        let input = "![a robust diagram](img/utilize.png \"seamlessly\")";
+        assert_eq!(flagged(input), vec!["robust"]);
+    }
+
+    #[test]
+    fn brackets_in_code_spans_ignored() {
+        assert_eq!(link_skip_ranges("`[a](b)` [c](d)"), vec![(12, 15)]);
+    }
+
+    #[test]
+    fn atx_headings_extracted() {
+        let doc =
+            "# Title #\n\n##  Setup\n#hashtag\n```\n# comment\n```\n    # indented code\n###\n";
+        let found: Vec<(usize, usize, usize, &str)> = headings(doc)
+            .iter()
+            .map(|h| (h.line, h.level, h.col, h.text))
+            .collect();
+        assert_eq!(
+            found,
+            vec![(1, 1, 2, "Title"), (3, 2, 4, "Setup"), (9, 3, 3, "")]
+        );
+    }
+
+    #[test]
+    fn frontmatter_fences() {
+        let end = |doc: &str| frontmatter_end(doc.lines());
+        assert_eq!(end("---\ntitle: x\n---\nbody\n"), Some(3));
+        assert_eq!(end("---\ntitle: x\n...\nbody\n"), Some(3));
+        assert_eq!(end("+++\ntitle = \"x\"\n+++\n"), Some(3));
+        assert_eq!(end("+++\ntitle = \"x\"\n---\n"), None);
+        assert_eq!(end("body\n---\nmore\n---\n"), None);
+        assert_eq!(end("---\nunclosed\n"), None);
+    }
+
+    #[test]
+    fn code_blocks_handle_info_strings_tildes_and_list_items() {
+        let content = "Intro\n\
+                       ```rust title=\"main.rs\"\n\
+                       fn main() {}\n\
+                       ```\n\
+                       ~~~~\n\
+                       ```\n\
+                       still code\n\
+                       ~~~~\n\
+                       - Step one:\n\
+                       \x20 ```sh\n\
+                       \x20 make\n\
+                       \x20 ```\n\
+                       Use ```inline``` spans.\n\
+                       ````\n\
+                       unclosed\n";
+        let blocks = code_blocks(content);
+        let sorted = |set: &HashSet<usize>| {
+            let mut lines: Vec<usize> = set.iter().copied().collect();
+            lines.sort();
+            lines
+        };
+        assert_eq!(sorted(&blocks.fences), [2, 4, 5, 8, 10, 12, 14]);
+        assert_eq!(sorted(&blocks.bodies), [3, 6, 7, 11, 15]);
+    }
+}
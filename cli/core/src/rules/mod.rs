@@ -0,0 +1,1425 @@
+use std::collections::{HashMap, HashSet};
+use std::time::Instant;
+
+mod api;
+mod code;
+mod commit;
+mod docstring;
+mod ignore;
+#[cfg(any(test, fuzzing))]
+pub mod invariants;
+mod line;
+mod long_lines;
+mod markdown;
+mod punctuation;
+mod registry;
+mod strings;
+mod structural;
+mod text;
+mod whitespace;
+mod wrap;
+
+pub use code::{apply_code_rules, apply_code_rules_until, CodeRule};
+pub(crate) use commit::{commit_region, generated_commit_lines};
+pub use ignore::{
+    allowed_spans, collect_ignored_lines, ignore_markers, is_ignored, strip_ignore_marker,
+    unused_config_entry, unused_directives,
+};
+pub use line::LineContext;
+pub use long_lines::{blank_lines, mask_long_lines};
+pub use markdown::{code_blocks, frontmatter_end};
+pub use punctuation::apply_punctuation_rules;
+pub use registry::{registry, rule_for, Pass, RuleDoc};
+pub use strings::apply_string_rules;
+pub use structural::{
+    apply_structural_rules, apply_structural_rules_until, check_first_person_plural,
+    check_headings, StructuralLimits,
+};
+pub(crate) use structural::{
+    count_connectors, prose_paragraphs, split_paragraphs, split_sentences,
+};
+pub(crate) use text::is_word_boundary;
+pub use text::{apply_text_rules, apply_text_rules_to, apply_text_rules_until, builtin_needles};
+pub use whitespace::apply_whitespace_rules;
+
+/// Apply user-defined rules from `cfg` to `lines`, as folded by
+/// [`LineContext::split`], returning findings.
+/// Literal patterns match whole words, case-insensitively (needle =
+/// pattern.to_lowercase()); `regex = true` patterns match anywhere, with `$1`
+/// references in the replacement expanded from the match. Byte offsets
+/// stored in `Finding.col` are always relative to the *original* line so that
+/// `clean()` and JSON consumers can safely slice the original text.
+pub fn apply_user_rules(
+    lines: &[LineContext],
+    cfg: Option<&crate::config::Config>,
+) -> Vec<Finding> {
+    let Some(cfg) = cfg else { return vec![] };
+    let mut findings = Vec::new();
+    for rule in &cfg.rules {
+        if !rule.enabled {
+            continue;
+        }
+        let needle = rule.pattern.to_lowercase();
+        let rule_id = rule
+            .id
+            .clone()
+            .unwrap_or_else(|| format!("user/{}", text::slug(&rule.pattern)));
+        let severity = rule
+            .severity
+            .as_deref()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(Severity::Low);
+        let message = || {
+            rule.message
+                .clone()
+                .unwrap_or_else(|| format!("User rule: '{}'", rule.pattern))
+        };
+        if rule.regex {
+            // Compiled once per rule; `Config::validate` already rejected
+            // patterns that do not compile.
+            let Ok(re) = rule.compile() else { continue };
+            for (line_idx, ctx) in lines.iter().enumerate() {
+                for caps in re.captures_iter(ctx.original) {
+                    let m = caps.get(0).expect("group 0 is the whole match");
+                    if m.is_empty() {
+                        continue;
+                    }
+                    let replacement = rule.replacement.as_deref().map(|r| {
+                        let mut expanded = String::new();
+                        caps.expand(r, &mut expanded);
+                        expanded
+                    });
+                    findings.push(Finding {
+                        line: line_idx + 1,
+                        col: m.start(),
+                        matched: m.as_str().to_string(),
+                        message: message(),
+                        rule_id: rule_id.clone(),
+                        replacement,
+                        severity,
+                        category: Category::User,
+                        ..Default::default()
+                    });
+                }
+            }
+            continue;
+        }
+        for (line_idx, ctx) in lines.iter().enumerate() {
+            let line = ctx.original;
+            let line_lower = &ctx.lower;
+            let mut start = 0;
+            while let Some(pos) = line_lower[start..].find(&needle) {
+                let col_lower = start + pos;
+                let end_lower = col_lower + needle.len();
+                if is_word_boundary(line_lower, col_lower, end_lower) {
+                    // Map offsets from `line_lower` back to `line`.
+                    let Some((col, end)) = ctx
+                        .to_original(col_lower, end_lower)
+                        .filter(|&(col, end)| is_word_boundary(line, col, end))
+                    else {
+                        start = end_lower;
+                        continue;
+                    };
+                    let matched = line[col..end].to_string();
+                    if rule.case_sensitive && matched != rule.pattern {
+                        start = end_lower;
+                        continue;
+                    }
+                    findings.push(Finding {
+                        line: line_idx + 1,
+                        col,
+                        matched,
+                        message: message(),
+                        rule_id: rule_id.clone(),
+                        replacement: rule.replacement.clone(),
+                        severity,
+                        category: Category::User,
+                        ..Default::default()
+                    });
+                }
+                start = end_lower;
+            }
+        }
+    }
+    findings
+}
+
+/// Severity level of a finding. Ordered by [`Severity::rank`], so
+/// `Critical > Low`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Critical,
+    High,
+    Medium,
+    #[default]
+    Low,
+}
+
+impl std::str::FromStr for Severity {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "critical" => Ok(Self::Critical),
+            "high" => Ok(Self::High),
+            "medium" => Ok(Self::Medium),
+            "low" => Ok(Self::Low),
+            other => Err(format!(
+                "unknown severity '{}'; valid: critical, high, medium, low",
+                other
+            )),
+        }
+    }
+}
+
+impl Severity {
+    /// Lowercase name, as in the config and the JSON report.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Critical => "critical",
+            Self::High => "high",
+            Self::Medium => "medium",
+            Self::Low => "low",
+        }
+    }
+
+    /// Numeric rank for filtering: higher = more severe.
+    pub fn rank(&self) -> u8 {
+        match self {
+            Self::Critical => 3,
+            Self::High => 2,
+            Self::Medium => 1,
+            Self::Low => 0,
+        }
+    }
+}
+
+impl Ord for Severity {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+impl PartialOrd for Severity {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// When a run gives up on a document. The rule passes check it between
+/// lines and paragraphs and return early once it has passed, so a slow input
+/// stops using CPU instead of running to the end unseen.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Deadline(Option<Instant>);
+
+impl Deadline {
+    /// No deadline: every pass runs to the end.
+    pub const NONE: Deadline = Deadline(None);
+
+    pub fn at(instant: Instant) -> Deadline {
+        Deadline(Some(instant))
+    }
+
+    pub fn passed(&self) -> bool {
+        self.0.is_some_and(|at| Instant::now() >= at)
+    }
+}
+
+/// How safely a replacement can be applied without a human reading it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FixSafety {
+    /// Same meaning and register; always fine to apply (`utilize` → `use`).
+    #[default]
+    Safe,
+    /// Shifts meaning or register (`comprehensive` → `thorough`); worth a look.
+    Caution,
+}
+
+/// Which family of rules produced a finding. `[fail]` thresholds in the
+/// config are set per category.
+#[derive(Debug, Clone, Copy, Default, PartialEq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Category {
+    /// Lexical text rules (word and phrase patterns).
+    #[default]
+    Text,
+    /// Paragraph- and document-level rules.
+    Structural,
+    /// Code and commit-message rules.
+    Code,
+    /// Rules from `[[rules]]` in the config file.
+    User,
+    /// Findings about unai's own configuration, such as unused suppressions.
+    Meta,
+}
+
+/// Part of the input a finding sits in, for consumers that filter by context.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Region {
+    /// Ordinary running text.
+    Prose,
+    /// YAML frontmatter between leading `---` fences.
+    Frontmatter,
+    /// A comment or docstring in source code.
+    CodeComment,
+    /// First line of a commit message.
+    CommitSubject,
+    /// Any commit message line after the subject.
+    CommitBody,
+    /// The contents of a string literal in source code.
+    StringLiteral,
+}
+
+/// How firmly the research behind a rule ties its pattern to LLM output.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Confidence {
+    High,
+    Medium,
+    Low,
+}
+
+/// Research a rule is grounded in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Source {
+    /// Citation with any evidence note, e.g. "25× excess frequency, Kobak 2025".
+    pub citation: &'static str,
+    pub confidence: Confidence,
+}
+
+/// Serializes as `{citation, url, confidence}`, leaving out an unknown url.
+impl serde::Serialize for Source {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let url = self.url();
+        let mut s = serializer.serialize_struct("Source", 2 + usize::from(url.is_some()))?;
+        s.serialize_field("citation", self.citation)?;
+        match url {
+            Some(url) => s.serialize_field("url", url)?,
+            None => s.skip_field("url")?,
+        }
+        s.serialize_field("confidence", &self.confidence)?;
+        s.end()
+    }
+}
+
+/// Papers cited by the rules, keyed by a string that identifies them in a
+/// citation. See "Research basis" in the README.
+const SOURCE_URLS: &[(&str, &str)] = &[
+    ("Kobak", "https://arxiv.org/abs/2406.07016"),
+    ("Liang", "https://arxiv.org/abs/2404.01268"),
+    ("Juzek", "https://arxiv.org/abs/2412.11385"),
+    (
+        "Rosenfeld",
+        "https://pmc.ncbi.nlm.nih.gov/articles/PMC11422446/",
+    ),
+    ("arxiv:2601.17406", "https://arxiv.org/abs/2601.17406"),
+];
+
+impl Source {
+    /// Link to the first paper named in the citation, when it is known.
+    pub fn url(&self) -> Option<&'static str> {
+        SOURCE_URLS
+            .iter()
+            .filter_map(|&(key, url)| self.citation.find(key).map(|pos| (pos, url)))
+            .min_by_key(|&(pos, _)| pos)
+            .map(|(_, url)| url)
+    }
+}
+
+/// A single match found in the input.
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct Finding {
+    /// 1-based line number.
+    pub line: usize,
+    /// Column byte offset within the line (0-based).
+    pub col: usize,
+    /// Matched text.
+    pub matched: String,
+    /// Explanation / suggestion.
+    pub message: String,
+    /// Id of the rule that reported it, as listed by `unai rules doc`:
+    /// `text/delve`, `commit/past-tense`, or `user/<pattern>` for a
+    /// `[[rules]]` entry without an `id`. Ids are stable across releases, so
+    /// suppressions and tools can key on them.
+    pub rule_id: String,
+    /// Replacement text if auto-fixable, otherwise None.
+    pub replacement: Option<String>,
+    /// Safety of `replacement`. Meaningless when there is no replacement.
+    pub fix_safety: FixSafety,
+    /// Capitalize the character right after the replaced span. Set when a
+    /// sentence-initial frame is deleted ("It is worth noting that the…").
+    #[serde(skip)]
+    pub recapitalize_next: bool,
+    /// Severity classification.
+    pub severity: Severity,
+    /// Rule family that produced the finding.
+    pub category: Category,
+    /// Where in the input the finding sits, when the rule knows.
+    pub region: Option<Region>,
+    /// Last line (1-based) of a finding that spans several lines, such as a
+    /// paragraph. When the finding has a `replacement`, `matched` contains the
+    /// newlines. `None` for the usual single-line finding.
+    pub end_line: Option<usize>,
+    /// Candidate rewrites for the reader to choose from. Never applied by
+    /// `clean()`, unlike `replacement`.
+    pub suggestions: Vec<String>,
+    /// Research the rule is grounded in; kept out of `message`.
+    #[serde(skip)]
+    pub source: Option<Source>,
+}
+
+impl Finding {
+    /// `message` with the citation appended, as shown by `--verbose`.
+    pub fn verbose_message(&self) -> String {
+        match self.source {
+            Some(source) => format!("{} ({})", self.message, source.citation),
+            None => self.message.clone(),
+        }
+    }
+
+    /// Last line the finding covers.
+    pub fn last_line(&self) -> usize {
+        self.end_line.unwrap_or(self.line).max(self.line)
+    }
+
+    /// Byte offset just past the match on its last line.
+    pub fn end_col(&self) -> usize {
+        match self.matched.rfind('\n') {
+            Some(nl) if self.last_line() > self.line => self.matched.len() - nl - 1,
+            _ => self.col + self.matched.len(),
+        }
+    }
+}
+
+/// Drop findings identical to an earlier one: same span, matched text, and
+/// message. Guards the reports and counts against a rule pass running twice.
+pub fn dedup_findings(findings: &mut Vec<Finding>) {
+    let mut seen = HashSet::new();
+    findings.retain(|f| {
+        seen.insert((
+            f.line,
+            f.col,
+            f.last_line(),
+            f.matched.clone(),
+            f.message.clone(),
+        ))
+    });
+}
+
+/// Drop text and user findings whose span overlaps another one on the same
+/// line, such as a user rule for a word a text rule already reports. The
+/// longest match is kept, then the most severe, then the earliest. Other
+/// categories are left alone: their `matched` is often a label, not the text.
+pub fn dedup_overlapping(findings: &mut Vec<Finding>) {
+    let spans = |f: &Finding| {
+        matches!(f.category, Category::Text | Category::User) && f.last_line() == f.line
+    };
+    let mut order: Vec<usize> = (0..findings.len())
+        .filter(|&i| spans(&findings[i]))
+        .collect();
+    order.sort_by_key(|&i| {
+        let f = &findings[i];
+        (
+            std::cmp::Reverse(f.matched.len()),
+            std::cmp::Reverse(f.severity.rank()),
+            i,
+        )
+    });
+    let mut kept: HashMap<usize, Vec<(usize, usize)>> = HashMap::new();
+    let mut dropped = HashSet::new();
+    for i in order {
+        let f = &findings[i];
+        let (start, end) = (f.col, f.end_col());
+        let taken = kept.entry(f.line).or_default();
+        if taken.iter().any(|&(s, e)| start < e && s < end) {
+            dropped.insert(i);
+        } else {
+            taken.push((start, end));
+        }
+    }
+    let mut i = 0;
+    findings.retain(|_| {
+        i += 1;
+        !dropped.contains(&(i - 1))
+    });
+}
+
+/// Produce a cleaned version of content by applying auto-fixable replacements.
+///
+/// An empty replacement deletes the matched span. When the deletion leaves
+/// nothing but whitespace on the line, the whole line is dropped, and when
+/// that leaves the document ending on a blank line, the blank line goes too.
+/// A finding whose empty replacement spans the entire line conflicts with the
+/// other fixes on that line; [`superseded_fixes`] decides which side is applied.
+/// Fixes whose offsets no longer match the line are
+/// skipped with a warning (see [`clean_checked`]). End-of-line ignore markers
+/// are stripped so they do not leak into the cleaned text.
+pub fn clean(content: &str, findings: &[Finding]) -> String {
+    clean_with(content, findings, None)
+}
+
+/// [`clean`], then re-wrap every paragraph containing an edited line to
+/// `rewrap` columns when set. Untouched paragraphs are left byte-identical.
+pub fn clean_with(content: &str, findings: &[Finding], rewrap: Option<usize>) -> String {
+    let (cleaned, skipped) = clean_checked(content, findings, rewrap);
+    warn_skipped(&skipped);
+    cleaned
+}
+
+/// A fix `clean()` left out because its offsets no longer match the line —
+/// typically a finding computed against older content — or because it
+/// overlaps a fix that was applied instead.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SkippedFix {
+    /// 1-based line number.
+    pub line: usize,
+    /// Column byte offset the finding claimed.
+    pub col: usize,
+    /// Why the fix was rejected.
+    pub reason: String,
+}
+
+/// Warnings printed per run before the rest are summarized as "and N more".
+const MAX_SKIP_WARNINGS: usize = 5;
+
+/// Print skipped fixes to stderr, the first few in full and then a count.
+pub fn warn_skipped(skipped: &[SkippedFix]) {
+    for line in skip_warnings(skipped) {
+        eprintln!("{}", line);
+    }
+}
+
+fn skip_warnings(skipped: &[SkippedFix]) -> Vec<String> {
+    let mut lines: Vec<String> = skipped
+        .iter()
+        .take(MAX_SKIP_WARNINGS)
+        .map(|s| {
+            format!(
+                "unai: warning: skipping fix at line {} col {} ({})",
+                s.line, s.col, s.reason
+            )
+        })
+        .collect();
+    if skipped.len() > MAX_SKIP_WARNINGS {
+        lines.push(format!(
+            "unai: warning: ...and {} more fix(es) skipped",
+            skipped.len() - MAX_SKIP_WARNINGS
+        ));
+    }
+    lines
+}
+
+/// Indices of findings whose fix loses to a conflicting one on the same line:
+/// an empty replacement that deletes the whole line against replacements
+/// inside it. The more severe side wins; on a tie, the deletion does.
+/// [`clean_checked`] leaves the losers unapplied.
+pub fn superseded_fixes(content: &str, findings: &[Finding]) -> HashSet<usize> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut by_line: HashMap<usize, (Vec<usize>, Vec<usize>)> = HashMap::new();
+    for (i, f) in findings.iter().enumerate() {
+        let Some(line) = f.line.checked_sub(1).and_then(|idx| lines.get(idx)) else {
+            continue;
+        };
+        if f.last_line() > f.line {
+            continue;
+        }
+        let (drops, edits) = by_line.entry(f.line).or_default();
+        match f.replacement.as_deref() {
+            Some("") if drops_line(f, line) => drops.push(i),
+            Some(_) => edits.push(i),
+            None => {}
+        }
+    }
+
+    let rank = |ids: &[usize]| {
+        ids.iter()
+            .map(|&i| findings[i].severity.rank())
+            .max()
+            .unwrap_or(0)
+    };
+    let mut superseded = HashSet::new();
+    let mut lost_drops: Vec<usize> = Vec::new();
+    for (drops, edits) in by_line.values() {
+        if drops.is_empty() || edits.is_empty() {
+            continue;
+        }
+        if rank(edits) > rank(drops) {
+            superseded.extend(drops);
+            lost_drops.extend(drops);
+        } else {
+            superseded.extend(edits);
+        }
+    }
+
+    // Deleting a paragraph takes one drop per line, all with the same message.
+    // When one line's drop loses, the adjacent ones go too, so the paragraph
+    // is kept whole rather than merged into its neighbour.
+    let all_drops: Vec<usize> = by_line.values().flat_map(|(d, _)| d).copied().collect();
+    while let Some(i) = lost_drops.pop() {
+        for &j in &all_drops {
+            let (a, b) = (&findings[i], &findings[j]);
+            if a.message == b.message && a.line.abs_diff(b.line) == 1 && superseded.insert(j) {
+                lost_drops.push(j);
+            }
+        }
+    }
+    superseded
+}
+
+/// [`clean_with`] without printing: returns the cleaned text and the fixes
+/// skipped for stale offsets, ordered by line and column.
+pub fn clean_checked(
+    content: &str,
+    findings: &[Finding],
+    rewrap: Option<usize>,
+) -> (String, Vec<SkippedFix>) {
+    let mut skipped = Vec::new();
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    let mut endings: Vec<&str> = content
+        .split_inclusive('\n')
+        .map(|l| if l.ends_with("\r\n") { "\r\n" } else { "\n" })
+        .collect();
+    let superseded = superseded_fixes(content, findings);
+
+    let mut drop_lines: HashSet<usize> = HashSet::new();
+    let mut fix_by_line: HashMap<usize, Vec<&Finding>> = HashMap::new();
+    let mut spans: Vec<&Finding> = Vec::new();
+
+    for (i, f) in findings.iter().enumerate() {
+        if superseded.contains(&i) {
+            continue;
+        }
+        // f.line is 1-based; skip malformed findings with line == 0.
+        let Some(idx) = f.line.checked_sub(1) else {
+            continue;
+        };
+        if idx >= lines.len() {
+            continue;
+        }
+        if f.replacement.is_some() && f.last_line() > f.line {
+            spans.push(f);
+            continue;
+        }
+        match f.replacement.as_deref() {
+            Some("") if drops_line(f, &lines[idx]) => {
+                drop_lines.insert(idx);
+            }
+            Some(_) => {
+                fix_by_line.entry(idx).or_default().push(f);
+            }
+            None => {}
+        }
+    }
+
+    // Multi-line spans are applied first and claim their lines: like a
+    // whole-line deletion, they win over single-line fixes on those lines.
+    spans.sort_by_key(|f| (f.line, f.col));
+    let mut claimed: HashSet<usize> = HashSet::new();
+    let mut edited_spans: HashSet<usize> = HashSet::new();
+    for f in spans {
+        let (first, last) = (f.line - 1, f.last_line() - 1);
+        if last >= lines.len() || (first..=last).any(|i| claimed.contains(&i)) {
+            skipped.push(SkippedFix {
+                line: f.line,
+                col: f.col,
+                reason: format!(
+                    "invalid offset: span to line {} is out of range or overlaps",
+                    last + 1
+                ),
+            });
+            continue;
+        }
+        let block = lines[first..=last].join("\n");
+        let end = f.col + f.matched.len();
+        if block.get(f.col..end) != Some(f.matched.as_str()) {
+            skipped.push(SkippedFix {
+                line: f.line,
+                col: f.col,
+                reason: format!(
+                    "invalid offset: expected {:?} through line {}",
+                    f.matched,
+                    last + 1
+                ),
+            });
+            continue;
+        }
+        let replacement = f.replacement.as_deref().unwrap_or_default();
+        let spliced = format!("{}{}{}", &block[..f.col], replacement, &block[end..]);
+        claimed.extend(first..=last);
+        drop_lines.extend(first + 1..=last);
+        if spliced.trim().is_empty() {
+            drop_lines.insert(first);
+        } else {
+            lines[first] = spliced;
+            endings[first] = endings[last];
+            edited_spans.insert(first);
+        }
+    }
+
+    for (idx, line_findings) in &fix_by_line {
+        if drop_lines.contains(idx) || claimed.contains(idx) {
+            continue;
+        }
+        let mut line = lines[*idx].clone();
+        let mut deleted = false;
+
+        // Every fix's offsets assume the original line, so of two overlapping
+        // fixes, or two starting at the same column, only one can apply: the
+        // more severe, then the leftmost. Fixes
+        // that do not fit the line are rejected first and block nothing.
+        let mut by_priority = line_findings.clone();
+        by_priority.sort_by_key(|f| (std::cmp::Reverse(f.severity.rank()), f.col));
+        let mut sorted: Vec<&Finding> = Vec::with_capacity(by_priority.len());
+        for f in by_priority {
+            let end = f.col + f.matched.len();
+            let reason = if end > line.len()
+                || !line.is_char_boundary(f.col)
+                || !line.is_char_boundary(end)
+            {
+                Some(format!("invalid offset: line length {}", line.len()))
+            } else if line[f.col..end] != f.matched {
+                Some(format!(
+                    "invalid offset: expected {:?}, found {:?}",
+                    f.matched,
+                    &line[f.col..end]
+                ))
+            } else {
+                sorted
+                    .iter()
+                    .find(|w| w.col == f.col || (f.col < w.col + w.matched.len() && w.col < end))
+                    .map(|w| {
+                        format!(
+                            "overlaps a fix applied instead; kept {:?}, dropped {:?}",
+                            w.message, f.message
+                        )
+                    })
+            };
+            match reason {
+                Some(reason) => skipped.push(SkippedFix {
+                    line: f.line,
+                    col: f.col,
+                    reason,
+                }),
+                None => sorted.push(f),
+            }
+        }
+        // Right to left, so each fix leaves the offsets before it intact.
+        sorted.sort_by_key(|f| std::cmp::Reverse(f.col));
+
+        for f in sorted {
+            if let Some(ref replacement) = f.replacement {
+                let end = f.col + f.matched.len();
+                let original = &line[f.col..end];
+                let fixed = apply_case(original, replacement);
+                let mut rest = &line[end..];
+                // A deleted span at the start of the line or between two spaces
+                // would leave a dangling gap; drop the whitespace after it.
+                let before = &line[..f.col];
+                if replacement.is_empty()
+                    && (before.trim().is_empty() || before.ends_with(char::is_whitespace))
+                {
+                    rest = rest.trim_start();
+                }
+                let rest = if f.recapitalize_next {
+                    capitalize_first(rest)
+                } else {
+                    rest.to_string()
+                };
+                deleted |= replacement.is_empty();
+                line = format!("{}{}{}", &line[..f.col], fixed, rest);
+            }
+        }
+        if deleted && line.trim().is_empty() {
+            drop_lines.insert(*idx);
+        }
+        lines[*idx] = line;
+    }
+
+    // A deleted block at the end of the document would leave the blank line
+    // that separated it behind: drop that too.
+    if lines
+        .len()
+        .checked_sub(1)
+        .is_some_and(|last| drop_lines.contains(&last))
+    {
+        let tail = (0..lines.len()).rev().find(|i| !drop_lines.contains(i));
+        if let Some(idx) = tail.filter(|&i| lines[i].trim().is_empty()) {
+            drop_lines.insert(idx);
+        }
+    }
+
+    // Each kept line keeps its own terminator, so CRLF and mixed files come
+    // back byte-identical outside the fixes. Rewrapping changes the line
+    // count, so rewrapped output uses the first line's terminator throughout.
+    let dominant = endings.first().copied().unwrap_or("\n");
+    let mut kept: Vec<String> = Vec::with_capacity(lines.len());
+    let mut kept_endings: Vec<&str> = Vec::with_capacity(lines.len());
+    let mut edited: HashSet<usize> = HashSet::new();
+    for (idx, line) in lines.into_iter().enumerate() {
+        if drop_lines.contains(&idx) {
+            continue;
+        }
+        if fix_by_line.contains_key(&idx) || edited_spans.contains(&idx) {
+            edited.insert(kept.len());
+        }
+        kept.push(strip_ignore_marker(&line).to_string());
+        kept_endings.push(endings.get(idx).copied().unwrap_or(dominant));
+    }
+    if let Some(width) = rewrap {
+        kept = wrap::rewrap_edited(&kept, &edited, width);
+        kept_endings = vec![dominant; kept.len()];
+    }
+    skipped.sort_by_key(|s| (s.line, s.col));
+
+    let mut cleaned = String::with_capacity(content.len());
+    for (i, (line, ending)) in kept.iter().zip(&kept_endings).enumerate() {
+        cleaned.push_str(line);
+        if i + 1 < kept.len() || content.ends_with('\n') {
+            cleaned.push_str(ending);
+        }
+    }
+    if kept.is_empty() && content.ends_with('\n') {
+        cleaned.push_str(dominant);
+    }
+    (cleaned, skipped)
+}
+
+/// Whether an empty-replacement finding covers everything but whitespace on `line`.
+fn drops_line(f: &Finding, line: &str) -> bool {
+    let end = f.col + f.matched.len();
+    match (line.get(..f.col), line.get(end..)) {
+        (Some(before), Some(after)) => before.trim().is_empty() && after.trim().is_empty(),
+        _ => false,
+    }
+}
+
+fn capitalize_first(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) => c.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+/// Preserve capitalization style of the original word when applying a replacement.
+///
+/// An all-caps original of more than one letter ("UTILIZE") uppercases the
+/// whole replacement; a multi-word original with every word capitalized
+/// ("In Order To") capitalizes every word of it; otherwise an initial capital
+/// carries over to the replacement's first letter.
+pub(crate) fn apply_case(original: &str, replacement: &str) -> String {
+    if original.is_empty() || replacement.is_empty() {
+        return replacement.to_string();
+    }
+    let letters = original.chars().filter(|c| c.is_alphabetic()).count();
+    if letters > 1 && !original.chars().any(char::is_lowercase) {
+        return replacement.to_uppercase();
+    }
+    let starts_upper = |word: &str| word.chars().next().is_some_and(char::is_uppercase);
+    let words: Vec<&str> = original.split_whitespace().collect();
+    if words.len() > 1 && words.iter().all(|w| starts_upper(w)) {
+        return replacement
+            .split(' ')
+            .map(capitalize_first)
+            .collect::<Vec<_>>()
+            .join(" ");
+    }
+    if starts_upper(original) {
+        return capitalize_first(replacement);
+    }
+    replacement.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn severity_orders_by_rank() {
+        let mut levels = [
+            Severity::Medium,
+            Severity::Critical,
+            Severity::Low,
+            Severity::High,
+        ];
+        levels.sort();
+        assert_eq!(
+            levels,
+            [
+                Severity::Low,
+                Severity::Medium,
+                Severity::High,
+                Severity::Critical
+            ]
+        );
+        assert!(Severity::Critical > Severity::Low);
+    }
+
+    #[test]
+    fn dedup_keeps_first_of_identical_findings() {
+        let a = make_finding(1, 0, "utilize", Some("use"));
+        let mut other_rule = a.clone();
+        other_rule.message = "Different rule".to_string();
+        let mut findings = vec![
+            a.clone(),
+            a.clone(),
+            other_rule,
+            make_finding(2, 0, "utilize", None),
+        ];
+        dedup_findings(&mut findings);
+        assert_eq!(findings.len(), 3);
+        assert_eq!(findings[1].message, "Different rule");
+    }
+
+    #[test]
+    fn overlapping_findings_keep_the_longest_match() {
+        let text = |col, matched: &str| Finding {
+            category: Category::Text,
+            ..make_finding(1, col, matched, None)
+        };
+        let user = Finding {
+            category: Category::User,
+            ..make_finding(1, 0, "leverage", None)
+        };
+        // "leveraging" nests "leverage"; "best practices" partly overlaps
+        // "practices today"; "synergy" stands apart.
+        let mut findings = vec![
+            user,
+            text(0, "leveraging"),
+            text(20, "best practices"),
+            text(25, "practices today"),
+            text(50, "synergy"),
+        ];
+        dedup_overlapping(&mut findings);
+        let kept: Vec<&str> = findings.iter().map(|f| f.matched.as_str()).collect();
+        assert_eq!(kept, ["leveraging", "practices today", "synergy"]);
+    }
+
+    #[test]
+    fn overlapping_findings_tie_goes_to_severity_then_order() {
+        let finding = |severity, message: &str| Finding {
+            category: Category::Text,
+            severity,
+            message: message.to_string(),
+            ..make_finding(1, 3, "delve", None)
+        };
+        let mut findings = vec![
+            finding(Severity::Medium, "first"),
+            finding(Severity::High, "severe"),
+            finding(Severity::High, "later"),
+        ];
+        dedup_overlapping(&mut findings);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].message, "severe");
+    }
+
+    #[test]
+    fn overlap_dedup_skips_other_lines_and_categories() {
+        let mut findings = vec![
+            Finding {
+                category: Category::Text,
+                ..make_finding(1, 0, "robust", None)
+            },
+            Finding {
+                category: Category::Text,
+                ..make_finding(2, 0, "robust", None)
+            },
+            Finding {
+                category: Category::Structural,
+                ..make_finding(1, 0, "3 discourse connectors", None)
+            },
+        ];
+        dedup_overlapping(&mut findings);
+        assert_eq!(findings.len(), 3);
+    }
+
+    #[test]
+    fn source_url_follows_first_cited_paper() {
+        let source = |citation| Source {
+            citation,
+            confidence: Confidence::Medium,
+        };
+        assert_eq!(
+            source("Liang 2024, Kobak 2025").url(),
+            Some("https://arxiv.org/abs/2404.01268")
+        );
+        assert_eq!(source("Neri 2024").url(), None);
+        let f = Finding {
+            message: "LLM tell: 'delve'".to_string(),
+            source: Some(source("Kobak 2025")),
+            ..Default::default()
+        };
+        assert_eq!(f.verbose_message(), "LLM tell: 'delve' (Kobak 2025)");
+    }
+
+    fn make_finding(line: usize, col: usize, matched: &str, replacement: Option<&str>) -> Finding {
+        Finding {
+            line,
+            col,
+            matched: matched.to_string(),
+            message: "test".to_string(),
+            replacement: replacement.map(str::to_string),
+            severity: Severity::Low,
+            ..Default::default()
+        }
+    }
+
+    // A finding with line == 0 is malformed (1-based) — clean() must skip it, not underflow.
+    #[test]
+    fn clean_skips_finding_with_line_zero() {
+        let f = make_finding(0, 0, "x", Some("y"));
+        let result = clean("hello\n", &[f]);
+        assert_eq!(
+            result, "hello\n",
+            "line-zero finding must be skipped, not panic"
+        );
+    }
+
+    // An empty replacement covering part of a line deletes just that span.
+    #[test]
+    fn clean_empty_replacement_deletes_span() {
+        let f = make_finding(1, 5, " very", Some(""));
+        assert_eq!(clean("it is very fast\n", &[f]), "it is fast\n");
+    }
+
+    // Deleting a leading span takes the whitespace after it along.
+    #[test]
+    fn clean_deletion_at_line_start_drops_following_space() {
+        let f = make_finding(1, 2, "Sure!", Some(""));
+        assert_eq!(clean("  Sure! Go ahead.\n", &[f]), "  Go ahead.\n");
+    }
+
+    // Deleting a word between two spaces leaves a single space.
+    #[test]
+    fn clean_deletion_between_spaces_collapses_gap() {
+        let f = make_finding(1, 6, "very", Some(""));
+        assert_eq!(clean("it is very fast\n", &[f]), "it is fast\n");
+    }
+
+    // An empty replacement covering the whole line drops the line.
+    #[test]
+    fn clean_empty_replacement_over_whole_line_drops_it() {
+        let f = make_finding(2, 2, "drop me", Some(""));
+        assert_eq!(clean("keep\n  drop me\nkeep\n", &[f]), "keep\nkeep\n");
+    }
+
+    // Dropping the last lines takes the blank separator above them along;
+    // one in the middle of the document is kept.
+    #[test]
+    fn clean_drop_at_end_takes_the_blank_line_above() {
+        let f = make_finding(3, 0, "Bye.", Some(""));
+        assert_eq!(
+            clean(
+                "keep
+
+Bye.
+",
+                &[f]
+            ),
+            "keep
+"
+        );
+        let f = make_finding(3, 0, "Bye.", Some(""));
+        assert_eq!(
+            clean(
+                "keep
+
+Bye.
+
+keep
+",
+                &[f]
+            ),
+            "keep
+
+
+keep
+"
+        );
+    }
+
+    // End-of-line ignore markers are left out of the cleaned text.
+    #[test]
+    fn clean_strips_trailing_ignore_markers() {
+        let f = make_finding(2, 6, "very", Some(""));
+        assert_eq!(
+            clean(
+                "A robust API. <!-- unai-ignore -->\nit is very fast // unai-ignore\n",
+                &[f]
+            ),
+            "A robust API.\nit is fast\n"
+        );
+    }
+
+    // A whole-line drop wins over a replacement elsewhere on the same line.
+    #[test]
+    fn clean_line_drop_takes_precedence_over_replacement() {
+        let drop = make_finding(1, 0, "use it", Some(""));
+        let fix = make_finding(1, 0, "use", Some("apply"));
+        assert_eq!(clean("use it\nnext\n", &[fix, drop]), "next\n");
+    }
+
+    // A more severe replacement beats a whole-line drop, in either order.
+    #[test]
+    fn severe_replacement_supersedes_line_drop() {
+        let drop = make_finding(1, 0, "use it", Some(""));
+        let fix = Finding {
+            severity: Severity::Critical,
+            ..make_finding(1, 0, "use", Some("apply"))
+        };
+        for findings in [
+            vec![drop.clone(), fix.clone()],
+            vec![fix.clone(), drop.clone()],
+        ] {
+            let drop_idx = findings.iter().position(|f| f.matched == "use it").unwrap();
+            let superseded = superseded_fixes("use it\nnext\n", &findings);
+            assert_eq!(superseded, HashSet::from([drop_idx]));
+            assert_eq!(clean("use it\nnext\n", &findings), "apply it\nnext\n");
+        }
+    }
+
+    // A more severe drop, or one tied with the replacement, wins.
+    #[test]
+    fn line_drop_supersedes_equal_or_milder_replacement() {
+        for drop_severity in [Severity::High, Severity::Medium] {
+            let drop = Finding {
+                severity: drop_severity,
+                ..make_finding(1, 0, "use it", Some(""))
+            };
+            let fix = Finding {
+                severity: Severity::Medium,
+                ..make_finding(1, 0, "use", Some("apply"))
+            };
+            for findings in [
+                vec![drop.clone(), fix.clone()],
+                vec![fix.clone(), drop.clone()],
+            ] {
+                let fix_idx = findings.iter().position(|f| f.matched == "use").unwrap();
+                let superseded = superseded_fixes("use it\nnext\n", &findings);
+                assert_eq!(superseded, HashSet::from([fix_idx]));
+                assert_eq!(clean("use it\nnext\n", &findings), "next\n");
+            }
+        }
+    }
+
+    // Stale findings are collected in line/column order and the warnings capped.
+    #[test]
+    fn clean_checked_orders_and_caps_skipped_fixes() {
+        let content = "a\nb\nc\n";
+        let findings: Vec<Finding> = (1..=3)
+            .rev()
+            .flat_map(|line| (0..3).map(move |i| make_finding(line, 10 + i, "x", Some("y"))))
+            .collect();
+        let (cleaned, skipped) = clean_checked(content, &findings, None);
+        assert_eq!(cleaned, content);
+        assert_eq!(skipped.len(), 9);
+        let order: Vec<(usize, usize)> = skipped.iter().map(|s| (s.line, s.col)).collect();
+        let mut sorted = order.clone();
+        sorted.sort();
+        assert_eq!(order, sorted);
+
+        let warnings = skip_warnings(&skipped);
+        assert_eq!(warnings.len(), MAX_SKIP_WARNINGS + 1);
+        assert!(warnings[0].contains("line 1 col 10 (invalid offset: line length 1)"));
+        assert_eq!(
+            warnings[MAX_SKIP_WARNINGS],
+            "unai: warning: ...and 4 more fix(es) skipped"
+        );
+    }
+
+    // A fix whose text no longer matches at its offset is skipped, not applied.
+    #[test]
+    fn clean_skips_fix_whose_text_moved() {
+        let f = make_finding(1, 0, "utilize", Some("use"));
+        let (cleaned, skipped) = clean_checked("we utilize it\n", &[f], None);
+        assert_eq!(cleaned, "we utilize it\n");
+        assert_eq!(skipped.len(), 1);
+    }
+
+    fn fix(col: usize, matched: &str, replacement: &str, severity: Severity) -> Finding {
+        Finding {
+            severity,
+            message: format!("fix {matched}"),
+            ..make_finding(1, col, matched, Some(replacement))
+        }
+    }
+
+    // Of two overlapping fixes, the more severe applies and the other is
+    // skipped with both messages in the reason.
+    #[test]
+    fn clean_overlapping_fixes_apply_the_more_severe() {
+        let content = "We leverage synergies.\n";
+        let low = fix(3, "leverage synergies", "combine strengths", Severity::Low);
+        let high = fix(3, "leverage", "use", Severity::High);
+        let (cleaned, skipped) = clean_checked(content, &[low.clone(), high.clone()], None);
+        assert_eq!(cleaned, clean(content, &[high]));
+        assert_eq!(cleaned, "We use synergies.\n");
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].col, 3);
+        assert!(skipped[0].reason.contains("\"fix leverage\""));
+        assert!(skipped[0].reason.contains("\"fix leverage synergies\""));
+    }
+
+    // On equal severity the leftmost of two partly overlapping fixes wins.
+    #[test]
+    fn clean_partly_overlapping_fixes_keep_the_leftmost() {
+        let content = "in order to utilize it\n";
+        let left = fix(0, "in order to", "to", Severity::Medium);
+        let right = fix(9, "to utilize", "to use", Severity::Medium);
+        let (cleaned, skipped) = clean_checked(content, &[right, left.clone()], None);
+        assert_eq!(cleaned, clean(content, &[left]));
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].col, 9);
+    }
+
+    // Overlapping fixes over multi-byte text do not panic, and the result
+    // is one of the fixes applied alone.
+    #[test]
+    fn clean_overlapping_fixes_on_multibyte_text() {
+        let content = "café crème brûlée\n";
+        let a = fix(0, "café crème", "coffee", Severity::Low);
+        let b = fix(6, "crème brûlée", "custard", Severity::Low);
+        let c = fix(0, "café", "", Severity::Low);
+        let (cleaned, skipped) = clean_checked(content, &[a.clone(), b, c], None);
+        assert_eq!(cleaned, clean(content, &[a]));
+        assert_eq!(skipped.len(), 2);
+    }
+
+    // An insertion where another fix starts conflicts with it.
+    #[test]
+    fn clean_insertion_at_fix_start_conflicts() {
+        let insert = fix(0, "", "ࠀ", Severity::High);
+        let replace = fix(0, "da", "DA", Severity::Low);
+        let (cleaned, skipped) = clean_checked("data.\n", &[replace, insert], None);
+        assert_eq!(cleaned, "ࠀdata.\n");
+        assert_eq!(skipped.len(), 1);
+    }
+
+    // A stale fix is rejected before overlaps are weighed, so it does not
+    // block a valid fix at the same spot.
+    #[test]
+    fn clean_stale_fix_does_not_block_overlapping_fix() {
+        let stale = fix(3, "utilise", "use", Severity::Critical);
+        let valid = fix(3, "utilize", "use", Severity::Low);
+        let (cleaned, skipped) = clean_checked("We utilize it.\n", &[stale, valid], None);
+        assert_eq!(cleaned, "We use it.\n");
+        assert!(skipped[0].reason.starts_with("invalid offset"));
+    }
+
+    // A two-line span with an empty replacement removes both lines.
+    #[test]
+    fn clean_deletes_multi_line_span() {
+        let f = Finding {
+            end_line: Some(3),
+            ..make_finding(2, 0, "drop this\nand this", Some(""))
+        };
+        assert_eq!(
+            clean("keep\ndrop this\nand this\nkeep too\n", &[f]),
+            "keep\nkeep too\n"
+        );
+    }
+
+    // A span that starts and ends mid-line joins what is left around it.
+    #[test]
+    fn clean_splices_partial_multi_line_span() {
+        let f = Finding {
+            end_line: Some(2),
+            ..make_finding(1, 4, "very\nvery ", Some(""))
+        };
+        let fix = make_finding(2, 5, "fast", Some("quick"));
+        assert_eq!(clean("its very\nvery fast\n", &[f, fix]), "its fast\n");
+    }
+
+    // A finding with col beyond line length must be skipped gracefully, not panic.
+    #[test]
+    fn clean_skips_finding_with_out_of_bounds_col() {
+        let f = make_finding(1, 100, "x", Some("y"));
+        let result = clean("hello\n", &[f]);
+        assert_eq!(
+            result, "hello\n",
+            "out-of-bounds col must be skipped, not panic"
+        );
+    }
+
+    // Multiple non-overlapping matches on the same line must all be reported.
+    #[test]
+    fn apply_user_rules_finds_multiple_matches_same_line() {
+        use crate::config::{
+            CodeConfig, CommitConfig, Config, DetectorConfig, FailConfig, IgnoreConfig,
+            InputConfig, OutputConfig, StructuralConfig, UserRule, WhitespaceConfig,
+        };
+        let cfg = Config {
+            version: 1,
+            disabled_rules: vec![],
+            severity_overrides: Default::default(),
+            rules: vec![UserRule {
+                id: None,
+                pattern: "ab".to_string(),
+                replacement: None,
+                severity: None,
+                message: None,
+                enabled: true,
+                case_sensitive: false,
+                regex: false,
+            }],
+            ignore: IgnoreConfig::default(),
+            fail: FailConfig::default(),
+            detector: DetectorConfig::default(),
+            whitespace: WhitespaceConfig::default(),
+            punctuation: Default::default(),
+            output: OutputConfig::default(),
+            code: CodeConfig::default(),
+            structural: StructuralConfig::default(),
+            input: InputConfig::default(),
+            commit: CommitConfig::default(),
+            allow: vec![],
+        };
+        let findings = apply_user_rules(&LineContext::split("ab ab ab"), Some(&cfg));
+        assert_eq!(
+            findings.len(),
+            3,
+            "three non-overlapping 'ab' matches expected, got {}",
+            findings.len()
+        );
+    }
+
+    #[test]
+    fn user_rule_ids_come_from_pattern_or_config() {
+        use crate::config::{Config, UserRule};
+        let rule = |id: Option<&str>, pattern: &str| UserRule {
+            id: id.map(str::to_string),
+            pattern: pattern.to_string(),
+            replacement: None,
+            severity: None,
+            message: None,
+            enabled: true,
+            case_sensitive: false,
+            regex: false,
+        };
+        let cfg = Config {
+            rules: vec![
+                rule(None, "circle back"),
+                rule(Some("house/leverage"), "leverage"),
+            ],
+            ..Config::default()
+        };
+        let findings = apply_user_rules(
+            &LineContext::split("We circle back to leverage it."),
+            Some(&cfg),
+        );
+        let ids: Vec<&str> = findings.iter().map(|f| f.rule_id.as_str()).collect();
+        assert_eq!(ids, ["user/circle-back", "house/leverage"]);
+    }
+
+    #[test]
+    fn regex_user_rules_report_each_match_span_and_expand_groups() {
+        use crate::config::{Config, UserRule};
+        let cfg = Config {
+            rules: vec![UserRule {
+                id: None,
+                pattern: r"\bsynerg(y|ize|istic)\b".to_string(),
+                replacement: Some("team$1".to_string()),
+                severity: None,
+                message: None,
+                enabled: true,
+                case_sensitive: false,
+                regex: true,
+            }],
+            ..Config::default()
+        };
+        let line = "Synergize, then\u{a0}synergy; not synergies.";
+        let findings = apply_user_rules(&LineContext::split(line), Some(&cfg));
+        let spans: Vec<(usize, &str)> = findings
+            .iter()
+            .map(|f| (f.col, f.matched.as_str()))
+            .collect();
+        assert_eq!(spans, [(0, "Synergize"), (17, "synergy")]);
+        assert_eq!(&line[17..24], "synergy");
+        let replacements: Vec<_> = findings.iter().map(|f| f.replacement.as_deref()).collect();
+        assert_eq!(replacements, [Some("teamize"), Some("teamy")]);
+    }
+
+    // The search cursor advances past each match (start = end), so a long line with many
+    // matches must terminate in bounded time.
+    #[test]
+    fn apply_user_rules_terminates_on_repeated_pattern() {
+        use crate::config::{
+            CodeConfig, CommitConfig, Config, DetectorConfig, FailConfig, IgnoreConfig,
+            InputConfig, OutputConfig, StructuralConfig, UserRule, WhitespaceConfig,
+        };
+        let cfg = Config {
+            version: 1,
+            disabled_rules: vec![],
+            severity_overrides: Default::default(),
+            rules: vec![UserRule {
+                id: None,
+                pattern: "x".to_string(),
+                replacement: None,
+                severity: None,
+                message: None,
+                enabled: true,
+                case_sensitive: false,
+                regex: false,
+            }],
+            ignore: IgnoreConfig::default(),
+            fail: FailConfig::default(),
+            detector: DetectorConfig::default(),
+            whitespace: WhitespaceConfig::default(),
+            punctuation: Default::default(),
+            output: OutputConfig::default(),
+            code: CodeConfig::default(),
+            structural: StructuralConfig::default(),
+            input: InputConfig::default(),
+            commit: CommitConfig::default(),
+            allow: vec![],
+        };
+        // Long line with many matches — must not hang.
+        let line = "x ".repeat(1000);
+        let findings = apply_user_rules(&LineContext::split(&line), Some(&cfg));
+        assert_eq!(findings.len(), 1000);
+    }
+
+    #[test]
+    fn apply_user_rules_case_sensitive_matches_exact_case() {
+        use crate::config::{
+            CodeConfig, CommitConfig, Config, DetectorConfig, FailConfig, IgnoreConfig,
+            InputConfig, OutputConfig, StructuralConfig, UserRule, WhitespaceConfig,
+        };
+        let cfg = Config {
+            version: 1,
+            disabled_rules: vec![],
+            severity_overrides: Default::default(),
+            rules: vec![UserRule {
+                id: None,
+                pattern: "Go".to_string(),
+                replacement: None,
+                severity: None,
+                message: None,
+                enabled: true,
+                case_sensitive: true,
+                regex: false,
+            }],
+            ignore: IgnoreConfig::default(),
+            fail: FailConfig::default(),
+            detector: DetectorConfig::default(),
+            whitespace: WhitespaceConfig::default(),
+            punctuation: Default::default(),
+            output: OutputConfig::default(),
+            code: CodeConfig::default(),
+            structural: StructuralConfig::default(),
+            input: InputConfig::default(),
+            commit: CommitConfig::default(),
+            allow: vec![],
+        };
+        let findings = apply_user_rules(&LineContext::split("Go, go, GO"), Some(&cfg));
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].col, 0);
+    }
+}
@@ -0,0 +1,411 @@
+use std::collections::HashSet;
+
+use super::markdown::headings;
+use super::{Category, Finding, FixSafety, Region, Severity};
+
+/// Flag typographic characters outside fenced code and backtick spans:
+/// curly quotes and the ellipsis character, with fixes to their ASCII forms;
+/// emoji, with fixes that delete them; and em dashes used as clause
+/// separators past the first `max_em_dashes` of a paragraph. Em dashes are
+/// only fixed when `em_dash_fix` is given: the dash and the spaces around it
+/// become `em_dash_fix` (`,` or ` -`), with a space after unless the dash
+/// ends the line. With `commit`, `content` is a commit message and its first
+/// line the subject.
+pub fn apply_punctuation_rules(
+    content: &str,
+    max_em_dashes: usize,
+    em_dash_fix: Option<&str>,
+    commit: bool,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let mut in_code_block = false;
+    let mut dashes_in_paragraph = 0;
+    // In a commit message a `#` line is a comment, not a heading.
+    let heading_lines: HashSet<usize> = if commit {
+        HashSet::new()
+    } else {
+        headings(content).iter().map(|h| h.line).collect()
+    };
+
+    for (idx, line) in content.lines().enumerate() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+        if line.trim().is_empty() {
+            dashes_in_paragraph = 0;
+            continue;
+        }
+
+        let mut in_code_span = false;
+        let mut emoji_until = 0;
+        for (col, c) in line.char_indices() {
+            if col < emoji_until {
+                continue;
+            }
+            if c == '`' {
+                in_code_span = !in_code_span;
+                continue;
+            }
+            if in_code_span {
+                continue;
+            }
+            if let Some(end) = emoji_end(line, col) {
+                let context = if commit && idx == 0 {
+                    Some((Severity::Critical, "the commit subject"))
+                } else if heading_lines.contains(&(idx + 1)) {
+                    Some((Severity::High, "a heading"))
+                } else {
+                    None
+                };
+                findings.push(emoji(idx, line, col, end, context));
+                emoji_until = end;
+                continue;
+            }
+            let (rule_id, message, replacement) = match c {
+                '\u{201C}' | '\u{201D}' | '\u{201E}' => (
+                    "punctuation/curly-quotes",
+                    "Curly double quote: use a straight one",
+                    "\"",
+                ),
+                '\u{2018}' | '\u{2019}' | '\u{201A}' => (
+                    "punctuation/curly-quotes",
+                    "Curly single quote or apostrophe: use a straight one",
+                    "'",
+                ),
+                '\u{2026}' => (
+                    "punctuation/ellipsis",
+                    "Ellipsis character: use three periods",
+                    "...",
+                ),
+                '\u{2014}' if !line[..col].trim().is_empty() => {
+                    dashes_in_paragraph += 1;
+                    if dashes_in_paragraph > max_em_dashes {
+                        findings.push(em_dash(
+                            idx,
+                            line,
+                            col,
+                            dashes_in_paragraph,
+                            max_em_dashes,
+                            em_dash_fix,
+                        ));
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+            findings.push(Finding {
+                line: idx + 1,
+                col,
+                matched: c.to_string(),
+                message: message.to_string(),
+                rule_id: rule_id.to_string(),
+                replacement: Some(replacement.to_string()),
+                severity: Severity::Low,
+                category: Category::Text,
+                region: Some(Region::Prose),
+                ..Default::default()
+            });
+        }
+    }
+
+    findings
+}
+
+/// The finding for the emoji at bytes `col..end` of `line`, deleted by its
+/// fix. One at the end of the line takes the space before it along, so no
+/// trailing space is left; elsewhere `clean()` drops the space after it.
+/// `context` raises the severity from Medium where an emoji is out of place.
+fn emoji(
+    idx: usize,
+    line: &str,
+    col: usize,
+    end: usize,
+    context: Option<(Severity, &str)>,
+) -> Finding {
+    let start = if line[end..].trim().is_empty() && line[..col].ends_with(' ') {
+        col - 1
+    } else {
+        col
+    };
+    let (severity, message) = match context {
+        Some((severity, place)) => (severity, format!("Emoji in {place}: drop it")),
+        None => (
+            Severity::Medium,
+            "Emoji as decoration: drop it, or say it in words".to_string(),
+        ),
+    };
+    Finding {
+        line: idx + 1,
+        col: start,
+        matched: line[start..end].to_string(),
+        message,
+        rule_id: "punctuation/emoji".to_string(),
+        replacement: Some(String::new()),
+        severity,
+        category: Category::Text,
+        region: Some(Region::Prose),
+        ..Default::default()
+    }
+}
+
+/// End of the emoji starting at byte `col` of `line`, if one does: a
+/// pictograph, or any character shown as emoji by U+FE0F, with its skin tone,
+/// keycap and zero-width-joined parts; a keycap such as 1️⃣; or a flag's pair
+/// of regional indicators.
+pub(super) fn emoji_end(line: &str, col: usize) -> Option<usize> {
+    let mut chars = line[col..].chars();
+    let first = chars.next()?;
+    let mut end = col + first.len_utf8();
+    if is_regional_indicator(first) {
+        let second = chars.next().filter(|&c| is_regional_indicator(c))?;
+        return Some(end + second.len_utf8());
+    }
+    let rest = &line[end..];
+    if matches!(first, '0'..='9' | '#' | '*') {
+        let keycap = ["\u{FE0F}\u{20E3}", "\u{20E3}"]
+            .into_iter()
+            .find(|k| rest.starts_with(k))?;
+        return Some(end + keycap.len());
+    }
+    if !is_pictograph(first) && !rest.starts_with('\u{FE0F}') {
+        return None;
+    }
+    let mut chars = line[end..].chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\u{FE0F}' | '\u{20E3}' | '\u{1F3FB}'..='\u{1F3FF}' | '\u{E0020}'..='\u{E007F}' => {
+                end += c.len_utf8();
+            }
+            '\u{200D}' => match chars.peek() {
+                Some(&next) if is_pictograph(next) => {
+                    end += c.len_utf8() + next.len_utf8();
+                    chars.next();
+                }
+                _ => break,
+            },
+            _ => break,
+        }
+    }
+    Some(end)
+}
+
+fn is_regional_indicator(c: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&c)
+}
+
+/// Characters shown as emoji by default: the pictograph blocks, and the
+/// older symbols Unicode gives emoji presentation (Emoji_Presentation=Yes).
+fn is_pictograph(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x1F300..=0x1F64F
+            | 0x1F680..=0x1F6FF
+            | 0x1F7E0..=0x1F7EB
+            | 0x1F900..=0x1F9FF
+            | 0x1FA70..=0x1FAFF
+            | 0x1F004
+            | 0x1F0CF
+            | 0x1F18E
+            | 0x1F191..=0x1F19A
+            | 0x1F201
+            | 0x1F21A
+            | 0x1F22F
+            | 0x1F232..=0x1F236
+            | 0x1F238..=0x1F23A
+            | 0x1F250..=0x1F251
+            | 0x231A..=0x231B
+            | 0x23E9..=0x23EC
+            | 0x23F0
+            | 0x23F3
+            | 0x25FD..=0x25FE
+            | 0x2614..=0x2615
+            | 0x2648..=0x2653
+            | 0x267F
+            | 0x2693
+            | 0x26A1
+            | 0x26AA..=0x26AB
+            | 0x26BD..=0x26BE
+            | 0x26C4..=0x26C5
+            | 0x26CE
+            | 0x26D4
+            | 0x26EA
+            | 0x26F2..=0x26F3
+            | 0x26F5
+            | 0x26FA
+            | 0x26FD
+            | 0x2705
+            | 0x270A..=0x270B
+            | 0x2728
+            | 0x274C
+            | 0x274E
+            | 0x2753..=0x2755
+            | 0x2757
+            | 0x2795..=0x2797
+            | 0x27B0
+            | 0x27BF
+            | 0x2B1B..=0x2B1C
+            | 0x2B50
+            | 0x2B55
+    )
+}
+
+/// The finding for the `nth` clause-separating em dash of a paragraph, at
+/// byte `col` of `line`. With a fix, the match takes one space on each side
+/// of the dash so that "ships Tuesday — if" becomes "ships Tuesday, if".
+fn em_dash(
+    idx: usize,
+    line: &str,
+    col: usize,
+    nth: usize,
+    max: usize,
+    fix: Option<&str>,
+) -> Finding {
+    let dash_end = col + '\u{2014}'.len_utf8();
+    let (start, end, replacement) = match fix {
+        Some(fix) => {
+            let start = if line[..col].ends_with(' ') {
+                col - 1
+            } else {
+                col
+            };
+            let end = if line[dash_end..].starts_with(' ') {
+                dash_end + 1
+            } else {
+                dash_end
+            };
+            let space = if line[end..].is_empty() { "" } else { " " };
+            (start, end, Some(format!("{fix}{space}")))
+        }
+        None => (col, dash_end, None),
+    };
+    Finding {
+        line: idx + 1,
+        col: start,
+        matched: line[start..end].to_string(),
+        message: format!(
+            "Em dash as a clause separator: {nth} in this paragraph, keep at most {max}"
+        ),
+        rule_id: "punctuation/em-dash".to_string(),
+        replacement,
+        fix_safety: FixSafety::Caution,
+        severity: Severity::Medium,
+        category: Category::Text,
+        region: Some(Region::Prose),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::clean;
+    use super::*;
+
+    fn ids(findings: &[Finding]) -> Vec<&str> {
+        findings.iter().map(|f| f.rule_id.as_str()).collect()
+    }
+
+    #[test]
+    fn curly_quotes_and_ellipsis_become_ascii() {
+        let input = "\u{201C}Wait\u{2026}\u{201D} she said. It\u{2019}s \u{2018}fine\u{2019}.\n";
+        let findings = apply_punctuation_rules(input, 1, None, false);
+        assert_eq!(findings.len(), 6);
+        assert_eq!(
+            (findings[1].col, findings[1].matched.as_str()),
+            (7, "\u{2026}")
+        );
+        assert_eq!(
+            clean(input, &findings),
+            "\"Wait...\" she said. It's 'fine'.\n"
+        );
+    }
+
+    #[test]
+    fn code_is_left_alone() {
+        let input =
+            "Run `echo \u{201C}hi\u{201D}` first.\n\n```\nlet s = \u{201C}x\u{201D};\n```\n";
+        assert!(apply_punctuation_rules(input, 1, None, false).is_empty());
+    }
+
+    #[test]
+    fn em_dashes_past_the_limit_are_flagged_per_paragraph() {
+        let input = "Ships Tuesday \u{2014} if tests pass \u{2014} with the API.\n\
+                     It was late \u{2014} twice.\n\n\
+                     One aside \u{2014} here.\n\n\
+                     \u{2014} Attribution line\n";
+        let findings = apply_punctuation_rules(input, 1, None, false);
+        assert_eq!(ids(&findings), ["punctuation/em-dash"; 2]);
+        assert_eq!(findings.iter().map(|f| f.line).collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(findings[0].matched, "\u{2014}");
+        assert_eq!(findings[0].replacement, None);
+        assert!(findings[1].message.contains("3 in this paragraph"));
+        assert!(apply_punctuation_rules(input, 3, None, false).is_empty());
+    }
+
+    #[test]
+    fn em_dash_fix_takes_the_spaces_around_it() {
+        let input =
+            "Ships Tuesday \u{2014} if tests pass\u{2014}with the API \u{2014}\nand docs.\n";
+        let comma = apply_punctuation_rules(input, 0, Some(","), false);
+        assert_eq!(
+            clean(input, &comma),
+            "Ships Tuesday, if tests pass, with the API,\nand docs.\n"
+        );
+        let hyphen = apply_punctuation_rules(input, 0, Some(" -"), false);
+        assert_eq!(
+            clean(input, &hyphen),
+            "Ships Tuesday - if tests pass - with the API -\nand docs.\n"
+        );
+        assert!(hyphen.iter().all(|f| f.fix_safety == FixSafety::Caution));
+    }
+
+    #[test]
+    fn emoji_severity_follows_context() {
+        let input = "## \u{1F680} Getting Started\n\nShipped it \u{1F389}\n";
+        let findings = apply_punctuation_rules(input, 1, None, false);
+        assert_eq!(ids(&findings), ["punctuation/emoji"; 2]);
+        assert_eq!(findings[0].severity, Severity::High);
+        assert_eq!(findings[1].severity, Severity::Medium);
+        assert_eq!(findings[1].matched, " \u{1F389}");
+        assert_eq!(
+            clean(input, &findings),
+            "## Getting Started\n\nShipped it\n"
+        );
+
+        let commit = "\u{2728} Added sparkle\n\nWith a body \u{2728} here.\n";
+        let findings = apply_punctuation_rules(commit, 1, None, true);
+        assert_eq!(findings[0].severity, Severity::Critical);
+        assert_eq!(findings[1].severity, Severity::Medium);
+        assert_eq!(
+            clean(commit, &findings),
+            "Added sparkle\n\nWith a body here.\n"
+        );
+    }
+
+    #[test]
+    fn emoji_sequences_are_one_finding() {
+        let input = "Team \u{1F468}\u{1F3FD}\u{200D}\u{1F4BB} and \u{1F1EB}\u{1F1F7} \u{2764}\u{FE0F} 1\u{FE0F}\u{20E3} go.\n";
+        let findings = apply_punctuation_rules(input, 1, None, false);
+        let matched: Vec<&str> = findings.iter().map(|f| f.matched.as_str()).collect();
+        assert_eq!(
+            matched,
+            [
+                "\u{1F468}\u{1F3FD}\u{200D}\u{1F4BB}",
+                "\u{1F1EB}\u{1F1F7}",
+                "\u{2764}\u{FE0F}",
+                "1\u{FE0F}\u{20E3}"
+            ]
+        );
+        assert_eq!(clean(input, &findings), "Team and go.\n");
+    }
+
+    #[test]
+    fn plain_symbols_and_code_are_not_emoji() {
+        let input = "Tests \u{2713} pass, 3 \u{00D7} faster \u{2192} done. \u{00A9} 2024 #1\n\n\
+                     Run `echo \u{1F680}` first.\n\n```\n// \u{2728}\n```\n";
+        assert!(apply_punctuation_rules(input, 1, None, false).is_empty());
+    }
+}
@@ -0,0 +1,946 @@
+use std::collections::HashSet;
+
+use super::code::{apply_code_rules, CodeRule};
+use super::commit::COMMIT_FINGERPRINT;
+use super::ignore::{collect_ignored_lines, is_ignored, unused_directives};
+use super::line::LineContext;
+use super::punctuation::apply_punctuation_rules;
+use super::structural::{
+    apply_structural_rules, check_first_person_plural, check_headings, StructuralLimits, ROSENFELD,
+};
+use super::text::{apply_text_rules, text_rule_docs};
+use super::whitespace::apply_whitespace_rules;
+use super::{Category, Finding, FixSafety, Severity, Source};
+use crate::detector::Mode;
+
+/// Sample inputs for a rule: `bad` makes it fire, `good` is a rewrite it
+/// leaves alone.
+#[derive(Debug, Clone, Copy, serde::Serialize)]
+pub struct Examples {
+    pub bad: &'static str,
+    pub good: &'static str,
+}
+
+/// What a rule's auto-fix does.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct FixDoc {
+    /// Text the match is replaced with; empty when the fix deletes it.
+    pub replacement: &'static str,
+    pub safety: FixSafety,
+    /// Limits on when the fix is offered, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
+}
+
+/// Documentation for one built-in rule, as printed by `unai rules doc`.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RuleDoc {
+    /// `<family>/<name>`, e.g. `text/delve` or `commit/past-tense`.
+    pub id: String,
+    pub category: Category,
+    pub severity: Severity,
+    pub tags: Vec<&'static str>,
+    pub description: String,
+    pub examples: Examples,
+    /// `None` when the rule only flags.
+    pub fix: Option<FixDoc>,
+    /// Rewrites listed in the report for the reader to pick from.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub suggestions: Vec<&'static str>,
+    pub source: Option<Source>,
+    /// The pass that implements the rule.
+    #[serde(skip)]
+    pub pass: Pass,
+    /// Modes the rule runs in; in any other mode its pass skips it.
+    pub modes: &'static [Mode],
+}
+
+/// Prose documents only.
+pub(super) const TEXT: &[Mode] = &[Mode::Text, Mode::Markdown];
+/// Prose documents and commit messages.
+const PROSE: &[Mode] = &[Mode::Text, Mode::Markdown, Mode::CommitMsg];
+/// Source code, including the fenced code blocks of Markdown.
+const CODE: &[Mode] = &[Mode::Code, Mode::Markdown];
+/// Commit messages, detected or passed to code mode as COMMIT_EDITMSG.
+const COMMIT: &[Mode] = &[Mode::Code, Mode::CommitMsg];
+
+/// A rule pass of the pipeline. Several rules share a pass; the pipeline
+/// decides per mode and config which passes run.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Pass {
+    /// Lexical text rules.
+    Text,
+    /// Paragraph-level rules of [`apply_structural_rules`].
+    Structural,
+    /// Markdown heading rules, text mode only.
+    Headings,
+    /// Opt-in first-person rule, enabled by `structural.first_person_plural_max`.
+    FirstPerson,
+    Whitespace,
+    /// Typographic characters: curly quotes, ellipses, em dashes and emoji.
+    Punctuation,
+    /// One code rule category.
+    Code(CodeRule),
+    /// Unused-suppression check, run with `--lint-config` or `--verbose`.
+    Suppressions,
+}
+
+impl RuleDoc {
+    /// Run the rule's pass over `content` with default settings.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub(crate) fn lint(&self, content: &str) -> Vec<Finding> {
+        match &self.pass {
+            Pass::Text => apply_text_rules(content),
+            // Examples are a paragraph or two, shorter than the documents
+            // the default limits wait for.
+            Pass::Structural => {
+                apply_structural_rules(content, &StructuralLimits::NONE, &HashSet::new())
+            }
+            Pass::Headings => check_headings(content, &StructuralLimits::NONE),
+            // Half the sentences, a threshold the examples clear either way.
+            Pass::FirstPerson => check_first_person_plural(content, 0.5),
+            // The `[whitespace]` default of two blank lines.
+            Pass::Whitespace => apply_whitespace_rules(content, 2, false),
+            // The `[punctuation]` default of one em dash per paragraph.
+            Pass::Punctuation => apply_punctuation_rules(content, 1, None, false),
+            Pass::Code(rule) => apply_code_rules(
+                &LineContext::split(content),
+                std::slice::from_ref(rule),
+                &HashSet::new(),
+            ),
+            Pass::Suppressions => {
+                let ignored = collect_ignored_lines(content);
+                let findings = apply_text_rules(content);
+                unused_directives(content, findings.iter().filter(|f| is_ignored(&ignored, f)))
+            }
+        }
+    }
+
+    /// Whether `finding` was reported by this rule.
+    pub(crate) fn reported(&self, finding: &Finding) -> bool {
+        finding.rule_id == self.id
+    }
+
+    /// Page under `rules/` in the repository that explains the rule's family.
+    pub fn doc_url(&self) -> Option<String> {
+        let page = match &self.pass {
+            Pass::Text
+            | Pass::Structural
+            | Pass::Headings
+            | Pass::FirstPerson
+            | Pass::Whitespace
+            | Pass::Punctuation => "text",
+            Pass::Code(CodeRule::Comments) => "code-comments",
+            Pass::Code(CodeRule::Naming) => "naming",
+            Pass::Code(CodeRule::Commits) => "commits",
+            Pass::Code(CodeRule::Docstrings) => "docstrings",
+            Pass::Code(CodeRule::Tests) => "tests",
+            Pass::Code(CodeRule::Errors) => "error-handling",
+            Pass::Code(CodeRule::Api) => "api-design",
+            Pass::Suppressions => return None,
+        };
+        Some(format!("{RULES_DOCS}/{page}.md"))
+    }
+}
+
+/// Where the rule family pages live.
+const RULES_DOCS: &str = "https://github.com/HugoLopes45/unai/blob/main/rules";
+
+/// The rule in `rules` that reported `finding`, by its id. `None` for ids
+/// outside the registry, such as user rules.
+pub fn rule_for<'a>(rules: &'a [RuleDoc], finding: &Finding) -> Option<&'a RuleDoc> {
+    rules.iter().find(|r| r.reported(finding))
+}
+
+/// Deletion fix that is always offered.
+const DELETE: Option<FixDoc> = Some(FixDoc {
+    replacement: "",
+    safety: FixSafety::Safe,
+    condition: None,
+});
+
+/// Every built-in rule, text rules first, in a stable order.
+pub fn registry() -> Vec<RuleDoc> {
+    let mut docs: Vec<RuleDoc> = text_rule_docs().collect();
+    docs.extend([
+        RuleDoc {
+            id: "structural/connector-density".to_string(),
+            category: Category::Structural,
+            severity: Severity::High,
+            tags: vec!["connector"],
+            description: "Three or more discourse connectors (moreover, additionally, as a result…) in one paragraph, at `structural.connectors_per_100_words` or more".to_string(),
+            examples: Examples {
+                bad: "Moreover, the cache is shared. Furthermore, it is fast. Additionally, it is small.",
+                good: "The cache is shared, fast, and small.",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: Some(ROSENFELD),
+            pass: Pass::Structural,
+            modes: TEXT,
+        },
+        RuleDoc {
+            id: "structural/uniform-sentence-length".to_string(),
+            category: Category::Structural,
+            severity: Severity::Medium,
+            tags: vec!["rhythm"],
+            description: "A paragraph of four or more sentences whose lengths barely vary, once the document has `structural.uniformity_min_sentences`".to_string(),
+            examples: Examples {
+                bad: "The parser reads each line from the file. The lexer splits every line into tokens. The checker walks the tokens in a loop. The printer writes all results to a file.",
+                good: "The parser reads each line. Then the lexer splits every line into tokens, keeping offsets so errors point at the right column. Checks run next. Results print last.",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: Some(ROSENFELD),
+            pass: Pass::Structural,
+            modes: TEXT,
+        },
+        RuleDoc {
+            id: "structural/conclusion-opener".to_string(),
+            category: Category::Structural,
+            severity: Severity::Medium,
+            tags: vec!["conclusion"],
+            description: "A closing paragraph that opens with \"In summary\" or a similar recap phrase".to_string(),
+            examples: Examples {
+                bad: "The cache holds parsed files.\n\nIn summary, switch the default to disk storage next quarter.",
+                good: "The cache holds parsed files.\n\nSwitch the default to disk storage next quarter.",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Structural,
+            modes: TEXT,
+        },
+        RuleDoc {
+            id: "structural/restating-conclusion".to_string(),
+            category: Category::Structural,
+            severity: Severity::Medium,
+            tags: vec!["conclusion"],
+            description: "A closing recap paragraph that only repeats earlier text".to_string(),
+            examples: Examples {
+                bad: "The cache holds parsed files and speeds up builds.\n\nIn summary, the cache holds parsed files.",
+                good: "The cache holds parsed files and speeds up builds.",
+            },
+            fix: Some(FixDoc {
+                replacement: "",
+                safety: FixSafety::Safe,
+                condition: Some("deletes the whole paragraph".to_string()),
+            }),
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Structural,
+            modes: TEXT,
+        },
+        RuleDoc {
+            id: "structural/duplicate-sentence".to_string(),
+            category: Category::Structural,
+            severity: Severity::Medium,
+            tags: vec!["repetition"],
+            description: "The same sentence, or a light rewording of it, repeated across the document".to_string(),
+            examples: Examples {
+                bad: "The tool provides a robust and scalable solution for teams. It runs fast.\n\nThe tool provides a robust and scalable solution for everyone.",
+                good: "The tool provides a scalable solution for teams. It runs fast.",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Structural,
+            modes: PROSE,
+        },
+        RuleDoc {
+            id: "structural/repeated-stem".to_string(),
+            category: Category::Structural,
+            severity: Severity::Medium,
+            tags: vec!["repetition"],
+            description: "Four or more sentences opening with the same three words".to_string(),
+            examples: Examples {
+                bad: "This allows you to cache. This allows you to retry. This allows you to log. This allows you to trace.",
+                good: "Caching is built in. Retries are automatic. Logs go to stderr. Traces are opt-in.",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Structural,
+            modes: TEXT,
+        },
+        RuleDoc {
+            id: "structural/bold-lead-in-list".to_string(),
+            category: Category::Structural,
+            severity: Severity::High,
+            tags: vec!["markdown", "lists"],
+            description: "Three or more adjacent list items that each open with a bold label and a colon or dash".to_string(),
+            examples: Examples {
+                bad: "- **Speed:** builds are cached.\n- **Safety:** inputs are checked.\n- **Scale:** workers share the queue.\n",
+                good: "- Builds are cached.\n- Inputs are checked.\n- Workers share the queue.\n",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Structural,
+            modes: TEXT,
+        },
+        RuleDoc {
+            id: "structural/repeated-opener".to_string(),
+            category: Category::Structural,
+            severity: Severity::Medium,
+            tags: vec!["repetition"],
+            description: "Three or more sentences in a row of one paragraph opening with the same word, other than \"the\", \"a\" or \"I\"".to_string(),
+            examples: Examples {
+                bad: "This keeps reads fast. This avoids locks. This scales well.",
+                good: "Reads stay fast. Nothing takes a lock. It scales well.",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Structural,
+            modes: TEXT,
+        },
+        RuleDoc {
+            id: "structural/connector-openers".to_string(),
+            category: Category::Structural,
+            severity: Severity::Low,
+            tags: vec!["connectors"],
+            description: "More than half the paragraphs of a document, of at least three, opening with a discourse connector such as \"moreover\"".to_string(),
+            examples: Examples {
+                bad: "Moreover, reads are fast.\n\nFurthermore, writes are batched.\n\nThe cache is small.\n",
+                good: "Reads are fast.\n\nWrites are batched.\n\nThe cache is small.\n",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Structural,
+            modes: TEXT,
+        },
+        RuleDoc {
+            id: "structural/first-person-plural".to_string(),
+            category: Category::Structural,
+            severity: Severity::Medium,
+            tags: vec!["voice", "opt-in"],
+            description: "A section where most sentences open with \"we\" or \"our\"; enabled by `structural.first_person_plural_max`".to_string(),
+            examples: Examples {
+                bad: "We built the cache. We tuned it. Our tests pass.",
+                good: "The cache is built. It is tuned. Tests pass.",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::FirstPerson,
+            modes: TEXT,
+        },
+        RuleDoc {
+            id: "structural/heading-density".to_string(),
+            category: Category::Structural,
+            severity: Severity::Medium,
+            tags: vec!["markdown", "headings"],
+            description: "Three or more headings with fewer than three sentences each on average, in a document of twenty non-empty lines or more; set by `structural.heading_min_lines`".to_string(),
+            examples: Examples {
+                bad: "# Intro\n\nShort.\n\n## Setup\n\nRun it.\n\n## Usage\n\nCall it.\n",
+                good: "# Guide\n\nInstall it. Run it. Read the output.\n",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Headings,
+            modes: TEXT,
+        },
+        RuleDoc {
+            id: "structural/heading-ratio".to_string(),
+            category: Category::Structural,
+            severity: Severity::Medium,
+            tags: vec!["markdown", "headings"],
+            description: "A heading for fewer than every four lines of prose, in a document of twenty non-empty lines or more; set by `structural.lines_per_heading` and `structural.heading_min_lines`".to_string(),
+            examples: Examples {
+                bad: "## Speed\n\nBuilds are cached across runs.\n\n## Safety\n\nInputs are checked before use.\n",
+                good: "## Design\n\nBuilds are cached across runs.\nInputs are checked before use.\nWorkers share one queue.\nLogs go to stderr.\n",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Headings,
+            modes: TEXT,
+        },
+        RuleDoc {
+            id: "structural/question-headings".to_string(),
+            category: Category::Structural,
+            severity: Severity::Low,
+            tags: vec!["markdown", "headings"],
+            description: "Headings that are title-cased questions".to_string(),
+            examples: Examples {
+                bad: "## What Is The Architecture?\n",
+                good: "## Architecture\n",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Headings,
+            modes: TEXT,
+        },
+        RuleDoc {
+            id: "structural/heading-skip".to_string(),
+            category: Category::Structural,
+            severity: Severity::Low,
+            tags: vec!["markdown", "headings"],
+            description: "A heading more than one level below the heading before it".to_string(),
+            examples: Examples {
+                bad: "# Guide\n\n### Install\n",
+                good: "# Guide\n\n## Install\n",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Headings,
+            modes: TEXT,
+        },
+        RuleDoc {
+            id: "structural/long-heading".to_string(),
+            category: Category::Structural,
+            severity: Severity::Low,
+            tags: vec!["markdown", "headings"],
+            description: "A heading over twelve words long".to_string(),
+            examples: Examples {
+                bad: "## How to configure the cache so that builds finish quickly on every machine we own\n",
+                good: "## Configure the cache\n",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Headings,
+            modes: TEXT,
+        },
+        RuleDoc {
+            id: "whitespace/trailing".to_string(),
+            category: Category::Structural,
+            severity: Severity::Low,
+            tags: vec!["whitespace"],
+            description: "Spaces or tabs at the end of a line, outside fenced code".to_string(),
+            examples: Examples {
+                bad: "Done.   \n",
+                good: "Done.\n",
+            },
+            fix: DELETE,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Whitespace,
+            modes: PROSE,
+        },
+        RuleDoc {
+            id: "whitespace/blank-lines".to_string(),
+            category: Category::Structural,
+            severity: Severity::Low,
+            tags: vec!["whitespace"],
+            description: "More consecutive blank lines than `whitespace.max_blank_lines` (default 2)".to_string(),
+            examples: Examples {
+                bad: "One.\n\n\n\nTwo.\n",
+                good: "One.\n\nTwo.\n",
+            },
+            fix: DELETE,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Whitespace,
+            modes: PROSE,
+        },
+        RuleDoc {
+            id: "punctuation/curly-quotes".to_string(),
+            category: Category::Text,
+            severity: Severity::Low,
+            tags: vec!["punctuation"],
+            description: "Curly double or single quotes and apostrophes, outside code".to_string(),
+            examples: Examples {
+                bad: "It\u{2019}s \u{201C}done\u{201D}.\n",
+                good: "It's \"done\".\n",
+            },
+            fix: Some(FixDoc {
+                replacement: "\"",
+                safety: FixSafety::Safe,
+                condition: Some("' for single quotes and apostrophes".to_string()),
+            }),
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Punctuation,
+            modes: PROSE,
+        },
+        RuleDoc {
+            id: "punctuation/ellipsis".to_string(),
+            category: Category::Text,
+            severity: Severity::Low,
+            tags: vec!["punctuation"],
+            description: "The ellipsis character, outside code".to_string(),
+            examples: Examples {
+                bad: "Loading\u{2026}\n",
+                good: "Loading...\n",
+            },
+            fix: Some(FixDoc {
+                replacement: "...",
+                safety: FixSafety::Safe,
+                condition: None,
+            }),
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Punctuation,
+            modes: PROSE,
+        },
+        RuleDoc {
+            id: "punctuation/em-dash".to_string(),
+            category: Category::Text,
+            severity: Severity::Medium,
+            tags: vec!["punctuation"],
+            description: "Em dashes between clauses past `punctuation.em_dashes_per_paragraph` (default 1) in one paragraph".to_string(),
+            examples: Examples {
+                bad: "The update ships Tuesday \u{2014} assuming tests pass \u{2014} with the new API.\n",
+                good: "The update ships Tuesday if tests pass \u{2014} with the new API.\n",
+            },
+            fix: Some(FixDoc {
+                replacement: ", ",
+                safety: FixSafety::Caution,
+                condition: Some("only with `punctuation.em_dash_fix` set; \" - \" for `hyphen`".to_string()),
+            }),
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Punctuation,
+            modes: PROSE,
+        },
+        RuleDoc {
+            id: "punctuation/emoji".to_string(),
+            category: Category::Text,
+            severity: Severity::Medium,
+            tags: vec!["punctuation", "emoji"],
+            description: "Emoji outside code: High in a Markdown heading, Critical in a commit subject".to_string(),
+            examples: Examples {
+                bad: "Shipped the new cache \u{1F680}\n",
+                good: "Shipped the new cache.\n",
+            },
+            fix: DELETE,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Punctuation,
+            modes: PROSE,
+        },
+        RuleDoc {
+            id: "meta/unused-suppression".to_string(),
+            category: Category::Meta,
+            severity: Severity::Low,
+            tags: vec!["config"],
+            description: "An ignore directive, `ignore.words` entry, or allow phrase that hid no finding; reported with `--lint-config`".to_string(),
+            examples: Examples {
+                bad: "<!-- unai-ignore -->\nThe results are in.\n<!-- /unai-ignore -->\n",
+                good: "<!-- unai-ignore -->\nLet us delve into the results.\n<!-- /unai-ignore -->\n",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Suppressions,
+            modes: Mode::ALL,
+        },
+        RuleDoc {
+            id: "code/section-header".to_string(),
+            category: Category::Code,
+            severity: Severity::High,
+            tags: vec!["comments"],
+            description: "A divider comment such as `# --- Setup ---` or `// HELPERS`".to_string(),
+            examples: Examples {
+                bad: "# --- Setup ---",
+                good: "# Load the config before parsing args.",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Code(CodeRule::Comments),
+            modes: CODE,
+        },
+        RuleDoc {
+            id: "code/bare-todo".to_string(),
+            category: Category::Code,
+            severity: Severity::Critical,
+            tags: vec!["comments"],
+            description: "A TODO with no detail or ticket, such as `TODO: fix this`".to_string(),
+            examples: Examples {
+                bad: "// TODO: fix this",
+                good: "// TODO(#42): retry on timeout",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Code(CodeRule::Comments),
+            modes: CODE,
+        },
+        RuleDoc {
+            id: "code/restated-comment".to_string(),
+            category: Category::Code,
+            severity: Severity::Medium,
+            tags: vec!["comments"],
+            description: "A comment of ten words or fewer that only restates the next line, such as `# increment the counter` above `counter += 1`, or a numbered `# Step 1:` comment".to_string(),
+            examples: Examples {
+                bad: "# increment the counter\ncounter += 1\n",
+                good: "# the first poll always times out\ncounter += 1\n",
+            },
+            fix: Some(FixDoc {
+                replacement: "",
+                safety: FixSafety::Caution,
+                condition: None,
+            }),
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Code(CodeRule::Comments),
+            modes: CODE,
+        },
+        RuleDoc {
+            id: "code/docstring-boilerplate".to_string(),
+            category: Category::Code,
+            severity: Severity::High,
+            tags: vec!["docstrings"],
+            description: "A docstring opening with \"This function serves as\" or a similar frame".to_string(),
+            examples: Examples {
+                bad: "/// This function serves as the entry point.",
+                good: "/// Parse the config file.",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Code(CodeRule::Docstrings),
+            modes: CODE,
+        },
+        RuleDoc {
+            id: "code/vacuous-docstring".to_string(),
+            category: Category::Code,
+            severity: Severity::Medium,
+            tags: vec!["docstrings"],
+            description: "A Google-style docstring section that says nothing: an `Args:` entry described by its own name or \"the value\", `Returns: None.`, or `Raises: Exception: if an error occurs.`".to_string(),
+            examples: Examples {
+                bad: "def f(user_id):\n    \"\"\"Fetch a user.\n\n    Args:\n        user_id (int): The user ID.\n    \"\"\"\n",
+                good: "def f(user_id):\n    \"\"\"Fetch a user.\n\n    Args:\n        user_id (int): Row id in the accounts table.\n    \"\"\"\n",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Code(CodeRule::Docstrings),
+            modes: CODE,
+        },
+        RuleDoc {
+            id: "code/anemic-suffix".to_string(),
+            category: Category::Code,
+            severity: Severity::High,
+            tags: vec!["naming"],
+            description: "An identifier ending in Manager, Handler, Helper, Util, Utility, or Service".to_string(),
+            examples: Examples {
+                bad: "let userManager = load();",
+                good: "let users = load();",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Code(CodeRule::Naming),
+            modes: CODE,
+        },
+        RuleDoc {
+            id: "code/type-in-name".to_string(),
+            category: Category::Code,
+            severity: Severity::Medium,
+            tags: vec!["naming"],
+            description: "An identifier that spells out its type, such as `userDataObject`".to_string(),
+            examples: Examples {
+                bad: "let userDataObject = load();",
+                good: "let user = load();",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Code(CodeRule::Naming),
+            modes: CODE,
+        },
+        RuleDoc {
+            id: "api/boolean-params".to_string(),
+            category: Category::Code,
+            severity: Severity::High,
+            tags: vec!["api"],
+            description: "A function taking two or more positional boolean parameters, typed `bool` or defaulting to a boolean".to_string(),
+            examples: Examples {
+                bad: "def get_user(user_id, include_deleted=False, as_dict=False):\n    pass",
+                good: "def get_user(user_id):\n    pass",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Code(CodeRule::Api),
+            modes: CODE,
+        },
+        RuleDoc {
+            id: "api/thin-wrapper".to_string(),
+            category: Category::Code,
+            severity: Severity::Medium,
+            tags: vec!["api"],
+            description: "A function whose body is one call to another function with the same arguments".to_string(),
+            examples: Examples {
+                bad: "def get_user(user_id):\n    return fetch_user(user_id)",
+                good: "def get_user(user_id):\n    return fetch_user(user_id, include_deleted=False)",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Code(CodeRule::Api),
+            modes: CODE,
+        },
+        RuleDoc {
+            id: "api/untyped-options".to_string(),
+            category: Category::Code,
+            severity: Severity::Medium,
+            tags: vec!["api"],
+            description: "A parameter named `options`, `config`, or `params` with no type".to_string(),
+            examples: Examples {
+                bad: "def connect(host, options):\n    pass",
+                good: "def connect(host: str, options: ConnectOptions):\n    pass",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Code(CodeRule::Api),
+            modes: CODE,
+        },
+        RuleDoc {
+            id: "commit/vague-message".to_string(),
+            category: Category::Code,
+            severity: Severity::Low,
+            tags: vec!["commit"],
+            description: "A commit message saying nothing specific, such as \"wip\" or \"misc changes\"".to_string(),
+            examples: Examples {
+                bad: "wip",
+                good: "Add retry budget to the HTTP client",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Code(CodeRule::Commits),
+            modes: COMMIT,
+        },
+        RuleDoc {
+            id: "commit/past-tense".to_string(),
+            category: Category::Code,
+            severity: Severity::High,
+            tags: vec!["commit"],
+            description: "A commit subject in the past tense (\"Added\") rather than the imperative".to_string(),
+            examples: Examples {
+                bad: "Added retry budget",
+                good: "Add retry budget",
+            },
+            fix: Some(FixDoc {
+                replacement: "add",
+                safety: FixSafety::Safe,
+                condition: Some("or the imperative of the verb found".to_string()),
+            }),
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Code(CodeRule::Commits),
+            modes: COMMIT,
+        },
+        RuleDoc {
+            id: "commit/vague-scope".to_string(),
+            category: Category::Code,
+            severity: Severity::High,
+            tags: vec!["commit"],
+            description: "A commit subject covering \"various\", \"several\", or \"multiple\" changes".to_string(),
+            examples: Examples {
+                bad: "Fix several bugs in the parser",
+                good: "Fix off-by-one in the parser",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Code(CodeRule::Commits),
+            modes: COMMIT,
+        },
+        RuleDoc {
+            id: "commit/multiple-changes".to_string(),
+            category: Category::Code,
+            severity: Severity::Medium,
+            tags: vec!["commit"],
+            description: "A commit subject joining two or more changes with \"and\", \"plus\", \"also\", \"&\", or commas".to_string(),
+            examples: Examples {
+                bad: "Add login page and fix navbar and update deps",
+                good: "Add drag and drop support",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Code(CodeRule::Commits),
+            modes: COMMIT,
+        },
+        RuleDoc {
+            id: "commit/title-case".to_string(),
+            category: Category::Code,
+            severity: Severity::Medium,
+            tags: vec!["commit"],
+            description: "A commit subject with most words capitalized".to_string(),
+            examples: Examples {
+                bad: "Add Retry Budget Support",
+                good: "Add retry budget support",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Code(CodeRule::Commits),
+            modes: COMMIT,
+        },
+        RuleDoc {
+            id: "commit/body".to_string(),
+            category: Category::Code,
+            severity: Severity::Low,
+            tags: vec!["commit"],
+            description: "A commit body, which single-purpose changes rarely need".to_string(),
+            examples: Examples {
+                bad: "Fix off-by-one in the parser\n\nThe loop stopped one token early.",
+                good: "Fix off-by-one in the parser",
+            },
+            fix: None,
+            suggestions: vec![],
+            source: Some(COMMIT_FINGERPRINT),
+            pass: Pass::Code(CodeRule::Commits),
+            modes: COMMIT,
+        },
+        RuleDoc {
+            id: "commit/ai-attribution".to_string(),
+            category: Category::Code,
+            severity: Severity::Critical,
+            tags: vec!["commit"],
+            description: "A trailer or footer crediting an AI assistant, such as `Co-Authored-By: Claude` or \"Generated with ChatGPT\"".to_string(),
+            examples: Examples {
+                bad: "Add retry budget\n\nCo-Authored-By: Claude <noreply@anthropic.com>",
+                good: "Add retry budget\n\nCo-authored-by: Ada Lovelace <ada@example.com>",
+            },
+            fix: Some(FixDoc {
+                replacement: "",
+                safety: FixSafety::Safe,
+                condition: Some("deletes the whole line".to_string()),
+            }),
+            suggestions: vec![],
+            source: None,
+            pass: Pass::Code(CodeRule::Commits),
+            modes: COMMIT,
+        },
+    ]);
+    docs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rule_for_finds_the_reporting_rule() {
+        let rules = registry();
+        let findings = apply_text_rules("Let us delve into it.\n");
+        let rule = rule_for(&rules, &findings[0]).unwrap();
+        assert_eq!(rule.id, "text/delve");
+        assert_eq!(
+            rule.doc_url().as_deref(),
+            Some("https://github.com/HugoLopes45/unai/blob/main/rules/text.md")
+        );
+        let user = Finding {
+            category: Category::User,
+            message: findings[0].message.clone(),
+            rule_id: "user/delve".to_string(),
+            ..Default::default()
+        };
+        assert!(rule_for(&rules, &user).is_none());
+    }
+
+    // Rule ids are a stable interface: ignores and baselines key on them.
+    // Renaming one is a breaking change, so these inputs pin a few.
+    #[test]
+    fn findings_carry_stable_ids() {
+        let ids = |findings: Vec<Finding>| -> Vec<String> {
+            findings.into_iter().map(|f| f.rule_id).collect()
+        };
+        assert_eq!(
+            ids(apply_text_rules("Let us delve into it.\n")),
+            ["text/delve"]
+        );
+        assert!(
+            ids(apply_text_rules("Great question! The cache is cold.\n"))
+                .contains(&"text/great-question".to_string())
+        );
+        let code = |rule: CodeRule, content: &str| {
+            ids(apply_code_rules(
+                &LineContext::split(content),
+                &[rule],
+                &HashSet::new(),
+            ))
+        };
+        assert_eq!(
+            code(CodeRule::Comments, "// ===== Helpers =====\nfn a() {}\n"),
+            ["code/section-header"]
+        );
+        assert!(code(CodeRule::Commits, "Added retry to the fetch loop\n")
+            .contains(&"commit/past-tense".to_string()));
+        let connectors = "Moreover, it runs. Furthermore, it stops. Additionally, it waits.\n";
+        assert!(ids(apply_structural_rules(
+            connectors,
+            &StructuralLimits::NONE,
+            &HashSet::new()
+        ))
+        .contains(&"structural/connector-density".to_string()));
+    }
+
+    #[test]
+    fn ids_are_unique() {
+        let docs = registry();
+        let ids: std::collections::HashSet<&str> = docs.iter().map(|d| d.id.as_str()).collect();
+        assert_eq!(ids.len(), docs.len());
+    }
+
+    #[test]
+    fn every_rule_runs_in_some_mode() {
+        for doc in registry() {
+            assert!(!doc.modes.is_empty(), "{} runs in no mode", doc.id);
+        }
+        let modes = |id: &str| registry().into_iter().find(|d| d.id == id).unwrap().modes;
+        assert_eq!(modes("text/certainly"), Mode::ALL);
+        assert_eq!(modes("text/in-conclusion"), TEXT);
+        assert_eq!(modes("structural/connector-density"), TEXT);
+        assert_eq!(modes("commit/past-tense"), COMMIT);
+    }
+
+    #[test]
+    fn every_rule_has_examples() {
+        for doc in registry() {
+            assert!(
+                !doc.examples.bad.is_empty() && !doc.examples.good.is_empty(),
+                "{} lacks examples",
+                doc.id
+            );
+        }
+    }
+
+    // Each rule must fire on its bad example and stay quiet on its good one.
+    #[test]
+    fn examples_agree_with_rules() {
+        for doc in registry() {
+            let bad = doc.lint(doc.examples.bad);
+            assert!(
+                bad.iter().any(|f| doc.reported(f)),
+                "{} does not fire on {:?}",
+                doc.id,
+                doc.examples.bad
+            );
+            let good = doc.lint(doc.examples.good);
+            assert!(
+                !good.iter().any(|f| doc.reported(f)),
+                "{} fires on {:?}",
+                doc.id,
+                doc.examples.good
+            );
+        }
+    }
+
+    #[test]
+    fn documented_severity_matches_findings() {
+        for doc in registry() {
+            for f in doc
+                .lint(doc.examples.bad)
+                .iter()
+                .filter(|f| doc.reported(f))
+            {
+                assert_eq!(f.severity, doc.severity, "{}", doc.id);
+                assert_eq!(f.category, doc.category, "{}", doc.id);
+            }
+        }
+    }
+}
@@ -0,0 +1,266 @@
+use super::{apply_text_rules, Category, Finding, Region};
+
+/// Languages whose line comments start with `#` rather than `//`.
+const HASH_COMMENT_EXTENSIONS: &[&str] = &[
+    "py", "rb", "sh", "bash", "zsh", "fish", "r", "ex", "exs", "nim", "pl",
+];
+
+/// Languages where `'…'` is a string rather than a char literal or lifetime.
+const SINGLE_QUOTE_EXTENSIONS: &[&str] = &[
+    "py", "js", "jsx", "ts", "tsx", "rb", "php", "sh", "bash", "zsh", "fish", "lua", "r", "dart",
+    "ex", "exs", "pl",
+];
+
+/// Languages where backticks delimit a string (template or raw literal).
+const BACKTICK_EXTENSIONS: &[&str] = &["js", "jsx", "ts", "tsx", "go"];
+
+/// A stretch of one line: the contents of a string literal without its
+/// quotes, or code outside strings and comments.
+struct Span<'a> {
+    /// 1-based line number.
+    line: usize,
+    /// Byte offset of the first byte within the line.
+    col: usize,
+    text: &'a str,
+    literal: bool,
+}
+
+/// Run the text rules on the contents of string literals in source code.
+/// Findings point into the literal on its line. Unless `fix` is set, their
+/// replacements become suggestions: changing a string changes the program.
+pub fn apply_string_rules(content: &str, extension: Option<&str>, fix: bool) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    for lit in string_literals(content, extension) {
+        for mut f in apply_text_rules(lit.text) {
+            f.line = lit.line;
+            f.col += lit.col;
+            f.category = Category::Text;
+            f.region = Some(Region::StringLiteral);
+            if !fix {
+                if let Some(replacement) = f.replacement.take() {
+                    if !replacement.is_empty() && !f.suggestions.contains(&replacement) {
+                        f.suggestions.insert(0, replacement);
+                    }
+                }
+                f.recapitalize_next = false;
+            }
+            findings.push(f);
+        }
+    }
+    findings
+}
+
+/// Single-line string literals in `content`, skipping comments. Strings that
+/// span lines (Python triple quotes, Go raw strings) are stepped over but not
+/// returned.
+fn string_literals<'a>(content: &'a str, extension: Option<&str>) -> Vec<Span<'a>> {
+    spans(content, extension)
+        .into_iter()
+        .filter(|span| span.literal)
+        .collect()
+}
+
+/// Each line of `content` with comments and string literals blanked to
+/// spaces, so byte offsets still match the original line. Lines inside a
+/// multi-line string or block comment come back empty.
+pub(super) fn code_only(content: &str, extension: Option<&str>) -> Vec<String> {
+    let mut lines: Vec<String> = content.lines().map(|l| " ".repeat(l.len())).collect();
+    for span in spans(content, extension).into_iter().filter(|s| !s.literal) {
+        lines[span.line - 1].replace_range(span.col..span.col + span.text.len(), span.text);
+    }
+    for line in &mut lines {
+        line.truncate(line.trim_end().len());
+    }
+    lines
+}
+
+/// The single-line string literals of `content` and the code around them,
+/// in order. Comments, quotes, and strings that span lines are left out.
+fn spans<'a>(content: &'a str, extension: Option<&str>) -> Vec<Span<'a>> {
+    let ext = extension.map(str::to_lowercase);
+    let is = |list: &[&str]| ext.as_deref().is_some_and(|e| list.contains(&e));
+    // Without an extension, accept both comment styles.
+    let hash_comments = ext.is_none() || is(HASH_COMMENT_EXTENSIONS);
+    let slash_comments = !is(HASH_COMMENT_EXTENSIONS);
+    let single_quotes = is(SINGLE_QUOTE_EXTENSIONS);
+    let backticks = is(BACKTICK_EXTENSIONS);
+
+    let mut spans = Vec::new();
+    // Closing delimiter of a string or block comment still open from an
+    // earlier line.
+    let mut open: Option<&str> = None;
+    for (idx, line) in content.lines().enumerate() {
+        let bytes = line.as_bytes();
+        let mut i = 0;
+        // Start of the code since the last string or comment.
+        let mut code = 0;
+        let push_code = |spans: &mut Vec<Span<'a>>, code: usize, end: usize| {
+            if code < end {
+                spans.push(Span {
+                    line: idx + 1,
+                    col: code,
+                    text: &line[code..end],
+                    literal: false,
+                });
+            }
+        };
+        if let Some(close) = open {
+            match find_close(line, 0, close) {
+                Some(end) => {
+                    i = end + close.len();
+                    code = i;
+                    open = None;
+                }
+                None => continue,
+            }
+        }
+        while i < bytes.len() {
+            // Byte-wise: every delimiter is ASCII, so matches fall on char
+            // boundaries even when `i` does not.
+            let rest = &bytes[i..];
+            if (slash_comments && rest.starts_with(b"//"))
+                || (hash_comments && rest.starts_with(b"#"))
+            {
+                break;
+            }
+            if slash_comments && rest.starts_with(b"/*") {
+                push_code(&mut spans, code, i);
+                match find_close(line, i + 2, "*/") {
+                    Some(end) => {
+                        i = end + 2;
+                        code = i;
+                        continue;
+                    }
+                    None => {
+                        code = line.len();
+                        open = Some("*/");
+                        break;
+                    }
+                }
+            }
+            let quote = match bytes[i] {
+                b'"' => "\"",
+                b'\'' if single_quotes => "'",
+                b'`' if backticks => "`",
+                _ => {
+                    i += 1;
+                    continue;
+                }
+            };
+            let quote = if rest.starts_with(b"\"\"\"") {
+                "\"\"\""
+            } else if rest.starts_with(b"'''") && single_quotes {
+                "'''"
+            } else {
+                quote
+            };
+            push_code(&mut spans, code, i);
+            let start = i + quote.len();
+            match find_close(line, start, quote) {
+                Some(end) => {
+                    spans.push(Span {
+                        line: idx + 1,
+                        col: start,
+                        text: &line[start..end],
+                        literal: true,
+                    });
+                    i = end + quote.len();
+                    code = i;
+                }
+                None => {
+                    code = line.len();
+                    // Only triple quotes and backticks may continue on the
+                    // next line; anything else is unterminated.
+                    if quote.len() == 3 || quote == "`" {
+                        open = Some(quote);
+                    }
+                    break;
+                }
+            }
+        }
+        push_code(&mut spans, code, i.min(line.len()));
+    }
+    spans
+}
+
+/// Byte offset of the first unescaped `close` in `line` at or after `from`.
+fn find_close(line: &str, from: usize, close: &str) -> Option<usize> {
+    let bytes = line.as_bytes();
+    let mut i = from;
+    while i < bytes.len() {
+        if bytes[i] == b'\\' && close != "*/" {
+            i += 2;
+            continue;
+        }
+        if bytes[i..].starts_with(close.as_bytes()) {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::clean;
+    use super::*;
+
+    fn texts<'a>(content: &'a str, ext: &str) -> Vec<&'a str> {
+        string_literals(content, Some(ext))
+            .into_iter()
+            .map(|l| l.text)
+            .collect()
+    }
+
+    #[test]
+    fn literals_found_outside_comments() {
+        let py = "x = 'a'  # \"not this\"\nprint(\"b \\\" c\")\n";
+        assert_eq!(texts(py, "py"), vec!["a", "b \\\" c"]);
+        let rs = "let c = 'x'; /* \"no\" */ f(\"yes\"); // \"no\"\n";
+        assert_eq!(texts(rs, "rs"), vec!["yes"]);
+    }
+
+    #[test]
+    fn code_only_blanks_comments_and_strings() {
+        let rs = "f(\"a, b\"); /* c */ g(é) // d\nlet s = `x\ny`;\n";
+        assert_eq!(
+            code_only(rs, Some("rs")),
+            ["f(      );         g(é)", "let s = `x", "y`;"]
+        );
+        let py = "x = 1  # note\ns = \"\"\"doc\nmore\"\"\"; y = 2\n";
+        assert_eq!(
+            code_only(py, Some("py")),
+            ["x = 1", "s =", "       ; y = 2"]
+        );
+    }
+
+    #[test]
+    fn non_ascii_source_does_not_panic() {
+        assert_eq!(texts("é = \"naïve \\é\" # ü\n", "py"), vec!["naïve \\é"]);
+    }
+
+    #[test]
+    fn multi_line_strings_skipped() {
+        let py = "s = \"\"\"utilize\nleverage\n\"\"\"\nt = \"after\"\n";
+        assert_eq!(texts(py, "py"), vec!["after"]);
+    }
+
+    #[test]
+    fn findings_anchored_in_literal_and_not_fixed() {
+        let py = "print(\"We utilize cutting-edge AI\")\n";
+        let findings = apply_string_rules(py, Some("py"), false);
+        let f = findings.iter().find(|f| f.matched == "utilize").unwrap();
+        assert_eq!((f.line, f.col), (1, 10));
+        assert_eq!(f.region, Some(Region::StringLiteral));
+        assert_eq!(f.replacement, None);
+        assert_eq!(f.suggestions, vec!["use"]);
+        assert_eq!(clean(py, &findings), py);
+    }
+
+    #[test]
+    fn fix_strings_applies_fixes() {
+        let py = "print(\"We utilize it\")\n";
+        let findings = apply_string_rules(py, Some("py"), true);
+        assert_eq!(clean(py, &findings), "print(\"We use it\")\n");
+    }
+}
@@ -0,0 +1,1682 @@
+use std::collections::HashSet;
+
+use super::markdown::{headings, Fences, Heading};
+use super::text::disclaimers;
+use super::{is_word_boundary, Category, Confidence, Deadline, Finding, Region, Severity, Source};
+
+pub(super) const ROSENFELD: Source = Source {
+    citation: "Rosenfeld 2024",
+    confidence: Confidence::Medium,
+};
+
+/// Fewest connectors that make a paragraph dense, whatever its length.
+const CONNECTOR_MIN_COUNT: usize = 3;
+
+/// How much text the document needs before each paragraph-level rule judges
+/// it. A short note with two connectors or a recap line reads fine, and a few
+/// sentences are too few for their lengths to say anything.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StructuralLimits {
+    /// Prose words before connector density is checked.
+    pub connector_min_words: usize,
+    /// Connectors per 100 words of a paragraph at which it is dense.
+    pub connectors_per_100_words: f64,
+    /// Prose sentences before sentence-length uniformity is checked.
+    pub uniformity_min_sentences: usize,
+    /// Prose words before conclusion paragraphs are checked.
+    pub conclusion_min_words: usize,
+    /// Prose words before duplicate sentences and repeated stems are checked.
+    pub repetition_min_words: usize,
+    /// Non-empty lines before heading density and the heading ratio are checked.
+    pub heading_min_lines: usize,
+    /// Prose lines per heading below which a document is over-structured.
+    pub lines_per_heading: f64,
+}
+
+impl StructuralLimits {
+    /// Every rule runs whatever the document's size, and three connectors
+    /// make any paragraph dense.
+    pub const NONE: StructuralLimits = StructuralLimits {
+        connector_min_words: 0,
+        connectors_per_100_words: 0.0,
+        uniformity_min_sentences: 0,
+        conclusion_min_words: 0,
+        repetition_min_words: 0,
+        heading_min_lines: 0,
+        lines_per_heading: 4.0,
+    };
+}
+
+impl Default for StructuralLimits {
+    fn default() -> Self {
+        StructuralLimits {
+            connector_min_words: 80,
+            connectors_per_100_words: 3.0,
+            uniformity_min_sentences: 8,
+            conclusion_min_words: 80,
+            repetition_min_words: 80,
+            heading_min_lines: 20,
+            lines_per_heading: 4.0,
+        }
+    }
+}
+
+/// Apply structural rules that catch paragraph-level patterns.
+/// These operate on whole-document structure, not individual lines; each
+/// waits until the document is as long as `limits` asks. Rules whose ids are
+/// in `disabled` are skipped.
+// source: rosenfeld2024 — structural signals more stable than lexical patterns
+pub fn apply_structural_rules(
+    content: &str,
+    limits: &StructuralLimits,
+    disabled: &HashSet<String>,
+) -> Vec<Finding> {
+    apply_structural_rules_until(content, limits, disabled, Deadline::NONE)
+}
+
+/// [`apply_structural_rules`], stopping at the first paragraph or check
+/// reached after `deadline` with the findings so far.
+pub fn apply_structural_rules_until(
+    content: &str,
+    limits: &StructuralLimits,
+    disabled: &HashSet<String>,
+    deadline: Deadline,
+) -> Vec<Finding> {
+    let on = |id: &str| !disabled.contains(id);
+    let mut findings = Vec::new();
+
+    let paragraphs = split_paragraphs(content);
+    let prose = prose_sentences(&paragraphs);
+    let words: usize = prose
+        .iter()
+        .map(|(_, s)| s.split_whitespace().count())
+        .sum();
+
+    let check_connectors =
+        words >= limits.connector_min_words && on("structural/connector-density");
+    let check_uniformity =
+        prose.len() >= limits.uniformity_min_sentences && on("structural/uniform-sentence-length");
+    for &(line_offset, para) in &paragraphs {
+        if deadline.passed() {
+            return findings;
+        }
+        let para_lower = para.to_lowercase();
+        // Paragraph findings cover every line, so an ignore directive on any
+        // of them reaches the finding.
+        let last_line = line_offset + para.lines().count().max(1) - 1;
+        let end_line = (last_line > line_offset).then_some(last_line);
+
+        let count = count_connectors(&para_lower);
+
+        let para_words = para.split_whitespace().count().max(1);
+        let per_100_words = count as f64 * 100.0 / para_words as f64;
+        if check_connectors
+            && count >= CONNECTOR_MIN_COUNT
+            && per_100_words >= limits.connectors_per_100_words
+        {
+            findings.push(Finding {
+                line: line_offset,
+                col: 0,
+                matched: format!("{} discourse connectors", count),
+                message: format!(
+                    "High connector density ({} in {} words): reads as machine-generated transitions",
+                    count, para_words
+                ),
+                rule_id: "structural/connector-density".to_string(),
+                replacement: None,
+                severity: Severity::High,
+                category: Category::Structural,
+                region: Some(Region::Prose),
+                end_line,
+                source: Some(ROSENFELD),
+                ..Default::default()
+            });
+        }
+
+        // Sentence length uniformity check
+        // source: rosenfeld2024 sentence-length-clustering
+        let sentences: Vec<&str> = split_sentences(para).into_iter().map(|(_, s)| s).collect();
+
+        if check_uniformity && sentences.len() >= 4 {
+            let word_counts: Vec<f64> = sentences
+                .iter()
+                .map(|s| s.split_whitespace().count() as f64)
+                .collect();
+            let mean = word_counts.iter().sum::<f64>() / word_counts.len() as f64;
+            let variance = word_counts.iter().map(|&x| (x - mean).powi(2)).sum::<f64>()
+                / word_counts.len() as f64;
+            let stddev = variance.sqrt();
+
+            if stddev < 3.0 && mean > 5.0 {
+                findings.push(Finding {
+                    line: line_offset,
+                    col: 0,
+                    matched: format!("stddev={:.1}", stddev),
+                    message: "Uniform sentence length — LLMs cluster in 10-30 token range"
+                        .to_string(),
+                    rule_id: "structural/uniform-sentence-length".to_string(),
+                    replacement: None,
+                    severity: Severity::Medium,
+                    category: Category::Structural,
+                    region: Some(Region::Prose),
+                    end_line,
+                    source: Some(ROSENFELD),
+                    ..Default::default()
+                });
+            }
+        }
+    }
+
+    // The document-wide checks each read every paragraph again.
+    if deadline.passed() {
+        return findings;
+    }
+    if on("structural/repeated-opener") {
+        check_repeated_openers(&paragraphs, &mut findings);
+    }
+    if on("structural/connector-openers") {
+        check_connector_openers(&paragraphs, &mut findings);
+    }
+    if on("structural/bold-lead-in-list") {
+        check_bold_lead_ins(content, &mut findings);
+    }
+    check_wrapped_disclaimers(&paragraphs, disabled, &mut findings);
+    if deadline.passed() {
+        return findings;
+    }
+    if words >= limits.conclusion_min_words {
+        check_restating_conclusion(&paragraphs, disabled, &mut findings);
+    }
+    if words >= limits.repetition_min_words {
+        if on("structural/duplicate-sentence") && !deadline.passed() {
+            check_duplicate_sentences(&paragraphs, &mut findings);
+        }
+        if on("structural/repeated-stem") && !deadline.passed() {
+            check_repeated_stems(content, &mut findings);
+        }
+    }
+
+    findings
+}
+
+/// Fewest adjacent list items with a bold lead-in that make a list formulaic.
+const BOLD_LEAD_IN_MIN_ITEMS: usize = 3;
+
+/// Report runs of adjacent Markdown list items that each open with a bold
+/// lead-in, "- **Scalability:** the system…", at the first item's line.
+/// Blank lines, continuation lines and items nested deeper than the run do
+/// not break it; an item at the run's level without a lead-in does.
+fn check_bold_lead_ins(content: &str, findings: &mut Vec<Finding>) {
+    // First line, last line and indent of the open run, and its item count.
+    let mut run: Option<(usize, usize, usize, usize)> = None;
+    let mut close = |run: &mut Option<(usize, usize, usize, usize)>| {
+        if let Some((first, last, _, count)) = run.take() {
+            if count >= BOLD_LEAD_IN_MIN_ITEMS {
+                findings.push(Finding {
+                    line: first,
+                    col: 0,
+                    matched: format!("{count} bold lead-ins"),
+                    message: format!(
+                        "{count} list items in a row open with a bold lead-in: drop the labels or write it as prose"
+                    ),
+                    rule_id: "structural/bold-lead-in-list".to_string(),
+                    replacement: None,
+                    severity: Severity::High,
+                    category: Category::Structural,
+                    region: Some(Region::Prose),
+                    end_line: Some(last),
+                    ..Default::default()
+                });
+            }
+        }
+    };
+    let mut fences = Fences::default();
+    for (idx, line) in content.lines().enumerate() {
+        if fences.in_code(line) {
+            close(&mut run);
+            continue;
+        }
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() {
+            continue;
+        }
+        let indent = line.len() - trimmed.len();
+        let run_indent = run.map(|(_, _, indent, _)| indent);
+        match list_item_text(trimmed) {
+            Some(_) if run_indent.is_some_and(|i| indent > i) => {}
+            Some(text) if has_bold_lead_in(text) => match &mut run {
+                Some((_, last, i, count)) if *i == indent => {
+                    *last = idx + 1;
+                    *count += 1;
+                }
+                _ => {
+                    close(&mut run);
+                    run = Some((idx + 1, idx + 1, indent, 1));
+                }
+            },
+            Some(_) => close(&mut run),
+            None if run_indent.is_some_and(|i| indent > i) => {}
+            None => close(&mut run),
+        }
+    }
+    close(&mut run);
+}
+
+/// The text of a Markdown list item after its `-`, `*`, `+`, `1.` or `1)`
+/// marker, or `None` when `trimmed` is not one.
+fn list_item_text(trimmed: &str) -> Option<&str> {
+    let digits = trimmed.bytes().take_while(u8::is_ascii_digit).count();
+    let marker = match trimmed.as_bytes().get(digits)? {
+        b'-' | b'*' | b'+' if digits == 0 => 1,
+        b'.' | b')' if (1..=9).contains(&digits) => digits + 1,
+        _ => return None,
+    };
+    let rest = &trimmed[marker..];
+    rest.starts_with([' ', '\t']).then(|| rest.trim_start())
+}
+
+/// Whether a list item opens with a bold phrase followed by a colon or a
+/// dash: `**Scalability:**`, `**Scalability**:` or `**Scalability** —`.
+fn has_bold_lead_in(text: &str) -> bool {
+    let Some(bold) = text.strip_prefix("**") else {
+        return false;
+    };
+    let Some(end) = bold.find("**") else {
+        return false;
+    };
+    let (label, after) = (&bold[..end], bold[end + 2..].trim_start());
+    !label.trim().is_empty()
+        && (label.ends_with(':') || after.starts_with([':', '\u{2014}', '\u{2013}', '-']))
+}
+
+/// Report the chatbot disclaimers that a hard wrap splits across lines,
+/// where the line-by-line text rules cannot see them. Each prose paragraph is
+/// joined onto one line; a phrase found there more often than on its single
+/// lines is reported once, at the paragraph's first line, under the text
+/// rule's id.
+fn check_wrapped_disclaimers(
+    paragraphs: &[(usize, &str)],
+    disabled: &HashSet<String>,
+    findings: &mut Vec<Finding>,
+) {
+    let phrases: Vec<_> = disclaimers()
+        .filter(|(id, _, _)| !disabled.contains(id))
+        .collect();
+    for (line_offset, para) in prose_paragraphs(paragraphs) {
+        let lines: Vec<String> = para
+            .lines()
+            .map(|l| {
+                l.split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join(" ")
+                    .to_lowercase()
+            })
+            .filter(|l| !l.is_empty())
+            .collect();
+        if lines.len() < 2 {
+            continue;
+        }
+        let joined = lines.join(" ");
+        for (id, needle, message) in &phrases {
+            let on_lines: usize = lines.iter().map(|l| phrase_count(l, needle)).sum();
+            if phrase_count(&joined, needle) <= on_lines {
+                continue;
+            }
+            findings.push(Finding {
+                line: line_offset,
+                col: 0,
+                matched: needle.to_string(),
+                message: format!("{message}, wrapped across lines"),
+                rule_id: id.clone(),
+                severity: Severity::Critical,
+                category: Category::Text,
+                region: Some(Region::Prose),
+                end_line: Some(line_offset + para.lines().count() - 1),
+                ..Default::default()
+            });
+        }
+    }
+}
+
+/// Whole-word occurrences of `needle` in `text`.
+fn phrase_count(text: &str, needle: &str) -> usize {
+    text.match_indices(needle)
+        .filter(|&(i, _)| is_word_boundary(text, i, i + needle.len()))
+        .count()
+}
+
+// source: rosenfeld2024 — structural signals more stable than lexical
+const CONNECTORS: &[&str] = &[
+    "moreover",
+    "furthermore",
+    "additionally",
+    "consequently",
+    "subsequently",
+    "nevertheless",
+    "nonetheless",
+    "in addition",
+    "as a result",
+    "on the other hand",
+    "with that said",
+    "that being said",
+    "to summarize",
+    "in summary",
+    "in conclusion",
+];
+
+/// Discourse connectors ("moreover", "as a result"…) in `lower`, a lowercased
+/// paragraph, counted where they stand as whole words.
+pub(crate) fn count_connectors(lower: &str) -> usize {
+    CONNECTORS
+        .iter()
+        .map(|&c| {
+            let mut n = 0;
+            let mut start = 0;
+            while let Some(pos) = lower[start..].find(c) {
+                let col = start + pos;
+                let end = col + c.len();
+                if is_word_boundary(lower, col, end) {
+                    n += 1;
+                }
+                start = end;
+            }
+            n
+        })
+        .sum()
+}
+
+/// Sentence-opening words that make a sentence first-person plural.
+const FIRST_PERSON_PLURAL: &[&str] = &["we", "our", "ours", "ourselves"];
+
+/// Sections shorter than this many sentences are too small to judge.
+const FIRST_PERSON_MIN_SENTENCES: usize = 3;
+
+/// Opt-in rule for neutral technical docs: flag each section (text between
+/// headings) where more than `max_fraction` of sentences open with "we" or
+/// "our" — "We believe… We recommend… Our comprehensive solution…". Off by
+/// default since blog posts use "we" legitimately.
+pub fn check_first_person_plural(content: &str, max_fraction: f64) -> Vec<Finding> {
+    #[derive(Default)]
+    struct Section {
+        sentences: usize,
+        openers: usize,
+        /// Line, column, and word of the first first-person opener.
+        first: Option<(usize, usize, String)>,
+    }
+
+    fn flush(section: &mut Section, max_fraction: f64, findings: &mut Vec<Finding>) {
+        let Section {
+            sentences,
+            openers,
+            first,
+        } = std::mem::take(section);
+        let Some((line, col, word)) = first else {
+            return;
+        };
+        if sentences >= FIRST_PERSON_MIN_SENTENCES
+            && openers as f64 / sentences as f64 > max_fraction
+        {
+            findings.push(Finding {
+                line,
+                col,
+                matched: word,
+                message: format!(
+                    "First-person plural opens {} of {} sentences in this section: prefer neutral wording",
+                    openers, sentences
+                ),
+                rule_id: "structural/first-person-plural".to_string(),
+                replacement: None,
+                severity: Severity::Medium,
+                category: Category::Structural,
+                region: Some(Region::Prose),
+                ..Default::default()
+            });
+        }
+    }
+
+    let mut findings = Vec::new();
+    let mut section = Section::default();
+    let mut fences = Fences::default();
+    let mut at_sentence_start = true;
+    for (idx, line) in content.lines().enumerate() {
+        if fences.in_code(line) {
+            continue;
+        }
+        let trimmed = line.trim();
+        if trimmed.starts_with('#') {
+            flush(&mut section, max_fraction, &mut findings);
+            at_sentence_start = true;
+            continue;
+        }
+        if trimmed.is_empty() {
+            at_sentence_start = true;
+            continue;
+        }
+        for (i, (offset, sentence)) in split_sentences(line).into_iter().enumerate() {
+            // A line continuing the previous line's sentence starts mid-sentence.
+            if i == 0 && !at_sentence_start {
+                continue;
+            }
+            section.sentences += 1;
+            // Skip list markers, quote marks, and emphasis before the first word.
+            let lead = sentence.len()
+                - sentence
+                    .trim_start_matches(|c: char| !c.is_alphabetic())
+                    .len();
+            let word: String = sentence[lead..]
+                .chars()
+                .take_while(|c| c.is_alphabetic())
+                .collect();
+            if FIRST_PERSON_PLURAL.contains(&word.to_lowercase().as_str()) {
+                section.openers += 1;
+                section.first.get_or_insert((idx + 1, offset + lead, word));
+            }
+        }
+        at_sentence_start = trimmed.ends_with(['.', '!', '?', ':']);
+    }
+    flush(&mut section, max_fraction, &mut findings);
+    findings
+}
+
+/// Documents with fewer headings than this are not judged for density.
+const HEADING_DENSITY_MIN_HEADINGS: usize = 3;
+
+/// Average sentences per heading below which a document is over-structured.
+const MIN_SENTENCES_PER_HEADING: f64 = 3.0;
+
+/// Headings with more words than this read as sentences.
+const MAX_HEADING_WORDS: usize = 12;
+
+/// Small words that stay lowercase in a title-cased heading.
+const TITLE_CASE_SMALL_WORDS: &[&str] = &[
+    "a", "an", "and", "as", "at", "but", "by", "for", "in", "nor", "of", "on", "or", "the", "to",
+    "vs",
+];
+
+/// Fewest words for a question heading to read as title-cased.
+const QUESTION_HEADING_MIN_WORDS: usize = 3;
+
+/// Whether `text` is a question with every word but the small ones
+/// capitalized: "What Is The Architecture?", not "What is Rust?".
+fn is_title_cased_question(text: &str) -> bool {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    text.ends_with('?')
+        && words.len() >= QUESTION_HEADING_MIN_WORDS
+        && words.iter().enumerate().all(|(i, w)| {
+            let word = w.trim_matches(|c: char| !c.is_alphanumeric());
+            match word.chars().next() {
+                Some(c) if c.is_alphabetic() => {
+                    c.is_uppercase()
+                        || (i > 0 && TITLE_CASE_SMALL_WORDS.contains(&word.to_lowercase().as_str()))
+                }
+                _ => true,
+            }
+        })
+}
+
+/// Markdown heading structure for text mode:
+///
+/// - in a document of `limits.heading_min_lines` non-empty lines or more, a
+///   Medium finding when headings come more often than every
+///   `MIN_SENTENCES_PER_HEADING` sentences, or otherwise when there is a
+///   heading for fewer than every `limits.lines_per_heading` prose lines;
+/// - a Low finding for each heading that is a title-cased question;
+/// - a Low finding for each heading that skips a level below the one before
+///   it (H1 → H3), and one for each heading over `MAX_HEADING_WORDS` words.
+pub fn check_headings(content: &str, limits: &StructuralLimits) -> Vec<Finding> {
+    let headings = headings(content);
+    let mut findings = Vec::new();
+
+    // Non-empty lines outside fenced code: a short document is allowed many
+    // headings, and the ratio counts the prose lines between them.
+    let mut fences = Fences::default();
+    let lines = content
+        .lines()
+        .filter(|l| !fences.in_code(l) && !l.trim().is_empty())
+        .count();
+    let long_enough = !headings.is_empty() && lines >= limits.heading_min_lines;
+
+    let mut dense = false;
+    if long_enough && headings.len() >= HEADING_DENSITY_MIN_HEADINGS {
+        let body = blank_headings(content, &headings);
+        let sentences = prose_sentences(&split_paragraphs(&body)).len();
+        let per_heading = sentences as f64 / headings.len() as f64;
+        dense = per_heading < MIN_SENTENCES_PER_HEADING;
+        if dense {
+            findings.push(Finding {
+                line: headings[0].line,
+                col: 0,
+                matched: format!("{} headings", headings.len()),
+                message: format!(
+                    "{} headings over {} sentences: fold short sections together",
+                    headings.len(),
+                    sentences
+                ),
+                rule_id: "structural/heading-density".to_string(),
+                replacement: None,
+                severity: Severity::Medium,
+                category: Category::Structural,
+                region: Some(Region::Prose),
+                ..Default::default()
+            });
+        }
+    }
+
+    let prose_lines = lines - headings.len();
+    if long_enough
+        && !dense
+        && (prose_lines as f64) < headings.len() as f64 * limits.lines_per_heading
+    {
+        findings.push(Finding {
+            line: headings[0].line,
+            col: 0,
+            matched: format!("{} headings", headings.len()),
+            message: format!(
+                "{} headings over {} lines of prose: fold short sections together",
+                headings.len(),
+                prose_lines
+            ),
+            rule_id: "structural/heading-ratio".to_string(),
+            replacement: None,
+            severity: Severity::Medium,
+            category: Category::Structural,
+            region: Some(Region::Prose),
+            ..Default::default()
+        });
+    }
+
+    for h in headings.iter().filter(|h| is_title_cased_question(h.text)) {
+        findings.push(Finding {
+            line: h.line,
+            col: h.col,
+            matched: h.text.to_string(),
+            message: "Heading is a title-cased question: name the topic instead".to_string(),
+            rule_id: "structural/question-headings".to_string(),
+            replacement: None,
+            severity: Severity::Low,
+            category: Category::Structural,
+            region: Some(Region::Prose),
+            ..Default::default()
+        });
+    }
+
+    for pair in headings.windows(2) {
+        let (prev, h) = (&pair[0], &pair[1]);
+        if h.level > prev.level + 1 {
+            findings.push(Finding {
+                line: h.line,
+                col: 0,
+                matched: "#".repeat(h.level),
+                message: format!(
+                    "Heading skips a level: H{} follows H{}",
+                    h.level, prev.level
+                ),
+                rule_id: "structural/heading-skip".to_string(),
+                replacement: None,
+                severity: Severity::Low,
+                category: Category::Structural,
+                region: Some(Region::Prose),
+                ..Default::default()
+            });
+        }
+    }
+
+    for h in &headings {
+        let words = h.text.split_whitespace().count();
+        if words > MAX_HEADING_WORDS {
+            findings.push(Finding {
+                line: h.line,
+                col: h.col,
+                matched: h.text.to_string(),
+                message: format!("Heading is {} words long: shorten it to a label", words),
+                rule_id: "structural/long-heading".to_string(),
+                replacement: None,
+                severity: Severity::Low,
+                category: Category::Structural,
+                region: Some(Region::Prose),
+                ..Default::default()
+            });
+        }
+    }
+
+    findings
+}
+
+/// `content` with its `headings` lines blanked out, so a heading is not read
+/// as a sentence and one without a full stop does not merge into the next.
+fn blank_headings(content: &str, headings: &[Heading]) -> String {
+    let heading_lines: HashSet<usize> = headings.iter().map(|h| h.line).collect();
+    content
+        .lines()
+        .enumerate()
+        .map(|(i, l)| {
+            if heading_lines.contains(&(i + 1)) {
+                ""
+            } else {
+                l
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Split `content` on blank lines, pairing each paragraph with its 1-based
+/// starting line.
+pub(crate) fn split_paragraphs(content: &str) -> Vec<(usize, &str)> {
+    let mut paragraphs = Vec::new();
+    let mut line_offset = 1usize;
+    for para in content.split("\n\n") {
+        paragraphs.push((line_offset, para));
+        // split("\n\n") consumes both newlines — the separator is one blank line,
+        // so the next paragraph starts 1 line after the last line of this one.
+        line_offset += para.lines().count() + 1;
+    }
+    paragraphs
+}
+
+/// Split a paragraph into sentences, pairing each with its byte offset in
+/// `para`. Trailing whitespace stays with the sentence it follows.
+pub(crate) fn split_sentences(para: &str) -> Vec<(usize, &str)> {
+    let mut sentences = Vec::new();
+    let mut remaining = para.trim();
+    let mut offset = para.len() - para.trim_start().len();
+    while !remaining.is_empty() {
+        // A sentence ends at `.`, `!` or `?` before a space or newline.
+        let cut = remaining
+            .as_bytes()
+            .windows(2)
+            .position(|w| matches!(w, [b'.' | b'!' | b'?', b' ' | b'\n']))
+            .map_or(remaining.len(), |pos| pos + 2);
+        let (sentence, rest) = remaining.split_at(cut);
+        sentences.push((offset, sentence));
+        let next = rest.trim_start();
+        offset += cut + (rest.len() - next.len());
+        remaining = next;
+    }
+    sentences
+}
+
+const CONCLUSION_OPENERS: &[&str] = &[
+    "in summary",
+    "in conclusion",
+    "to conclude",
+    "to summarize",
+    "to sum up",
+];
+
+/// Closing paragraphs longer than this are treated as real content, not a recap.
+const CONCLUSION_MAX_WORDS: usize = 80;
+
+/// Share of the closing paragraph's content words that must already appear
+/// earlier for it to count as a restatement.
+const RESTATEMENT_OVERLAP: f64 = 0.6;
+
+/// Flag a final "In summary, ..." paragraph. When it only repeats words from
+/// earlier paragraphs, every line of it gets an empty replacement so `clean()`
+/// removes the block, with the blank line before it; otherwise the opener is
+/// flagged without a fix. Either finding is dropped when its id is in
+/// `disabled`.
+fn check_restating_conclusion(
+    paragraphs: &[(usize, &str)],
+    disabled: &HashSet<String>,
+    findings: &mut Vec<Finding>,
+) {
+    let Some(last_idx) = paragraphs.iter().rposition(|(_, p)| !p.trim().is_empty()) else {
+        return;
+    };
+    if last_idx == 0 {
+        return;
+    }
+    let (start_line, para) = paragraphs[last_idx];
+    let lower = para.trim_start().to_lowercase();
+    let Some(opener) = CONCLUSION_OPENERS
+        .iter()
+        .find(|o| lower.starts_with(*o) && is_word_boundary(&lower, 0, o.len()))
+    else {
+        return;
+    };
+    let words = content_words(&lower[opener.len()..]);
+    if words.is_empty() || para.split_whitespace().count() > CONCLUSION_MAX_WORDS {
+        return;
+    }
+
+    let earlier: std::collections::HashSet<String> = paragraphs[..last_idx]
+        .iter()
+        .flat_map(|(_, p)| content_words(&p.to_lowercase()))
+        .collect();
+    let repeated = words.iter().filter(|w| earlier.contains(*w)).count();
+    let overlap = repeated as f64 / words.len() as f64;
+
+    let leading_blank = para.len() - para.trim_start().len();
+    let opener_line = start_line + para[..leading_blank].matches('\n').count();
+    let opener_text = para
+        .trim_start()
+        .get(..opener.len())
+        .unwrap_or(opener)
+        .to_string();
+
+    if overlap < RESTATEMENT_OVERLAP {
+        if disabled.contains("structural/conclusion-opener") {
+            return;
+        }
+        findings.push(Finding {
+            line: opener_line,
+            col: 0,
+            matched: opener_text,
+            message: "Conclusion paragraph: state the new point without the recap opener"
+                .to_string(),
+            rule_id: "structural/conclusion-opener".to_string(),
+            replacement: None,
+            severity: Severity::Medium,
+            category: Category::Structural,
+            region: Some(Region::Prose),
+            ..Default::default()
+        });
+        return;
+    }
+    if disabled.contains("structural/restating-conclusion") {
+        return;
+    }
+
+    let message = format!(
+        "Restating conclusion ({:.0}% of its words repeat earlier text): drop the paragraph",
+        overlap * 100.0
+    );
+    for (offset, line) in para.trim_end_matches('\n').lines().enumerate() {
+        findings.push(Finding {
+            line: start_line + offset,
+            col: 0,
+            matched: line.to_string(),
+            message: message.clone(),
+            rule_id: "structural/restating-conclusion".to_string(),
+            replacement: Some(String::new()),
+            severity: Severity::Medium,
+            category: Category::Structural,
+            region: Some(Region::Prose),
+            ..Default::default()
+        });
+    }
+}
+
+/// Token n-gram size used to fingerprint sentences for near-duplicate search.
+const SHINGLE_SIZE: usize = 5;
+
+/// Jaccard similarity of two sentences' shingle sets at or above which they
+/// count as the same sentence reworded.
+const DUPLICATE_SIMILARITY: f64 = 0.5;
+
+/// Shingles shared by more sentences than this only propose adjacent pairs
+/// instead of every pair, keeping a sentence repeated hundreds of times linear.
+const MAX_SHINGLE_FANOUT: usize = 32;
+
+/// Upper bound on pairwise comparisons per document, keeping huge inputs fast.
+const MAX_PAIR_COMPARISONS: usize = 20_000;
+
+/// Report groups of near-identical sentences — generated docs repeat "X
+/// provides a robust and scalable solution" across sections. Sentences are
+/// fingerprinted by normalized token 5-grams; only pairs sharing a shingle
+/// are compared, and each group yields one finding listing its lines.
+fn check_duplicate_sentences(paragraphs: &[(usize, &str)], findings: &mut Vec<Finding>) {
+    use std::collections::{BTreeMap, HashMap, HashSet};
+    use std::hash::{DefaultHasher, Hash, Hasher};
+
+    struct Sentence<'a> {
+        line: usize,
+        text: &'a str,
+        shingles: HashSet<u64>,
+    }
+
+    let mut sentences: Vec<Sentence> = Vec::new();
+    for (line, text) in prose_sentences(paragraphs) {
+        let tokens = tokens(text);
+        if tokens.len() <= SHINGLE_SIZE {
+            continue;
+        }
+        let shingles = tokens
+            .windows(SHINGLE_SIZE)
+            .map(|w| {
+                let mut h = DefaultHasher::new();
+                w.hash(&mut h);
+                h.finish()
+            })
+            .collect();
+        sentences.push(Sentence {
+            line,
+            text: text.trim(),
+            shingles,
+        });
+    }
+
+    // Ordered so the comparison cap cuts off at the same place on every run.
+    let mut index: BTreeMap<u64, Vec<usize>> = BTreeMap::new();
+    for (i, s) in sentences.iter().enumerate() {
+        for &sh in &s.shingles {
+            index.entry(sh).or_default().push(i);
+        }
+    }
+
+    // Union-find over sentence indices.
+    let mut parent: Vec<usize> = (0..sentences.len()).collect();
+    fn root(parent: &mut [usize], mut i: usize) -> usize {
+        while parent[i] != i {
+            parent[i] = parent[parent[i]];
+            i = parent[i];
+        }
+        i
+    }
+
+    let mut compared: HashSet<(usize, usize)> = HashSet::new();
+    'outer: for ids in index.values() {
+        let pairs: Vec<(usize, usize)> = if ids.len() > MAX_SHINGLE_FANOUT {
+            ids.windows(2).map(|w| (w[0], w[1])).collect()
+        } else {
+            ids.iter()
+                .enumerate()
+                .flat_map(|(n, &a)| ids[n + 1..].iter().map(move |&b| (a, b)))
+                .collect()
+        };
+        for (a, b) in pairs {
+            if compared.len() >= MAX_PAIR_COMPARISONS {
+                break 'outer;
+            }
+            if !compared.insert((a, b)) {
+                continue;
+            }
+            let (sa, sb) = (&sentences[a].shingles, &sentences[b].shingles);
+            let shared = sa.intersection(sb).count();
+            let similarity = shared as f64 / (sa.len() + sb.len() - shared) as f64;
+            if similarity >= DUPLICATE_SIMILARITY {
+                let (ra, rb) = (root(&mut parent, a), root(&mut parent, b));
+                parent[ra.max(rb)] = ra.min(rb);
+            }
+        }
+    }
+
+    let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();
+    for i in 0..sentences.len() {
+        let r = root(&mut parent, i);
+        groups.entry(r).or_default().push(i);
+    }
+    let mut groups: Vec<Vec<usize>> = groups.into_values().filter(|g| g.len() > 1).collect();
+    groups.sort_by_key(|g| g[0]);
+
+    for group in groups {
+        let lines: Vec<usize> = group.iter().map(|&i| sentences[i].line).collect();
+        let first = &sentences[group[0]];
+        findings.push(Finding {
+            line: first.line,
+            col: 0,
+            matched: first.text.to_string(),
+            message: format!(
+                "Near-duplicate sentence repeated {} times {}: say it once",
+                group.len(),
+                where_repeated(&lines)
+            ),
+            rule_id: "structural/duplicate-sentence".to_string(),
+            replacement: None,
+            severity: Severity::Medium,
+            category: Category::Structural,
+            region: Some(Region::Prose),
+            ..Default::default()
+        });
+    }
+}
+
+/// A repeated sentence's message lists this many of its lines, then how many
+/// more.
+const REPEAT_LINES_LISTED: usize = 5;
+
+/// Where a repeated sentence appears, from its sorted 1-based `lines`: "on
+/// line 4" when every copy shares one line, else the first
+/// `REPEAT_LINES_LISTED` distinct lines in parentheses.
+fn where_repeated(lines: &[usize]) -> String {
+    let mut distinct = lines.to_vec();
+    distinct.dedup();
+    if let [line] = distinct[..] {
+        return format!("on line {line}");
+    }
+    let mut listed = distinct
+        .iter()
+        .take(REPEAT_LINES_LISTED)
+        .map(usize::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+    if distinct.len() > REPEAT_LINES_LISTED {
+        listed += &format!(" and {} more", distinct.len() - REPEAT_LINES_LISTED);
+    }
+    format!("(lines {listed})")
+}
+
+/// A sentence stem must open at least this many sentences to be reported.
+const STEM_MIN_REPEATS: usize = 4;
+
+/// Words too common to make a stem distinctive on their own: a stem made only
+/// of these ("It is the") is not reported.
+const STEM_STOPWORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "he", "i", "if", "in", "is",
+    "it", "its", "of", "on", "or", "she", "so", "that", "the", "there", "these", "they", "this",
+    "those", "to", "was", "we", "were", "with", "you",
+];
+
+/// Report sentence stems reused across the document, such as "This allows you
+/// to…" opening one sentence after another. Sentences are bucketed by their first three
+/// normalized tokens, Markdown headings left out; each stem opening
+/// `STEM_MIN_REPEATS` or more sentences yields one finding listing its lines.
+fn check_repeated_stems(content: &str, findings: &mut Vec<Finding>) {
+    let body = blank_headings(content, &headings(content));
+    let mut stems: std::collections::HashMap<Vec<String>, Vec<usize>> = Default::default();
+    for (line, text) in prose_sentences(&split_paragraphs(&body)) {
+        let mut stem = tokens(text);
+        if stem.len() < 3 {
+            continue;
+        }
+        stem.truncate(3);
+        if stem.iter().all(|w| STEM_STOPWORDS.contains(&w.as_str())) {
+            continue;
+        }
+        stems.entry(stem).or_default().push(line);
+    }
+    let mut stems: Vec<(Vec<String>, Vec<usize>)> = stems
+        .into_iter()
+        .filter(|(_, lines)| lines.len() >= STEM_MIN_REPEATS)
+        .collect();
+    stems.sort_by_key(|(_, lines)| lines[0]);
+
+    for (stem, lines) in stems {
+        findings.push(Finding {
+            line: lines[0],
+            col: 0,
+            matched: stem.join(" "),
+            message: format!(
+                "Sentence stem repeated {} times {}: vary how sentences open",
+                lines.len(),
+                where_repeated(&lines)
+            ),
+            rule_id: "structural/repeated-stem".to_string(),
+            replacement: None,
+            severity: Severity::Medium,
+            category: Category::Structural,
+            region: Some(Region::Prose),
+            ..Default::default()
+        });
+    }
+}
+
+/// Sentences outside fenced code, each paired with its 1-based line.
+fn prose_sentences<'a>(paragraphs: &[(usize, &'a str)]) -> Vec<(usize, &'a str)> {
+    let mut sentences = Vec::new();
+    for (start_line, para) in prose_paragraphs(paragraphs) {
+        for (offset, text) in split_sentences(para) {
+            sentences.push((start_line + para[..offset].matches('\n').count(), text));
+        }
+    }
+    sentences
+}
+
+/// The `paragraphs` outside fenced code blocks.
+pub(crate) fn prose_paragraphs<'a>(paragraphs: &[(usize, &'a str)]) -> Vec<(usize, &'a str)> {
+    let mut prose = Vec::new();
+    let mut fences = Fences::default();
+    for &(start_line, para) in paragraphs {
+        // A paragraph holding any fence or code line is skipped whole; every
+        // line is still read so the block state carries into the next one.
+        let code = para.lines().fold(false, |code, l| fences.in_code(l) | code);
+        if !code {
+            prose.push((start_line, para));
+        }
+    }
+    prose
+}
+
+/// Consecutive sentences of a paragraph opening with the same word that make
+/// the run worth reporting.
+const OPENER_MIN_RUN: usize = 3;
+
+/// Opening words too plain to count as a repeat.
+const OPENER_STOPWORDS: &[&str] = &["the", "a", "i"];
+
+/// Report runs of `OPENER_MIN_RUN` or more consecutive sentences in one
+/// paragraph that open with the same word, "This ensures… This allows… This
+/// provides…", at the run's first line.
+fn check_repeated_openers(paragraphs: &[(usize, &str)], findings: &mut Vec<Finding>) {
+    for (start_line, para) in prose_paragraphs(paragraphs) {
+        let sentences = split_sentences(para);
+        let openers: Vec<Option<String>> = sentences
+            .iter()
+            .map(|(_, s)| tokens(s).into_iter().next())
+            .collect();
+        let mut i = 0;
+        while i < openers.len() {
+            let run = openers[i..]
+                .iter()
+                .take_while(|w| **w == openers[i])
+                .count();
+            let word = openers[i]
+                .as_deref()
+                .filter(|w| !OPENER_STOPWORDS.contains(w));
+            if let (Some(word), true) = (word, run >= OPENER_MIN_RUN) {
+                let line_of = |k: usize| start_line + para[..sentences[k].0].matches('\n').count();
+                let (first, last) = (line_of(i), line_of(i + run - 1));
+                findings.push(Finding {
+                    line: first,
+                    col: 0,
+                    matched: word.to_string(),
+                    message: format!(
+                        "{run} sentences in a row open with \"{word}\": vary how they start"
+                    ),
+                    rule_id: "structural/repeated-opener".to_string(),
+                    replacement: None,
+                    severity: Severity::Medium,
+                    category: Category::Structural,
+                    region: Some(Region::Prose),
+                    end_line: (last > first).then_some(last),
+                    ..Default::default()
+                });
+            }
+            i += run;
+        }
+    }
+}
+
+/// Fewest prose paragraphs before connector openers are judged.
+const CONNECTOR_OPENER_MIN_PARAGRAPHS: usize = 3;
+
+/// Report a document where more than half the prose paragraphs open with a
+/// discourse connector ("Moreover, … Furthermore, …"), at the first such
+/// paragraph, naming each connector and how many paragraphs it opens.
+fn check_connector_openers(paragraphs: &[(usize, &str)], findings: &mut Vec<Finding>) {
+    let prose: Vec<(usize, String)> = prose_paragraphs(paragraphs)
+        .into_iter()
+        .filter(|(_, p)| !p.trim().is_empty())
+        .filter(|(_, p)| !p.lines().all(|l| l.trim_start().starts_with('#')))
+        .map(|(line, p)| (line, tokens(p).join(" ")))
+        .collect();
+    if prose.len() < CONNECTOR_OPENER_MIN_PARAGRAPHS {
+        return;
+    }
+    // Each connector with the number of paragraphs it opens, in order of
+    // first use, and the line of the first of those paragraphs.
+    let mut used: Vec<(&str, usize)> = Vec::new();
+    let mut first_line = None;
+    for (line, words) in &prose {
+        let Some(&connector) = CONNECTORS.iter().find(|c| {
+            words
+                .strip_prefix(**c)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with(' '))
+        }) else {
+            continue;
+        };
+        first_line.get_or_insert(*line);
+        match used.iter_mut().find(|(c, _)| *c == connector) {
+            Some((_, n)) => *n += 1,
+            None => used.push((connector, 1)),
+        }
+    }
+    let opened: usize = used.iter().map(|(_, n)| n).sum();
+    let Some(line) = first_line.filter(|_| opened * 2 > prose.len()) else {
+        return;
+    };
+    let named: Vec<String> = used.iter().map(|(c, n)| format!("\"{c}\" {n}×")).collect();
+    findings.push(Finding {
+        line,
+        col: 0,
+        matched: format!("{opened} connector openers"),
+        message: format!(
+            "{opened} of {} paragraphs open with a discourse connector ({}): open with the point",
+            prose.len(),
+            named.join(", ")
+        ),
+        rule_id: "structural/connector-openers".to_string(),
+        replacement: None,
+        severity: Severity::Low,
+        category: Category::Structural,
+        region: Some(Region::Prose),
+        ..Default::default()
+    });
+}
+
+/// Lowercase alphanumeric runs of `text`, in order.
+fn tokens(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Lowercase words of four or more letters — a cheap stand-in for content words.
+fn content_words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.chars().count() >= 4)
+        .map(str::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Structural findings with the default limits.
+    fn structural(content: &str) -> Vec<Finding> {
+        apply_structural_rules(content, &StructuralLimits::default(), &HashSet::new())
+    }
+
+    #[test]
+    fn bold_lead_in_list_flagged_at_first_item() {
+        let doc = "Intro.\n\n- **Scalability:** it grows.\n  more on scale\n- **Speed**: it is fast.\n  * nested detail\n\n- **Cost** \u{2014} it is cheap.\n\nAfter.\n";
+        let findings = structural(doc);
+        assert_eq!(findings.len(), 1, "{findings:?}");
+        let f = &findings[0];
+        assert_eq!(f.rule_id, "structural/bold-lead-in-list");
+        assert_eq!((f.line, f.end_line), (3, Some(8)));
+        assert_eq!(f.severity, Severity::High);
+        assert!(f.message.starts_with("3 list items"), "{}", f.message);
+
+        let numbered = "1. **A:** x\n2. **B:** y\n3. **C:** z\n";
+        assert_eq!(structural(numbered).len(), 1);
+        let nested = "- Parts\n    - **A:** x\n    - **B:** y\n    - **C:** z\n";
+        assert_eq!(structural(nested)[0].line, 2);
+    }
+
+    #[test]
+    fn bold_lead_in_run_broken_by_plain_item() {
+        let doc = "- **A:** x\n- **B:** y\n- plain\n- **C:** z\n- **D** just bold\n";
+        assert!(structural(doc).is_empty());
+        let disabled = HashSet::from(["structural/bold-lead-in-list".to_string()]);
+        let three = "- **A:** x\n- **B:** y\n- **C:** z\n";
+        assert!(apply_structural_rules(three, &StructuralLimits::default(), &disabled).is_empty());
+    }
+
+    #[test]
+    fn repeated_opener_names_word_and_count() {
+        let doc = "It works.\nThis keeps reads fast. This avoids\nlocks. this scales. It ends.\n";
+        let findings = structural(doc);
+        assert_eq!(findings.len(), 1, "{findings:?}");
+        let f = &findings[0];
+        assert_eq!(f.rule_id, "structural/repeated-opener");
+        assert_eq!((f.line, f.end_line), (2, Some(3)));
+        assert_eq!(f.severity, Severity::Medium);
+        assert!(f.message.starts_with("3 sentences"), "{}", f.message);
+        assert!(f.message.contains("\"this\""), "{}", f.message);
+
+        let plain = "The cache is warm. The disk is cold. The net is slow.";
+        assert!(structural(plain).is_empty());
+        let two = "This is fast. This is safe. It scales.";
+        assert!(structural(two).is_empty());
+    }
+
+    #[test]
+    fn connector_openers_need_more_than_half() {
+        let doc = "Moreover, reads are fast.\n\nThe cache is small.\n\nMoreover, writes batch.\n\nFurthermore, it scales.\n";
+        let findings = structural(doc);
+        assert_eq!(findings.len(), 1, "{findings:?}");
+        let f = &findings[0];
+        assert_eq!(f.rule_id, "structural/connector-openers");
+        assert_eq!((f.line, f.severity), (1, Severity::Low));
+        assert!(f.message.starts_with("3 of 4 paragraphs"), "{}", f.message);
+        assert!(f.message.contains("\"moreover\" 2×"), "{}", f.message);
+
+        let half = "Moreover, reads are fast.\n\nThe cache is small.\n\nFurthermore, it scales.\n\nWrites batch.\n";
+        assert!(structural(half).is_empty());
+        let short = "Moreover, reads are fast.\n\nFurthermore, it scales.\n";
+        assert!(structural(short).is_empty());
+    }
+
+    #[test]
+    fn wrapped_disclaimer_reported_at_paragraph_start() {
+        let wrapped =
+            "Intro line here.\nThe answer is no. As an AI\nlanguage model, I cannot\nsay more.\n";
+        let findings = structural(wrapped);
+        assert_eq!(findings.len(), 1, "{findings:?}");
+        let f = &findings[0];
+        assert_eq!(f.rule_id, "text/as-an-ai-language-model");
+        assert_eq!((f.line, f.end_line), (1, Some(4)));
+        assert_eq!(f.severity, Severity::Critical);
+        assert!(super::super::apply_text_rules(wrapped).is_empty());
+
+        // On one line the text rule reports it, so this check stays quiet.
+        let single = "Intro line here.\nAs an AI language model, I cannot say more.\n";
+        assert!(structural(single).is_empty());
+        let text = super::super::apply_text_rules(single);
+        assert_eq!(text[0].rule_id, "text/as-an-ai-language-model");
+        assert_eq!(text[0].line, 2);
+
+        let disabled = HashSet::from(["text/as-an-ai-language-model".to_string()]);
+        assert!(
+            apply_structural_rules(wrapped, &StructuralLimits::default(), &disabled).is_empty()
+        );
+    }
+
+    /// An 85-word paragraph of plain prose, on one line, that trips no
+    /// structural rule. Put before a test document it clears every default
+    /// activation minimum and moves later lines down by two.
+    const LEAD: &str = "The build server compiles every branch overnight. \
+        Reviewers read the logs each morning, looking for flaky tests, slow steps, \
+        and warnings that crept in since the last release. Most days nothing stands out. \
+        When a job fails twice in a row, the on-call engineer opens a ticket, tags the \
+        owning team, and links the failing run so the history stays in one place. \
+        Caches are wiped weekly. Disk usage on the runners is graphed, and an alert \
+        fires when any of them passes ninety percent.";
+
+    /// `doc` after `LEAD` and a blank line.
+    fn led(doc: &str) -> String {
+        format!("{LEAD}\n\n{doc}")
+    }
+
+    #[test]
+    fn lead_paragraph_is_clean() {
+        assert_eq!(LEAD.split_whitespace().count(), 85);
+        assert!(structural(LEAD).is_empty());
+    }
+
+    #[test]
+    fn first_person_plural_threshold_boundary() {
+        let doc = "# Intro\n\nWe built this. We test it. The API is small. It is fast.\n";
+        // Two of four sentences: fires only when the limit is below one half.
+        assert_eq!(check_first_person_plural(doc, 0.49).len(), 1);
+        assert!(check_first_person_plural(doc, 0.5).is_empty());
+        let f = &check_first_person_plural(doc, 0.49)[0];
+        assert_eq!((f.line, f.col, f.matched.as_str()), (3, 0, "We"));
+        assert_eq!(f.severity, Severity::Medium);
+    }
+
+    #[test]
+    fn first_person_plural_counted_per_section() {
+        let doc = "# A\nWe ship. We test. We recommend it.\n\n# B\nThe tool runs.\nIt reads files. Our docs\ncover the rest.\n";
+        let findings = check_first_person_plural(doc, 0.5);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].line, 2);
+    }
+
+    #[test]
+    fn first_person_plural_off_by_default() {
+        let doc = "We ship. We test. We recommend it.\n";
+        assert!(structural(doc)
+            .iter()
+            .all(|f| !f.message.contains("First-person")));
+    }
+
+    #[test]
+    fn connector_density_fires() {
+        let para = "Moreover, this is important. Furthermore, we note that. Additionally, as a result, the data shows. Consequently, we conclude.";
+        let findings = structural(&led(para));
+        assert!(
+            findings
+                .iter()
+                .any(|f| f.message.contains("connector density")),
+            "high connector density should fire, got: {:?}",
+            findings.iter().map(|f| &f.message).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn connector_density_exactly_three_fires() {
+        let para =
+            "Moreover, this is the case. Furthermore, it matters. Additionally, we note this.";
+        let findings = structural(&led(para));
+        assert!(
+            findings
+                .iter()
+                .any(|f| f.message.contains("connector density")),
+            "exactly 3 connectors should fire at the >= 3 threshold"
+        );
+    }
+
+    #[test]
+    fn connector_density_low_count_no_fire() {
+        let para = "Moreover, this is important. Furthermore, this helps.";
+        let findings = structural(&led(para));
+        assert!(
+            !findings
+                .iter()
+                .any(|f| f.message.contains("connector density")),
+            "2 connectors should not fire"
+        );
+    }
+
+    #[test]
+    fn short_note_has_no_structural_findings() {
+        let note = "Moreover, the deploy is done. Furthermore, the cache is warm. \
+            Additionally, as a result, the alerts are quiet.\n\
+            \n\
+            In summary, the deploy is done and the cache is warm.\n";
+        assert!(structural(note).is_empty(), "{:?}", structural(note));
+        let notes = apply_structural_rules(note, &StructuralLimits::NONE, &HashSet::new());
+        assert!(notes.len() >= 2, "{notes:?}");
+    }
+
+    #[test]
+    fn connector_density_scales_with_paragraph_length() {
+        let dense = "Moreover, the cache is shared. Furthermore, it is fast. \
+            Additionally, it is small.";
+        assert_eq!(
+            structural(&led(dense))
+                .iter()
+                .filter(|f| f.message.contains("connector density"))
+                .count(),
+            1
+        );
+        // The same three connectors spread over a long paragraph.
+        let diluted = format!("{dense} {LEAD} Nothing else changed.");
+        assert!(!structural(&led(&diluted))
+            .iter()
+            .any(|f| f.message.contains("connector density")));
+        let lenient = StructuralLimits {
+            connectors_per_100_words: 2.0,
+            ..Default::default()
+        };
+        assert!(
+            apply_structural_rules(&led(&diluted), &lenient, &HashSet::new())
+                .iter()
+                .any(|f| f.message.contains("(3 in 101 words)"))
+        );
+    }
+
+    #[test]
+    fn uniformity_waits_for_enough_sentences() {
+        let uniform = "The parser reads each line from the file. The lexer splits every \
+            line into tokens. The checker walks the tokens in a loop. The printer writes \
+            all results to a file.";
+        let is_uniform = |f: &Finding| f.message.starts_with("Uniform sentence length");
+        assert!(!structural(uniform).iter().any(is_uniform));
+        assert!(structural(&led(uniform)).iter().any(is_uniform));
+    }
+
+    #[test]
+    fn restating_conclusion_removed_by_clean() {
+        let input = "The cache stores parsed templates in memory.\n\
+            Eviction runs when memory pressure rises.\n\
+            \n\
+            In summary, the cache stores parsed templates\n\
+            and eviction runs under memory pressure.\n";
+        let input = &led(input);
+        let findings = structural(input);
+        assert!(
+            findings
+                .iter()
+                .any(|f| f.message.contains("Restating conclusion")),
+            "restating conclusion should fire, got: {:?}",
+            findings.iter().map(|f| &f.message).collect::<Vec<_>>()
+        );
+        // One finding per paragraph line, none for the blank line above it.
+        let lines: Vec<usize> = findings
+            .iter()
+            .filter(|f| f.rule_id == "structural/restating-conclusion")
+            .map(|f| f.line)
+            .collect();
+        assert_eq!(lines, [6, 7]);
+        assert_eq!(
+            super::super::clean(input, &findings),
+            led("The cache stores parsed templates in memory.\n\
+                 Eviction runs when memory pressure rises.\n")
+        );
+    }
+
+    #[test]
+    fn conclusion_with_new_action_items_flagged_not_removed() {
+        let input = "The cache stores parsed templates in memory.\n\
+            \n\
+            In conclusion, schedule a migration window next Tuesday\n\
+            and notify the billing team before deploying.\n";
+        let input = &led(input);
+        let findings = structural(input);
+        let f = findings
+            .iter()
+            .find(|f| f.message.contains("Conclusion paragraph"))
+            .expect("conclusion opener should be flagged");
+        assert_eq!(f.line, 5);
+        assert_eq!(f.matched, "In conclusion");
+        assert!(f.replacement.is_none());
+        assert_eq!(super::super::clean(input, &findings), *input);
+    }
+
+    #[test]
+    fn single_paragraph_conclusion_ignored() {
+        let findings = structural("In summary, it works.\n");
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn duplicate_sentence_across_sections_reported_once() {
+        let input = "# Storage\n\
+            \n\
+            The engine provides a robust and scalable solution for storing events.\n\
+            \n\
+            # Querying\n\
+            \n\
+            Reads are served from replicas.\n\
+            The engine provides a robust and scalable solution for querying events.\n\
+            \n\
+            # Operations\n\
+            \n\
+            - The engine provides a robust and scalable solution for storing events.\n";
+        let findings = structural(&led(input));
+        let dups: Vec<_> = findings
+            .iter()
+            .filter(|f| f.message.contains("Near-duplicate"))
+            .collect();
+        assert_eq!(dups.len(), 1, "got: {:?}", dups);
+        assert_eq!(dups[0].line, 5);
+        assert!(
+            dups[0].message.contains("lines 5, 10, 14"),
+            "{}",
+            dups[0].message
+        );
+    }
+
+    #[test]
+    fn duplicates_on_one_line_name_it_once() {
+        let input =
+            "The engine provides a robust and scalable solution for storing events. ".repeat(3);
+        let findings = structural(&led(&input));
+        let dup = findings
+            .iter()
+            .find(|f| f.rule_id == "structural/duplicate-sentence")
+            .unwrap();
+        assert_eq!(
+            dup.message,
+            "Near-duplicate sentence repeated 3 times on line 3: say it once"
+        );
+    }
+
+    #[test]
+    fn heavily_repeated_sentence_grouped() {
+        let input = "We ship the release notes with every tagged build.\n".repeat(100);
+        let findings = structural(&input);
+        let dups: Vec<_> = findings
+            .iter()
+            .filter(|f| f.message.contains("Near-duplicate"))
+            .collect();
+        assert_eq!(dups.len(), 1);
+        assert!(dups[0].message.contains("100 times"), "{}", dups[0].message);
+    }
+
+    #[test]
+    fn unique_prose_has_no_duplicates() {
+        let input = "The parser reads tokens from the input stream one at a time.\n\
+            \n\
+            Each token is classified before the grammar rules are applied to it.\n\
+            \n\
+            Errors are collected and reported together at the end of the run.\n";
+        let findings = structural(input);
+        assert!(!findings
+            .iter()
+            .any(|f| f.message.contains("Near-duplicate")));
+    }
+
+    #[test]
+    fn duplicates_inside_code_blocks_ignored() {
+        let input = "```\n\
+            let handler = build the default request handler for this app;\n\
+            \n\
+            let handler = build the default request handler for this app;\n\
+            ```\n";
+        let findings = structural(input);
+        assert!(!findings
+            .iter()
+            .any(|f| f.message.contains("Near-duplicate")));
+    }
+
+    #[test]
+    fn tilde_fenced_code_is_not_prose() {
+        let fenced = |body: &str| format!("Intro.\n\n~~~text\n{body}~~~\n\nOutro.\n");
+        let lead_ins = fenced("- **A:** x\n- **B:** y\n- **C:** z\n");
+        assert!(structural(&lead_ins).is_empty(), "{lead_ins}");
+        let dups = fenced("Build the default request handler for this app.\n\nBuild the default request handler for this app.\n");
+        assert!(structural(&dups).is_empty(), "{dups}");
+        let we = fenced("We ship. We test. We recommend it.\n");
+        assert!(check_first_person_plural(&we, 0.0).is_empty());
+        let headings = fenced("# A\n# B\n# C\n");
+        assert!(check_headings(&headings, &StructuralLimits::NONE).is_empty());
+    }
+
+    fn stem_findings(input: &str) -> Vec<Finding> {
+        structural(input)
+            .into_iter()
+            .filter(|f| f.message.starts_with("Sentence stem"))
+            .collect()
+    }
+
+    #[test]
+    fn repeated_stem_reported_with_lines() {
+        let input = "This allows you to cache results.
+            This allows you to retry requests.
+            
+            The parser is small.
+            
+            This allows you to skip the build. This allows you to ship.
+            
+            This allows you to sleep at night.
+";
+        let findings = stem_findings(&led(input));
+        assert_eq!(findings.len(), 1);
+        let f = &findings[0];
+        assert_eq!((f.line, f.matched.as_str()), (3, "this allows you"));
+        assert_eq!(
+            f.message,
+            "Sentence stem repeated 5 times (lines 3, 4, 8, 10): vary how sentences open"
+        );
+        assert_eq!(f.severity, Severity::Medium);
+    }
+
+    #[test]
+    fn repeated_stem_lists_at_most_five_lines() {
+        let input: String = [
+            "cache", "retry", "skip", "ship", "sleep", "test", "log", "wait",
+        ]
+        .iter()
+        .map(|verb| format!("This allows you to {verb}.\n\n"))
+        .collect();
+        let findings = stem_findings(&led(&input));
+        assert_eq!(
+            findings[0].message,
+            "Sentence stem repeated 8 times (lines 3, 5, 7, 9, 11 and 3 more): vary how sentences open"
+        );
+    }
+
+    #[test]
+    fn headings_do_not_count_as_stems() {
+        let faq: String = [
+            ("Install", "Run the script."),
+            ("Configure", "Edit one file."),
+            ("Upgrade", "Pull the new tag."),
+            ("Remove", "Delete the folder."),
+        ]
+        .iter()
+        .map(|(topic, answer)| format!("## What Is The {topic} Step?\n\n{answer}\n\n"))
+        .collect();
+        assert!(stem_findings(&led(&faq)).is_empty());
+    }
+
+    #[test]
+    fn varied_or_stopword_stems_not_reported() {
+        let varied = "This allows caching. This ensures that retries work. \
+            This makes it easy to ship. This lets you skip the build.\n";
+        assert!(stem_findings(varied).is_empty());
+        let stopwords = "It is the first. It is the second. It is the third. It is the last.\n";
+        assert!(stem_findings(stopwords).is_empty());
+    }
+
+    #[test]
+    fn dense_headings_fire_once() {
+        let doc = "# Guide\nIt helps. It is fast.\n\n## Install\nRun the script.\n\n\
+            ## Configure\nEdit the file. Save it.\n\n## Run\nStart it. Stop it.\n\n\
+            ## Test\nCheck it. Watch it.\n\n## Ship\nTag it.\n";
+        // Short documents are allowed their headings.
+        assert!(check_headings(doc, &StructuralLimits::default()).is_empty());
+        let findings = check_headings(doc, &StructuralLimits::NONE);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Medium);
+        assert_eq!(
+            findings[0].message,
+            "6 headings over 10 sentences: fold short sections together"
+        );
+    }
+
+    #[test]
+    fn heading_ratio_waits_for_long_documents() {
+        let section = |i: usize| {
+            format!("## Part {i}\n\nIt does one thing. It does it well.\nAnd one more.\n\n")
+        };
+        let short: String = (1..=3).map(section).collect();
+        assert!(check_headings(&short, &StructuralLimits::default()).is_empty());
+
+        let long: String = (1..=7).map(section).collect();
+        let findings = check_headings(&long, &StructuralLimits::default());
+        assert_eq!(findings.len(), 1, "{findings:?}");
+        assert_eq!(findings[0].rule_id, "structural/heading-ratio");
+        assert_eq!(
+            findings[0].message,
+            "7 headings over 14 lines of prose: fold short sections together"
+        );
+
+        let lenient = StructuralLimits {
+            lines_per_heading: 2.0,
+            ..Default::default()
+        };
+        assert!(check_headings(&long, &lenient).is_empty());
+    }
+
+    #[test]
+    fn title_cased_question_headings_flagged_each() {
+        let doc = "# What Is The Architecture?\nOne. Two. Three.\n\n## What is Rust?\nOne. Two. Three.\n\n\
+            ## How Does It Work?\nOne. Two. Three.\n\n## Why?\nOne. Two. Three.\n";
+        let findings = check_headings(doc, &StructuralLimits::default());
+        let at: Vec<(usize, usize, Option<usize>, &str)> = findings
+            .iter()
+            .map(|f| (f.line, f.col, f.end_line, f.matched.as_str()))
+            .collect();
+        assert_eq!(
+            at,
+            [
+                (1, 2, None, "What Is The Architecture?"),
+                (7, 3, None, "How Does It Work?"),
+            ]
+        );
+        assert!(findings
+            .iter()
+            .all(|f| f.rule_id == "structural/question-headings" && f.severity == Severity::Low));
+    }
+
+    #[test]
+    fn skipped_level_and_long_heading_flagged() {
+        let long =
+            "Why this library is the best choice for every single team that ships software today";
+        let doc = format!(
+            "# Title\nOne. Two. Three.\n\n### Details\nOne. Two. Three.\n\n## {}\nOne. Two. Three.\n",
+            long
+        );
+        let findings = check_headings(&doc, &StructuralLimits::default());
+        assert_eq!(findings.len(), 2, "{:?}", findings);
+        assert_eq!((findings[0].line, findings[0].matched.as_str()), (4, "###"));
+        assert_eq!(findings[0].message, "Heading skips a level: H3 follows H1");
+        assert_eq!((findings[1].line, findings[1].col), (7, 3));
+        assert_eq!(findings[1].matched, long);
+        assert!(findings[1].message.contains("15 words"));
+    }
+
+    #[test]
+    fn structural_rules_empty_input() {
+        let findings = structural("");
+        assert!(findings.is_empty());
+    }
+}
@@ -0,0 +1,137 @@
+use super::{Category, Finding, Region, Severity};
+
+/// Flag trailing whitespace and runs of more than `max_blank_lines` blank
+/// lines, both with fixes that delete the excess. Fenced code blocks are left
+/// alone. With `hard_breaks`, a line ending in exactly two spaces is a
+/// Markdown line break and is not flagged. Whitespace-only lines count as
+/// blank lines; they are only removed as part of an over-long run.
+pub fn apply_whitespace_rules(
+    content: &str,
+    max_blank_lines: usize,
+    hard_breaks: bool,
+) -> Vec<Finding> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut findings = Vec::new();
+    let mut in_code_block = false;
+    let mut blank_run: Option<usize> = None;
+
+    for (idx, line) in lines.iter().enumerate() {
+        let is_fence = line.trim_start().starts_with("```");
+        if is_fence {
+            in_code_block = !in_code_block;
+        }
+        let is_blank = !is_fence && !in_code_block && line.trim().is_empty();
+        if is_blank {
+            blank_run.get_or_insert(idx);
+            continue;
+        }
+        if let Some(start) = blank_run.take() {
+            push_blank_run(&lines, start, idx, max_blank_lines, &mut findings);
+        }
+        if is_fence || in_code_block {
+            continue;
+        }
+
+        let body_len = line.trim_end().len();
+        let trailing = &line[body_len..];
+        if trailing.is_empty() || (hard_breaks && trailing == "  ") {
+            continue;
+        }
+        findings.push(Finding {
+            line: idx + 1,
+            col: body_len,
+            matched: trailing.to_string(),
+            message: "Trailing whitespace".to_string(),
+            rule_id: "whitespace/trailing".to_string(),
+            replacement: Some(String::new()),
+            severity: Severity::Low,
+            category: Category::Structural,
+            region: Some(Region::Prose),
+            ..Default::default()
+        });
+    }
+    if let Some(start) = blank_run {
+        push_blank_run(&lines, start, lines.len(), max_blank_lines, &mut findings);
+    }
+
+    findings
+}
+
+/// Report the blank lines `start..end` past the first `max` as one finding
+/// whose fix deletes them.
+fn push_blank_run(
+    lines: &[&str],
+    start: usize,
+    end: usize,
+    max: usize,
+    findings: &mut Vec<Finding>,
+) {
+    let first_extra = start + max;
+    if end <= first_extra {
+        return;
+    }
+    findings.push(Finding {
+        line: first_extra + 1,
+        col: 0,
+        matched: lines[first_extra..end].join("\n"),
+        message: format!("Run of {} blank lines: keep at most {}", end - start, max),
+        rule_id: "whitespace/blank-lines".to_string(),
+        replacement: Some(String::new()),
+        severity: Severity::Low,
+        category: Category::Structural,
+        region: Some(Region::Prose),
+        end_line: (end - first_extra > 1).then_some(end),
+        ..Default::default()
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::clean;
+    use super::*;
+
+    #[test]
+    fn trailing_whitespace_trimmed() {
+        let input = "One. \t\nTwo.\n";
+        let findings = apply_whitespace_rules(input, 2, true);
+        assert_eq!(findings.len(), 1);
+        assert_eq!((findings[0].line, findings[0].col), (1, 4));
+        assert_eq!(clean(input, &findings), "One.\nTwo.\n");
+    }
+
+    #[test]
+    fn markdown_hard_break_exempt() {
+        let input = "Line one  \nLine two   \n";
+        let findings = apply_whitespace_rules(input, 2, true);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].line, 2);
+        assert_eq!(apply_whitespace_rules(input, 2, false).len(), 2);
+    }
+
+    #[test]
+    fn blank_run_collapses_to_max() {
+        let input = "A\n\n\n\n\nB\n\nC\n";
+        let findings = apply_whitespace_rules(input, 2, true);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].line, 4);
+        assert_eq!(findings[0].end_line, Some(5));
+        assert_eq!(clean(input, &findings), "A\n\n\nB\n\nC\n");
+
+        let findings = apply_whitespace_rules(input, 1, true);
+        assert_eq!(clean(input, &findings), "A\n\nB\n\nC\n");
+    }
+
+    #[test]
+    fn whitespace_only_lines_count_as_blank() {
+        let input = "A\n  \n\n \nB\n";
+        let findings = apply_whitespace_rules(input, 2, true);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(clean(input, &findings), "A\n  \n\nB\n");
+    }
+
+    #[test]
+    fn fenced_code_untouched() {
+        let input = "```\nlet x = 1;   \n\n\n\n\n```\n";
+        assert!(apply_whitespace_rules(input, 2, true).is_empty());
+    }
+}
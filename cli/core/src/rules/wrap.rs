@@ -0,0 +1,188 @@
+use std::collections::HashSet;
+
+/// Re-wrap the paragraphs of `lines` that contain an edited line (indices in
+/// `edited`) to `width` columns. Each list item wraps on its own under its
+/// marker; fenced code, tables, headings, block quotes, HTML, and indented
+/// code are never touched, and neither is any paragraph without an edit.
+pub(super) fn rewrap_edited(
+    lines: &[String],
+    edited: &HashSet<usize>,
+    width: usize,
+) -> Vec<String> {
+    let mut out = Vec::with_capacity(lines.len());
+    let mut in_code_block = false;
+    let mut i = 0;
+    while i < lines.len() {
+        let line = &lines[i];
+        let is_fence = line.trim_start().starts_with("```");
+        if is_fence {
+            in_code_block = !in_code_block;
+        }
+        if is_fence || in_code_block || !starts_block(line) {
+            out.push(line.clone());
+            i += 1;
+            continue;
+        }
+        let start = i;
+        i += 1;
+        while i < lines.len() && continues_block(&lines[i]) && !ends_with_hard_break(&lines[i - 1])
+        {
+            i += 1;
+        }
+        if (start..i).any(|n| edited.contains(&n)) {
+            out.extend(wrap_block(&lines[start..i], width));
+        } else {
+            out.extend(lines[start..i].iter().cloned());
+        }
+    }
+    out
+}
+
+/// Whether `line` can open a wrappable block: prose or a list item, not
+/// indented far enough to be a code block.
+fn starts_block(line: &str) -> bool {
+    let indent = line.len() - line.trim_start().len();
+    is_prose(line) && (indent < 4 || list_marker(line.trim_start()).is_some())
+}
+
+/// Whether `line` continues the block above it. A new list item never joins
+/// the previous one.
+fn continues_block(line: &str) -> bool {
+    is_prose(line) && list_marker(line.trim_start()).is_none()
+}
+
+fn is_prose(line: &str) -> bool {
+    let trimmed = line.trim();
+    if trimmed.is_empty()
+        || trimmed
+            .chars()
+            .all(|c| matches!(c, '-' | '=' | '*' | '_' | ' '))
+    {
+        return false;
+    }
+    !["#", "|", ">", "<", "```"]
+        .iter()
+        .any(|p| trimmed.starts_with(p))
+}
+
+/// A trailing backslash or two trailing spaces force a line break in Markdown.
+fn ends_with_hard_break(line: &str) -> bool {
+    line.ends_with('\\') || line.ends_with("  ")
+}
+
+/// Byte length of a leading list marker and its following space (`- `, `* `,
+/// `+ `, `1. `, `2) `), if `trimmed` starts with one.
+fn list_marker(trimmed: &str) -> Option<usize> {
+    let digits = trimmed.bytes().take_while(u8::is_ascii_digit).count();
+    let marker = if digits > 0 {
+        match trimmed.as_bytes().get(digits) {
+            Some(b'.' | b')') => digits + 1,
+            _ => return None,
+        }
+    } else {
+        match trimmed.as_bytes().first() {
+            Some(b'-' | b'*' | b'+') => 1,
+            _ => return None,
+        }
+    };
+    trimmed[marker..].starts_with(' ').then_some(marker + 1)
+}
+
+/// Greedily fill the words of `block` into lines of at most `width` columns,
+/// keeping the first line's indentation and list marker and a trailing hard
+/// break. A word longer than `width` gets a line to itself.
+fn wrap_block(block: &[String], width: usize) -> Vec<String> {
+    let first = &block[0];
+    let body = first.trim_start();
+    let indent = &first[..first.len() - body.len()];
+    let marker_len = list_marker(body).unwrap_or(0);
+    let first_prefix = format!("{}{}", indent, &body[..marker_len]);
+    let cont_prefix = format!("{}{}", indent, " ".repeat(marker_len));
+
+    let words = std::iter::once(&body[marker_len..])
+        .chain(block[1..].iter().map(String::as_str))
+        .flat_map(str::split_whitespace);
+
+    let mut out = Vec::new();
+    let mut current = first_prefix;
+    let mut has_word = false;
+    for word in words {
+        if has_word && current.chars().count() + 1 + word.chars().count() > width {
+            out.push(std::mem::replace(&mut current, cont_prefix.clone()));
+            has_word = false;
+        }
+        if has_word {
+            current.push(' ');
+        }
+        current.push_str(word);
+        has_word = true;
+    }
+    if block[block.len() - 1].ends_with("  ") {
+        current.push_str("  ");
+    }
+    out.push(current);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{clean_with, Finding};
+
+    fn fix(line: usize, col: usize, matched: &str, replacement: &str) -> Finding {
+        Finding {
+            line,
+            col,
+            matched: matched.to_string(),
+            replacement: Some(replacement.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn edited_paragraph_rewrapped_others_untouched() {
+        let input = "The build failed due to the fact that the cache\n\
+                     was stale and nobody noticed it for a\n\
+                     week.\n\
+                     \n\
+                     This paragraph is wrapped\n\
+                     oddly but has no fix.\n";
+        let f = fix(1, 17, "due to the fact that", "because");
+        let cleaned = clean_with(input, &[f], Some(40));
+        assert_eq!(
+            cleaned,
+            "The build failed because the cache was\n\
+             stale and nobody noticed it for a week.\n\
+             \n\
+             This paragraph is wrapped\n\
+             oddly but has no fix.\n"
+        );
+        assert!(cleaned.lines().all(|l| l.chars().count() <= 40));
+    }
+
+    #[test]
+    fn list_items_wrap_under_their_marker() {
+        let input = "- first item due to the fact that it wraps\n- second item\n";
+        let f = fix(1, 13, "due to the fact that", "because");
+        assert_eq!(
+            clean_with(input, &[f], Some(18)),
+            "- first item\n  because it wraps\n- second item\n"
+        );
+    }
+
+    #[test]
+    fn code_blocks_and_tables_not_rewrapped() {
+        let input = "```\nlet x = utilize(1);\n```\n| utilize | a |\n";
+        let findings = [fix(2, 8, "utilize", "use"), fix(4, 2, "utilize", "use")];
+        assert_eq!(
+            clean_with(input, &findings, Some(5)),
+            "```\nlet x = use(1);\n```\n| use | a |\n"
+        );
+    }
+
+    #[test]
+    fn without_width_matches_clean() {
+        let input = "We utilize\nthis.\n";
+        let f = fix(1, 3, "utilize", "use");
+        assert_eq!(clean_with(input, &[f], None), "We use\nthis.\n");
+    }
+}
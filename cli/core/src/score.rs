@@ -0,0 +1,268 @@
+//! How machine-written each paragraph of a prose document reads, as a score
+//! from 0 to 100. Three signals feed it: the findings on the paragraph,
+//! weighted by severity; its density of discourse connectors; and how uniform
+//! its sentence lengths are. [`WEIGHTS`] holds every tunable.
+
+use serde::Serialize;
+
+use crate::rules::{
+    count_connectors, prose_paragraphs, split_paragraphs, split_sentences, Category, Finding,
+    Severity,
+};
+
+/// The tunables of [`score`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Weights {
+    /// Points a finding adds, by severity.
+    pub critical: f64,
+    pub high: f64,
+    pub medium: f64,
+    pub low: f64,
+    /// Finding points per 100 words at which the findings signal is full.
+    pub points_per_100_words: f64,
+    /// Connectors per sentence at which the connector signal is full.
+    pub connectors_per_sentence: f64,
+    /// Standard deviation of sentence lengths, in words, from which lengths
+    /// count as varied. Below it the uniformity signal grows, to full at 0.
+    pub varied_stddev: f64,
+    /// Fewest sentences, and fewest words in the average one, for sentence
+    /// lengths to say anything.
+    pub uniformity_min_sentences: usize,
+    pub uniformity_min_mean_words: f64,
+    /// Share of the score each signal can make up; the three sum to 1.
+    pub findings_share: f64,
+    pub connectors_share: f64,
+    pub uniformity_share: f64,
+}
+
+pub const WEIGHTS: Weights = Weights {
+    critical: 8.0,
+    high: 4.0,
+    medium: 2.0,
+    low: 1.0,
+    points_per_100_words: 12.0,
+    connectors_per_sentence: 0.5,
+    varied_stddev: 6.0,
+    uniformity_min_sentences: 3,
+    uniformity_min_mean_words: 5.0,
+    findings_share: 0.6,
+    connectors_share: 0.2,
+    uniformity_share: 0.2,
+};
+
+impl Weights {
+    fn points(&self, severity: Severity) -> f64 {
+        match severity {
+            Severity::Critical => self.critical,
+            Severity::High => self.high,
+            Severity::Medium => self.medium,
+            Severity::Low => self.low,
+        }
+    }
+}
+
+/// The scores of a document, as the JSON report's `scores`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Scores {
+    /// Mean of the paragraph scores, weighted by their word counts.
+    pub document: u8,
+    /// Every prose paragraph, in document order.
+    pub paragraphs: Vec<ParagraphScore>,
+}
+
+impl Scores {
+    /// The `n` paragraphs with the highest scores above 0, highest first;
+    /// ties keep document order.
+    pub fn top(&self, n: usize) -> Vec<&ParagraphScore> {
+        let mut ranked: Vec<&ParagraphScore> =
+            self.paragraphs.iter().filter(|p| p.score > 0).collect();
+        ranked.sort_by_key(|p| std::cmp::Reverse(p.score));
+        ranked.truncate(n);
+        ranked
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ParagraphScore {
+    /// First and last line of the paragraph, from 1.
+    pub line: usize,
+    pub end_line: usize,
+    pub score: u8,
+    pub words: usize,
+    /// Findings on the paragraph's lines. Structural findings are left out:
+    /// the connector and uniformity signals measure the same things.
+    pub findings: usize,
+    pub connectors: usize,
+    pub sentences: usize,
+    /// `None` with too few sentences to judge.
+    pub sentence_length_stddev: Option<f64>,
+    /// What raised the score, in words: "2 findings (1 critical, 1 high)".
+    pub factors: Vec<String>,
+}
+
+/// Score each prose paragraph of `content`, outside fenced code and Markdown
+/// headings, from the `findings` reported on it.
+pub fn score(content: &str, findings: &[Finding]) -> Scores {
+    let w = &WEIGHTS;
+    let mut paragraphs = Vec::new();
+    for (line, para) in prose_paragraphs(&split_paragraphs(content)) {
+        let words = para.split_whitespace().count();
+        let heading = para.lines().all(|l| l.trim_start().starts_with('#'));
+        if words == 0 || heading {
+            continue;
+        }
+        let end_line = line + para.lines().count().max(1) - 1;
+        let on_para: Vec<&Finding> = findings
+            .iter()
+            .filter(|f| !matches!(f.category, Category::Structural | Category::Meta))
+            .filter(|f| (line..=end_line).contains(&f.line))
+            .collect();
+        let points: f64 = on_para.iter().map(|f| w.points(f.severity)).sum();
+        let connectors = count_connectors(&para.to_lowercase());
+        let lengths: Vec<f64> = split_sentences(para)
+            .iter()
+            .map(|(_, s)| s.split_whitespace().count() as f64)
+            .collect();
+        let sentences = lengths.len();
+        let mean = lengths.iter().sum::<f64>() / sentences.max(1) as f64;
+        let stddev = (sentences >= w.uniformity_min_sentences
+            && mean > w.uniformity_min_mean_words)
+            .then(|| {
+                let variance =
+                    lengths.iter().map(|l| (l - mean).powi(2)).sum::<f64>() / sentences as f64;
+                (variance.sqrt() * 10.0).round() / 10.0
+            });
+
+        let findings_signal = (points * 100.0 / words as f64 / w.points_per_100_words).min(1.0);
+        let connector_signal =
+            (connectors as f64 / sentences.max(1) as f64 / w.connectors_per_sentence).min(1.0);
+        let uniformity_signal = stddev.map_or(0.0, |s| (1.0 - s / w.varied_stddev).max(0.0));
+        let score = 100.0
+            * (w.findings_share * findings_signal
+                + w.connectors_share * connector_signal
+                + w.uniformity_share * uniformity_signal);
+
+        let mut factors = Vec::new();
+        if !on_para.is_empty() {
+            let by_severity: Vec<String> = [
+                Severity::Critical,
+                Severity::High,
+                Severity::Medium,
+                Severity::Low,
+            ]
+            .into_iter()
+            .map(|sev| (sev, on_para.iter().filter(|f| f.severity == sev).count()))
+            .filter(|&(_, n)| n > 0)
+            .map(|(sev, n)| format!("{n} {}", sev.label()))
+            .collect();
+            factors.push(format!(
+                "{} finding(s) ({})",
+                on_para.len(),
+                by_severity.join(", ")
+            ));
+        }
+        if connectors > 0 {
+            factors.push(format!(
+                "{connectors} connector(s) in {sentences} sentence(s)"
+            ));
+        }
+        if let Some(s) = stddev.filter(|_| uniformity_signal > 0.0) {
+            factors.push(format!("uniform sentence length (stddev {s:.1} words)"));
+        }
+
+        paragraphs.push(ParagraphScore {
+            line,
+            end_line,
+            score: score.round() as u8,
+            words,
+            findings: on_para.len(),
+            connectors,
+            sentences,
+            sentence_length_stddev: stddev,
+            factors,
+        });
+    }
+
+    let total_words: usize = paragraphs.iter().map(|p| p.words).sum();
+    let weighted: usize = paragraphs
+        .iter()
+        .map(|p| usize::from(p.score) * p.words)
+        .sum();
+    let document = match total_words {
+        0 => 0,
+        _ => (weighted as f64 / total_words as f64).round() as u8,
+    };
+    Scores {
+        document,
+        paragraphs,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::apply_text_rules;
+
+    const LLM: &str = "Certainly! Moreover, we leverage a robust and comprehensive \
+        framework. Furthermore, it is a testament to our meticulous design process. \
+        Additionally, it seamlessly streamlines the whole developer workflow.";
+    const HUMAN: &str = "I rewrote the parser last week. It was slow. The old version \
+        re-read every file twice because nobody remembered why the cache existed, so I \
+        deleted it and nothing broke.";
+
+    fn scores(content: &str) -> Scores {
+        score(content, &apply_text_rules(content))
+    }
+
+    #[test]
+    fn llm_paragraph_scores_above_plain_prose() {
+        let content = format!("{HUMAN}\n\n{LLM}\n");
+        let s = scores(&content);
+        assert_eq!(s.paragraphs.len(), 2);
+        let (human, llm) = (&s.paragraphs[0], &s.paragraphs[1]);
+        assert!(llm.score > human.score, "{llm:?} vs {human:?}");
+        assert!(llm.score >= 50, "{llm:?}");
+        assert!(s.document > human.score && s.document < llm.score, "{s:?}");
+        assert_eq!((llm.line, llm.end_line), (3, 3));
+    }
+
+    #[test]
+    fn factors_name_the_signals() {
+        let s = scores(LLM);
+        let factors = &s.paragraphs[0].factors;
+        assert!(factors[0].contains("critical"), "{factors:?}");
+        assert!(
+            factors.iter().any(|f| f.starts_with("3 connector(s)")),
+            "{factors:?}"
+        );
+    }
+
+    #[test]
+    fn top_ranks_highest_first_and_skips_zero() {
+        let content = format!("{HUMAN}\n\n{LLM}\n\nPlain words here.\n");
+        let s = scores(&content);
+        let top: Vec<usize> = s.top(5).iter().map(|p| p.line).collect();
+        assert_eq!(top[0], 3);
+        assert!(!top.contains(&5), "{s:?}");
+        assert_eq!(s.top(1).len(), 1);
+    }
+
+    #[test]
+    fn fenced_code_is_not_scored() {
+        let content = format!("```\n{LLM}\n```\n");
+        assert_eq!(
+            scores(&content),
+            Scores {
+                document: 0,
+                paragraphs: Vec::new()
+            }
+        );
+    }
+
+    #[test]
+    fn signal_shares_sum_to_one() {
+        let w = WEIGHTS;
+        let sum = w.findings_share + w.connectors_share + w.uniformity_share;
+        assert!((sum - 1.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,65 @@
+//! Allocation counts for the line pre-pass, measured with a counting global
+//! allocator. Kept in its own test binary so the allocator sees only this.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::collections::HashSet;
+
+use unai_core::rules::{apply_text_rules_to, LineContext};
+
+struct Counting;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|n| n.set(n.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// Allocations made on this thread while `f` runs.
+fn allocations<T>(f: impl FnOnce() -> T) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    drop(f());
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+fn document(lines: usize) -> String {
+    "The parser reads each `token` and builds the tree for the next stage.\n".repeat(lines)
+}
+
+/// Each line's fold, offset table and backtick spans: three allocations.
+#[test]
+fn line_contexts_allocate_three_times_per_line() {
+    let (short, long) = (document(1000), document(2000));
+    let extra =
+        allocations(|| LineContext::split(&long)) - allocations(|| LineContext::split(&short));
+    assert!(
+        (3000..3000 + 16).contains(&extra),
+        "{extra} for 1000 more lines"
+    );
+}
+
+/// The text pass reads the shared fold instead of lowercasing each line
+/// again, so lines without findings cost it no allocations.
+#[test]
+fn text_pass_does_not_fold_lines_again() {
+    let disabled = HashSet::new();
+    let (short, long) = (document(1000), document(2000));
+    let (short, long) = (LineContext::split(&short), LineContext::split(&long));
+    // Builds the rule tables once.
+    apply_text_rules_to(&short, &disabled);
+    let extra = allocations(|| apply_text_rules_to(&long, &disabled))
+        .saturating_sub(allocations(|| apply_text_rules_to(&short, &disabled)));
+    assert!(extra < 100, "{extra} for 1000 more lines");
+}
@@ -0,0 +1,53 @@
+//! Feeds arbitrary documents and findings to `clean()` and checks the
+//! properties in `core/src/rules/invariants.rs`. Run with
+//! `cargo +nightly fuzz run clean` from `cli/`.
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+
+use unai_core::rules::invariants::{check_clean, check_converges, RawFix};
+
+#[derive(Debug, Arbitrary)]
+struct Input {
+    content: String,
+    fixes: Vec<Fix>,
+}
+
+#[derive(Debug, Arbitrary)]
+struct Fix {
+    line: usize,
+    start: usize,
+    len: usize,
+    extra_lines: u8,
+    replacement: Option<String>,
+    severity: u8,
+    recapitalize_next: bool,
+    stale: bool,
+}
+
+fuzz_target!(|input: Input| {
+    let findings: Vec<_> = input
+        .fixes
+        .into_iter()
+        .filter_map(|f| {
+            RawFix {
+                line: f.line,
+                start: f.start,
+                len: f.len,
+                extra_lines: usize::from(f.extra_lines % 4),
+                replacement: f.replacement,
+                severity: f.severity,
+                recapitalize_next: f.recapitalize_next,
+                stale: f.stale,
+            }
+            .to_finding(&input.content)
+        })
+        .collect();
+    if let Err(e) = check_clean(&input.content, &findings) {
+        panic!("{e}\nfindings: {findings:#?}");
+    }
+    if let Err(e) = check_converges(&input.content) {
+        panic!("{e}");
+    }
+});
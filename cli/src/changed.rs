@@ -0,0 +1,76 @@
+//! Lines a file adds or changes relative to a git ref, for `--changed-only`.
+
+use std::collections::HashSet;
+use std::path::Path;
+use std::process::Command;
+
+/// 1-based lines of `path` that `git diff <base>` shows as added or modified,
+/// in the working tree against `base`. An error says why the lines are
+/// unknown: git is missing, the file is not tracked, or the diff failed.
+pub fn changed_lines(path: &str, base: &str) -> Result<HashSet<usize>, String> {
+    let path = Path::new(path);
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    let name = path
+        .file_name()
+        .ok_or_else(|| format!("'{}' is not a file", path.display()))?;
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(args)
+            .arg("--")
+            .arg(name)
+            .output()
+            .map_err(|e| format!("cannot run git: {e}"))
+    };
+    if !git(&["ls-files", "--error-unmatch"])?.status.success() {
+        return Err(format!("'{}' is not tracked by git", path.display()));
+    }
+    let diff = git(&["diff", "--no-color", "--no-ext-diff", "-U0", base])?;
+    if !diff.status.success() {
+        return Err(format!(
+            "git diff against '{base}' failed: {}",
+            String::from_utf8_lossy(&diff.stderr).trim()
+        ));
+    }
+    Ok(hunk_lines(&String::from_utf8_lossy(&diff.stdout)))
+}
+
+/// New-side lines covered by the hunk headers of a `-U0` unified diff. A
+/// header `@@ -a,b +c,d @@` covers lines `c..c+d`, with `d` 1 when omitted;
+/// a pure deletion has `d` 0 and covers nothing.
+fn hunk_lines(diff: &str) -> HashSet<usize> {
+    let mut lines = HashSet::new();
+    for header in diff.lines().filter_map(|l| l.strip_prefix("@@ ")) {
+        let Some(new) = header.split_whitespace().find_map(|r| r.strip_prefix('+')) else {
+            continue;
+        };
+        let (start, count) = match new.split_once(',') {
+            Some((start, count)) => (start.parse::<usize>(), count.parse()),
+            None => (new.parse(), Ok(1)),
+        };
+        if let (Ok(start), Ok(count)) = (start, count) {
+            lines.extend(start..start + count);
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hunk_headers_give_added_and_modified_lines() {
+        let diff = "diff --git a/f b/f\n--- a/f\n+++ b/f\n\
+                    @@ -2 +2 @@ a\n-b\n+B\n\
+                    @@ -3,0 +4,2 @@ c\n+d\n+e\n\
+                    @@ -9,2 +10,0 @@\n-x\n-y\n";
+        let mut lines: Vec<usize> = hunk_lines(diff).into_iter().collect();
+        lines.sort();
+        assert_eq!(lines, [2, 4, 5]);
+    }
+}
@@ -0,0 +1,71 @@
+//! `--format compact`: one `file:line:col: severity: rule-id: message` line
+//! per finding, the shape compiler diagnostics take, so vim's quickfix list,
+//! Emacs compilation-mode, and CI log scanners can read it.
+
+use unai_core::rules::Finding;
+
+/// One line per finding of `content`, read from `file`, ordered by line then
+/// column. Columns count characters from 1; findings about the whole input
+/// carry no line or column.
+pub fn lines(file: &str, content: &str, findings: &[Finding]) -> String {
+    let text_lines: Vec<&str> = content.lines().collect();
+    let mut located: Vec<(usize, usize, &Finding)> = findings
+        .iter()
+        .map(|f| {
+            let col = text_lines
+                .get(f.line.wrapping_sub(1))
+                .and_then(|l| l.get(..f.col))
+                .map_or(f.col, |before| before.chars().count())
+                + 1;
+            (f.line, col, f)
+        })
+        .collect();
+    located.sort_by_key(|&(line, col, _)| (line, col));
+    let mut out = String::new();
+    for (line, col, f) in located {
+        let location = match line {
+            0 => file.to_string(),
+            _ => format!("{file}:{line}:{col}"),
+        };
+        let rule = match f.rule_id.is_empty() {
+            true => String::new(),
+            false => format!(" {}:", f.rule_id),
+        };
+        let message = f.message.replace(['\r', '\n'], " ");
+        out.push_str(&format!(
+            "{location}: {}:{rule} {message}\n",
+            f.severity.label()
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unai_core::rules::{apply_text_rules, Severity};
+
+    #[test]
+    fn findings_are_ordered_by_line_then_column() {
+        let content = "Café, we utilize it.\nCertainly! We delve.\n";
+        assert_eq!(
+            lines("README.md", content, &apply_text_rules(content)),
+            "README.md:1:10: high: text/utilize: LLM filler: 'utilize'\n\
+             README.md:2:1: critical: text/certainly: Sycophantic opener: 'Certainly!'\n\
+             README.md:2:15: critical: text/delve: LLM tell: 'delve'\n"
+        );
+    }
+
+    #[test]
+    fn whole_input_findings_have_no_location() {
+        let finding = Finding {
+            message: "two\nlines".to_string(),
+            severity: Severity::Low,
+            ..Default::default()
+        };
+        assert_eq!(
+            lines("<stdin>", "", &[finding]),
+            "<stdin>: low: two lines\n"
+        );
+    }
+}
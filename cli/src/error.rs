@@ -22,19 +22,24 @@ pub enum UnaiError {
     #[error("'{path}' exceeds 64 MiB size limit")]
     FileTooLarge { path: PathBuf },
 
-    #[error("Cannot parse config at '{path}': {source}")]
-    ConfigParse {
-        path: PathBuf,
-        #[source]
-        source: Box<toml::de::Error>,
-    },
-
-    #[error("Invalid config: {0}")]
-    ConfigInvalid(String),
+    /// Reading or validating the config.
+    #[error(transparent)]
+    Core(#[from] unai_core::Error),
 
     #[error("Invalid rule: {0}")]
     InvalidRule(String),
 
+    #[error("{0}")]
+    InvalidArgs(String),
+
+    /// The reader of stdout went away; not reported, and unai exits 0.
+    #[error("stdout was closed")]
+    StdoutClosed,
+
+    /// Inputs that ran past `--timeout`, under `--strict-files`.
+    #[error("{0} file(s) took longer than --timeout")]
+    TimedOut(usize),
+
     #[error("Cannot write output to '{path}': {source}")]
     FileWrite {
         path: PathBuf,
@@ -47,13 +52,25 @@ pub type Result<T> = std::result::Result<T, UnaiError>;
 
 /// Exit codes for unai.
 /// 0  = success (no findings, or findings auto-fixed)
-/// 1  = I/O error
-/// 2  = config / rule parse error
-/// 10 = findings exist (used with --fail)
+/// 1  = I/O error, or a file ran past --timeout under --strict-files
+/// 2  = config / rule parse error, or invalid arguments
+/// 10 = findings exist (used with --fail); under --exit-by-severity, the
+///      worst failing finding is Low
+/// 11 = --exit-by-severity: the worst failing finding is Medium
+/// 12 = --exit-by-severity: High
+/// 13 = --exit-by-severity: Critical
 pub mod exit_code {
+    use unai_core::Severity;
+
     #[allow(dead_code)]
     pub const SUCCESS: i32 = 0;
     pub const IO_ERROR: i32 = 1;
     pub const CONFIG_ERROR: i32 = 2;
     pub const FINDINGS: i32 = 10;
+
+    /// The `--exit-by-severity` code for a run whose worst failing finding
+    /// has `severity`.
+    pub fn for_severity(severity: Severity) -> i32 {
+        FINDINGS + i32::from(severity.rank())
+    }
 }
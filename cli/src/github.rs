@@ -0,0 +1,99 @@
+//! GitHub Actions workflow commands, which the runner turns into inline
+//! annotations on the pull request.
+
+use unai_core::rules::{Finding, Severity};
+
+/// Workflow command for a severity.
+fn command(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical | Severity::High => "error",
+        Severity::Medium => "warning",
+        Severity::Low => "notice",
+    }
+}
+
+/// `text` escaped for the message of a workflow command.
+fn escape_data(text: &str) -> String {
+    text.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// `text` escaped for a property value, where `:` and `,` end the value.
+fn escape_property(text: &str) -> String {
+    escape_data(text).replace(':', "%3A").replace(',', "%2C")
+}
+
+/// One `::error`, `::warning`, or `::notice` line per finding of `content`,
+/// read from `path` (`None` for stdin, whose annotations have no file).
+/// Columns count characters from 1; findings about the whole input carry no
+/// line.
+pub fn annotations(path: Option<&str>, content: &str, findings: &[Finding]) -> String {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut out = String::new();
+    for f in findings {
+        let mut properties = Vec::new();
+        if let Some(path) = path {
+            properties.push(format!("file={}", escape_property(path)));
+        }
+        if f.line > 0 {
+            let col = lines
+                .get(f.line - 1)
+                .and_then(|l| l.get(..f.col))
+                .map_or(f.col, |before| before.chars().count())
+                + 1;
+            properties.push(format!("line={}", f.line));
+            if f.last_line() > f.line {
+                properties.push(format!("endLine={}", f.last_line()));
+            }
+            properties.push(format!("col={col}"));
+        }
+        if !f.rule_id.is_empty() {
+            properties.push(format!("title={}", escape_property(&f.rule_id)));
+        }
+        let properties = match properties.is_empty() {
+            true => String::new(),
+            false => format!(" {}", properties.join(",")),
+        };
+        out.push_str(&format!(
+            "::{}{}::{}\n",
+            command(f.severity),
+            properties,
+            escape_data(&f.message)
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unai_core::rules::{apply_text_rules, Category};
+
+    #[test]
+    fn findings_become_workflow_commands() {
+        let content = "Café, we delve.\n";
+        assert_eq!(
+            annotations(Some("docs/draft.md"), content, &apply_text_rules(content)),
+            "::error file=docs/draft.md,line=1,col=10,title=text/delve::LLM tell: 'delve'\n"
+        );
+    }
+
+    #[test]
+    fn messages_and_properties_are_escaped() {
+        let user = Finding {
+            message: "100% sure,\r\nreally".to_string(),
+            category: Category::User,
+            severity: Severity::Medium,
+            ..Default::default()
+        };
+        assert_eq!(
+            annotations(Some("a,b:c.md"), "", std::slice::from_ref(&user)),
+            "::warning file=a%2Cb%3Ac.md::100%25 sure,%0D%0Areally\n"
+        );
+        assert_eq!(
+            annotations(None, "", &[user]),
+            "::warning::100%25 sure,%0D%0Areally\n"
+        );
+    }
+}
@@ -1,28 +1,40 @@
-mod config;
-mod detector;
-mod diff;
+mod changed;
+mod compact;
 mod error;
-mod rules;
+mod github;
+mod sarif;
+mod scan;
+mod watch;
 
+use std::collections::{BTreeMap, HashSet};
 use std::fs;
-use std::io::{self, IsTerminal, Read};
+use std::io::{self, IsTerminal, Read, Write};
 use std::path::Path;
 use std::process;
+use std::time::{Duration, Instant};
 
+use anstream::AutoStream;
 use anstyle::{AnsiColor, Style};
-use clap::{Parser, ValueEnum};
+use clap::{Parser, Subcommand, ValueEnum};
 
-use detector::{detect_mode, is_commit_msg_file, Mode};
+use config::{FinalNewline, LineEndings};
+use detector::{detect_mode, DetectionReason, Mode};
 use error::{exit_code, Result, UnaiError};
 use rules::{
-    apply_code_rules, apply_structural_rules, apply_text_rules, apply_user_rules, clean,
-    collect_ignored_lines, CodeRule, Finding, Severity,
+    allowed_spans, clean, clean_checked, clean_with, registry, rule_for, superseded_fixes,
+    unused_config_entry, unused_directives, Category, CodeRule, Finding, FixSafety, RuleDoc,
+    Severity,
+};
+use unai_core::report::{truncated, JsonFinding};
+use unai_core::{
+    config, detector, diff, effective_code_rules, inline, pass_runs, rules, score, Analysis,
+    Engine, SuppressCause,
 };
 
 /// Maximum bytes accepted from stdin. Inputs larger than this are rejected.
 const MAX_STDIN_BYTES: usize = 64 * 1024 * 1024; // 64 MiB
 
-#[derive(Parser, Debug)]
+#[derive(Parser, Debug, Clone)]
 #[command(
     name = "unai",
     version,
@@ -30,14 +42,31 @@ const MAX_STDIN_BYTES: usize = 64 * 1024 * 1024; // 64 MiB
     long_about = None
 )]
 struct Args {
-    /// Input file. Reads from stdin if omitted.
-    #[arg(value_name = "FILE")]
-    file: Option<String>,
+    /// Input files or directories; directories are scanned recursively.
+    /// Reads from stdin if omitted.
+    #[arg(value_name = "PATH")]
+    files: Vec<String>,
+
+    /// Scan only files in a directory whose path matches this glob.
+    /// Repeatable.
+    #[arg(long, value_name = "GLOB")]
+    include: Vec<String>,
+
+    /// Skip files in a directory whose path matches this glob. Repeatable;
+    /// wins over --include.
+    #[arg(long, value_name = "GLOB")]
+    exclude: Vec<String>,
 
     /// Processing mode. Defaults to automatic detection.
     #[arg(long, value_enum, default_value = "auto")]
     mode: ModeArg,
 
+    /// File name for content read from stdin: the mode is detected and the
+    /// config's commit_globs matched as if stdin came from this file, and
+    /// JSON reports it as the file. For editors that pipe a buffer.
+    #[arg(long, value_name = "NAME", conflicts_with = "files")]
+    stdin_filename: Option<String>,
+
     /// Code rules to apply (comma-separated). Applies all when omitted.
     /// Values: comments, naming, commits, docstrings, tests, errors, api
     #[arg(long, value_delimiter = ',')]
@@ -55,13 +84,70 @@ struct Args {
     #[arg(long)]
     annotate: bool,
 
+    /// Check a leading YAML or TOML frontmatter block too; by default text and
+    /// Markdown mode skip it.
+    #[arg(long)]
+    scan_frontmatter: bool,
+
+    /// In code mode, also run the text rules on string literal contents.
+    /// Their fixes are reported as suggestions, not applied.
+    #[arg(long)]
+    check_strings: bool,
+
+    /// With --check-strings, apply fixes inside string literals too. This
+    /// changes program behavior.
+    #[arg(long)]
+    fix_strings: bool,
+
+    /// Apply only replacements that keep the meaning intact; report the rest
+    /// as suggestions.
+    #[arg(long)]
+    safe_fixes: bool,
+
+    /// Re-wrap paragraphs changed by a fix to this many columns.
+    #[arg(long, value_name = "WIDTH", value_parser = clap::value_parser!(u16).range(1..))]
+    rewrap: Option<u16>,
+
     /// Print a summary of patterns found, grouped by severity.
     #[arg(long)]
     report: bool,
 
-    /// Only show findings at or above this severity level.
-    #[arg(long, value_enum, default_value = "low")]
-    min_severity: MinSeverityArg,
+    /// Print nothing but errors: no text, report, or diff, so only the exit
+    /// code tells the result. An explicit --format json|sarif|github or
+    /// --output file is still written.
+    #[arg(long, conflicts_with = "summary")]
+    quiet: bool,
+
+    /// Instead of the text, print only the finding count and the count per
+    /// severity, to stdout or --output.
+    #[arg(long, conflicts_with_all = ["diff", "dry_run", "annotate"])]
+    summary: bool,
+
+    /// Layout of --report: aligned columns, or the one-line-per-finding
+    /// format grouped under severity headings, for scripts that grep it.
+    #[arg(long, value_enum, default_value = "aligned")]
+    report_style: ReportStyleArg,
+
+    /// How --report lists findings: each under its severity, or one line per
+    /// rule with its count and the first lines it fired on.
+    #[arg(long, value_enum, default_value = "severity")]
+    group_by: GroupByArg,
+
+    /// Under each finding in --report, explain why the pattern reads as
+    /// machine-written and show a rewrite, from the rule's documentation.
+    /// Wrapped to $COLUMNS, or 80 columns when unset.
+    #[arg(long, requires = "report")]
+    explain_findings: bool,
+
+    /// How many paragraphs --report ranks by how machine-written they read,
+    /// in text and Markdown; 0 leaves the ranking out.
+    #[arg(long, value_name = "N", default_value_t = 3)]
+    top_paragraphs: usize,
+
+    /// Only show findings at or above this severity level. Defaults to a
+    /// file's inline `min-severity` setting, then low.
+    #[arg(long, value_enum)]
+    min_severity: Option<MinSeverityArg>,
 
     /// Output format.
     #[arg(long, value_enum, default_value = "text")]
@@ -71,30 +157,157 @@ struct Args {
     #[arg(long, value_name = "FILE")]
     output: Option<String>,
 
+    /// Keep running, and check the PATHs again each time a file under them is
+    /// saved: the report covers the changed files, or every file when the
+    /// config changes. With a --format other than text each run reports every
+    /// file, so --output always holds the whole picture. Stop with Ctrl-C.
+    #[arg(long, requires = "files", conflicts_with_all = ["annotate", "changed_only"])]
+    watch: bool,
+
+    /// With --watch, write the cleaned text back to each checked file.
+    #[arg(long, requires = "watch")]
+    write: bool,
+
     /// Path to config file. Defaults to ./unai.toml if present.
     #[arg(long, value_name = "FILE")]
     config: Option<String>,
 
-    /// Exit with code 10 if any findings exist at or above --min-severity.
+    /// List findings hidden by ignore directives, ignore.words, or --min-severity.
+    #[arg(long)]
+    show_suppressed: bool,
+
+    /// Append the research citation to each finding's message in text output.
+    #[arg(long)]
+    verbose: bool,
+
+    /// Exit with code 10 if any findings exist at or above --min-severity, or
+    /// at the per-category thresholds in the config's [fail] section.
     #[arg(long)]
     fail: bool,
 
+    /// Severity at which --fail trips, independent of what --min-severity
+    /// shows: `--min-severity low --fail-on critical` lists everything but
+    /// fails only on Critical. Defaults to the --min-severity level.
+    #[arg(long, value_enum, value_name = "SEVERITY")]
+    fail_on: Option<MinSeverityArg>,
+
+    /// Like --fail, but the exit code tells the worst failing finding's
+    /// severity: 10 for Low, 11 Medium, 12 High, 13 Critical.
+    #[arg(long)]
+    exit_by_severity: bool,
+
+    /// Report ignore directives, ignore.words entries, and allow phrases that
+    /// hid no finding, as Low findings. --verbose turns this on too.
+    #[arg(long)]
+    lint_config: bool,
+
+    /// Exit with code 10 if any suppression hid no finding. Implies
+    /// --lint-config.
+    #[arg(long)]
+    fail_on_unused_suppressions: bool,
+
     /// Colorize output. Auto-detects TTY when set to 'auto'.
     #[arg(long, value_enum, default_value = "auto")]
     color: ColorArg,
+
+    /// Make rule ids and line references in the report clickable (OSC 8
+    /// hyperlinks). 'auto' enables them on terminals known to support them.
+    /// Never emitted with --color never.
+    #[arg(long, value_enum, default_value = "auto")]
+    hyperlinks: HyperlinksArg,
+
+    /// Report only findings on lines added or changed since the --diff-base
+    /// git ref, HEAD by default. Files git does not track are checked in
+    /// full, with a warning.
+    #[arg(long)]
+    changed_only: bool,
+
+    /// Git ref that --changed-only compares against; implies --changed-only.
+    #[arg(long, value_name = "REF")]
+    diff_base: Option<String>,
+
+    /// Give up on a file whose analysis takes longer than this many seconds
+    /// (fractions allowed). The file is reported as timed out, with no
+    /// findings, and the run moves on to the next one.
+    #[arg(long, value_name = "SECONDS", value_parser = parse_timeout)]
+    timeout: Option<Duration>,
+
+    /// Exit with code 1 if any file hit --timeout.
+    #[arg(long)]
+    strict_files: bool,
+
+    /// Print the default configuration, with every setting commented, and
+    /// exit.
+    #[arg(long)]
+    print_default_config: bool,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+enum Command {
+    /// Inspect the built-in rules.
+    Rules {
+        #[command(subcommand)]
+        command: RulesCommand,
+    },
+    /// Manage the unai.toml config file.
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+}
+
+#[derive(Subcommand, Debug, Clone)]
+enum ConfigCommand {
+    /// Write the default configuration to ./unai.toml.
+    Init {
+        /// Overwrite an existing unai.toml.
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+#[derive(Subcommand, Debug, Clone)]
+enum RulesCommand {
+    /// Print documentation for every built-in rule: id, severity, tags,
+    /// examples, fix behavior, and source.
+    Doc {
+        /// Documentation format.
+        #[arg(long, value_enum, default_value = "markdown")]
+        format: DocFormatArg,
+    },
+}
+
+#[derive(ValueEnum, Debug, Clone, PartialEq)]
+enum DocFormatArg {
+    Json,
+    Markdown,
 }
 
 #[derive(ValueEnum, Debug, Clone, PartialEq)]
 enum ModeArg {
     Auto,
     Text,
+    /// Text rules on the prose, code rules inside fenced code blocks.
+    Markdown,
     Code,
+    /// Commit message rules, as for COMMIT_EDITMSG.
+    Commit,
 }
 
 #[derive(ValueEnum, Debug, Clone, PartialEq)]
 enum FormatArg {
     Text,
     Json,
+    /// SARIF 2.1.0, for code scanning.
+    Sarif,
+    /// GitHub Actions workflow commands, shown as pull request annotations.
+    Github,
+    /// One `file:line:col: severity: rule-id: message` line per finding, as
+    /// compilers print them, for editors and CI log scanners.
+    Compact,
 }
 
 #[derive(ValueEnum, Debug, Clone, PartialEq)]
@@ -104,6 +317,25 @@ enum ColorArg {
     Never,
 }
 
+#[derive(ValueEnum, Debug, Clone, PartialEq)]
+enum ReportStyleArg {
+    Aligned,
+    Plain,
+}
+
+#[derive(ValueEnum, Debug, Clone, PartialEq)]
+enum GroupByArg {
+    Severity,
+    Rule,
+}
+
+#[derive(ValueEnum, Debug, Clone, PartialEq)]
+enum HyperlinksArg {
+    Auto,
+    Always,
+    Never,
+}
+
 #[derive(ValueEnum, Debug, Clone, PartialEq)]
 enum MinSeverityArg {
     Critical,
@@ -112,6 +344,25 @@ enum MinSeverityArg {
     Low,
 }
 
+impl Args {
+    /// `--min-severity`, or `fallback` when it is not given.
+    fn min_severity_or(&self, fallback: Severity) -> Severity {
+        self.min_severity
+            .as_ref()
+            .map_or(fallback, MinSeverityArg::as_severity)
+    }
+
+    /// Whether findings can fail the run: `--fail` or `--exit-by-severity`.
+    fn failing_run(&self) -> bool {
+        self.fail || self.exit_by_severity
+    }
+
+    /// The explicit `--fail-on` level, if given.
+    fn fail_on(&self) -> Option<Severity> {
+        self.fail_on.as_ref().map(MinSeverityArg::as_severity)
+    }
+}
+
 impl MinSeverityArg {
     fn as_severity(&self) -> Severity {
         match self {
@@ -123,58 +374,256 @@ impl MinSeverityArg {
     }
 }
 
+/// A finding removed by filtering, kept so suppressions can be audited.
+#[derive(Debug)]
+struct Suppressed {
+    cause: SuppressCause,
+    finding: Finding,
+}
+
+fn count_suppressed(suppressed: &[Suppressed], cause: SuppressCause) -> usize {
+    suppressed.iter().filter(|s| s.cause == cause).count()
+}
+
+/// Append the research citation to each finding's message, for `--verbose`.
+fn with_citations(findings: &mut [Finding], suppressed: &mut [Suppressed]) {
+    let all = findings
+        .iter_mut()
+        .chain(suppressed.iter_mut().map(|s| &mut s.finding));
+    for f in all {
+        f.message = f.verbose_message();
+    }
+}
+
 /// Output of the findings pipeline, passed to `render()`.
 #[derive(Debug)]
 struct PipelineResult {
     findings: Vec<Finding>,
+    suppressed: Vec<Suppressed>,
     mode: Mode,
     content: String,
+    /// Input path as given on the command line; `None` for stdin.
+    path: Option<String>,
     filename: Option<String>,
+    /// Findings, shown or hidden by `--min-severity`, at or above the fail
+    /// threshold; counted with or without `--fail`.
+    failing: usize,
+    /// The worst severity among the findings that make the run exit
+    /// non-zero: `--fail` per the `[fail]` thresholds, or Low for
+    /// `--fail-on-unused-suppressions` with a suppression left unused. `None`
+    /// when the run passes.
+    fails: Option<Severity>,
+    /// `[output]` normalization for the cleaned text.
+    output: config::OutputConfig,
+    /// `[input]` limits; `--annotate` shortens lines past `max_line_bytes`.
+    input: config::InputConfig,
+    run: RunInfo,
+    /// Analysis ran past `--timeout` and was abandoned; `findings` is empty.
+    timed_out: bool,
+    /// The input started with a UTF-8 byte order mark. `content` has it
+    /// stripped, so columns count from the first character after it; the
+    /// written text gets it back.
+    bom: bool,
+}
+
+/// How the run was set up, reported as the JSON `run` object.
+#[derive(Debug)]
+struct RunInfo {
+    /// Config file that was loaded, if any.
+    config_path: Option<String>,
+    detection: DetectionReason,
+    /// Built-in rules whose pass ran for this mode and config.
+    enabled_rules: usize,
+    total_rules: usize,
+    started: Instant,
 }
 
 #[derive(serde::Serialize)]
 struct JsonReport {
     version: &'static str,
+    /// The mode every input was read in, or "mixed" for a scan whose files
+    /// differ.
     mode: String,
     file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    run: Option<JsonRun>,
+    /// Each file a multi-file scan read; absent for a single input.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    files: Option<Vec<JsonFile>>,
     findings: Vec<JsonFinding>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suppressed: Option<Vec<JsonSuppressedFinding>>,
     summary: JsonSummary,
+    /// Finding counts per rule id, whatever `--group-by` says.
+    by_rule: BTreeMap<String, JsonRuleGroup>,
+    /// How machine-written each paragraph of a single prose input reads.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scores: Option<score::Scores>,
+    /// A single input whose analysis ran past `--timeout`.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    timeout: bool,
 }
 
 #[derive(serde::Serialize)]
-struct JsonFinding {
-    line: usize,
-    column: usize,
-    end_column: usize,
-    matched: String,
-    message: String,
-    severity: Severity,
-    replacement: Option<String>,
-    source: String,
+struct JsonRun {
+    config: Option<String>,
+    /// No presets exist yet, so this is always null.
+    preset: Option<String>,
+    /// Per input; in a multi-file scan it is under each of `files` instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rules: Option<JsonRuleCounts>,
+    min_severity: Severity,
+    /// Severity at which `--fail` exits non-zero; null without `--fail`.
+    fail_threshold: Option<Severity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    detection: Option<JsonDetection>,
+    elapsed_ms: f64,
+}
+
+/// A file read by a multi-file scan.
+#[derive(serde::Serialize)]
+struct JsonFile {
+    path: String,
+    detection: JsonDetection,
+    rules: JsonRuleCounts,
+    findings: usize,
+    /// The file's document score, for a prose file; see `scores`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    score: Option<u8>,
+    /// Analysis ran past `--timeout`; the file has no findings.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    timeout: bool,
 }
 
 #[derive(serde::Serialize)]
+struct JsonRuleCounts {
+    enabled: usize,
+    disabled: usize,
+}
+
+#[derive(serde::Serialize)]
+struct JsonDetection {
+    mode: &'static str,
+    reason: String,
+}
+
+#[derive(serde::Serialize)]
+struct JsonSuppressedFinding {
+    #[serde(flatten)]
+    finding: JsonFinding,
+    cause: &'static str,
+}
+
+#[derive(serde::Serialize, Default)]
 struct JsonSummary {
     total: usize,
     critical: usize,
     high: usize,
     medium: usize,
     low: usize,
+    /// Findings that trip `--fail`: at or above `--fail-on`, else shown
+    /// after `--min-severity`, unless the config's `[fail]` section sets the
+    /// category's level. Counted whether or not `--fail` is given.
+    failing: usize,
+    suppressed: JsonSuppressedSummary,
+    /// Fixes `clean()` would skip because their offsets do not fit the input
+    /// or they overlap a fix applied instead.
+    skipped_fixes: usize,
 }
 
-fn build_json_report(findings: &[Finding], mode: &Mode, filename: Option<&str>) -> JsonReport {
+#[derive(serde::Serialize)]
+struct JsonRuleGroup {
+    severity: Severity,
+    count: usize,
+    /// Lines the rule fired on; left out of a multi-file scan, where the
+    /// findings tell the file.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    lines: Vec<usize>,
+}
+
+#[derive(serde::Serialize, Default)]
+struct JsonSuppressedSummary {
+    total: usize,
+    ignore_word: usize,
+    allow_phrase: usize,
+    ignore_directive: usize,
+    min_severity: usize,
+    unchanged_line: usize,
+}
+
+impl JsonSummary {
+    fn add(&mut self, other: &JsonSummary) {
+        self.total += other.total;
+        self.critical += other.critical;
+        self.high += other.high;
+        self.medium += other.medium;
+        self.low += other.low;
+        self.failing += other.failing;
+        self.suppressed.total += other.suppressed.total;
+        self.suppressed.ignore_word += other.suppressed.ignore_word;
+        self.suppressed.allow_phrase += other.suppressed.allow_phrase;
+        self.suppressed.ignore_directive += other.suppressed.ignore_directive;
+        self.suppressed.min_severity += other.suppressed.min_severity;
+        self.suppressed.unchanged_line += other.suppressed.unchanged_line;
+        self.skipped_fixes += other.skipped_fixes;
+    }
+}
+
+/// The `run` object of a single-input report.
+fn json_run(run: &RunInfo, mode: &Mode, args: &Args) -> JsonRun {
+    JsonRun {
+        rules: Some(json_rule_counts(run)),
+        detection: Some(json_detection(run, mode)),
+        ..json_scan_run(run.config_path.clone(), run.started, args)
+    }
+}
+
+/// The `run` object of a multi-file scan, where rules and detection vary by
+/// file and are reported under `files`.
+fn json_scan_run(config: Option<String>, started: Instant, args: &Args) -> JsonRun {
+    let elapsed_us = started.elapsed().as_micros() as f64;
+    JsonRun {
+        config,
+        preset: None,
+        rules: None,
+        min_severity: args.min_severity_or(Severity::Low),
+        fail_threshold: args.failing_run().then(|| {
+            args.fail_on()
+                .unwrap_or(args.min_severity_or(Severity::Low))
+        }),
+        detection: None,
+        elapsed_ms: elapsed_us / 1000.0,
+    }
+}
+
+fn json_rule_counts(run: &RunInfo) -> JsonRuleCounts {
+    JsonRuleCounts {
+        enabled: run.enabled_rules,
+        disabled: run.total_rules - run.enabled_rules,
+    }
+}
+
+fn json_detection(run: &RunInfo, mode: &Mode) -> JsonDetection {
+    JsonDetection {
+        mode: mode.label(),
+        reason: run.detection.to_string(),
+    }
+}
+
+/// Build the JSON report. Suppressed findings are always counted in the summary
+/// and listed in full only when `show_suppressed` is set.
+fn build_json_report(
+    findings: &[Finding],
+    suppressed: &[Suppressed],
+    show_suppressed: bool,
+    skipped_fixes: usize,
+    mode: &Mode,
+    filename: Option<&str>,
+    max_matched: usize,
+) -> JsonReport {
     let json_findings: Vec<JsonFinding> = findings
         .iter()
-        .map(|f| JsonFinding {
-            line: f.line,
-            column: f.col,
-            end_column: f.col + f.matched.len(),
-            matched: f.matched.clone(),
-            message: f.message.clone(),
-            severity: f.severity,
-            replacement: f.replacement.clone(),
-            source: mode_label(mode).to_string(),
-        })
+        .map(|f| JsonFinding::new(f, mode, max_matched))
         .collect();
 
     let summary = JsonSummary {
@@ -183,27 +632,222 @@ fn build_json_report(findings: &[Finding], mode: &Mode, filename: Option<&str>)
         high: count_by_severity(findings, Severity::High),
         medium: count_by_severity(findings, Severity::Medium),
         low: count_by_severity(findings, Severity::Low),
+        failing: 0,
+        suppressed: JsonSuppressedSummary {
+            total: suppressed.len(),
+            ignore_word: count_suppressed(suppressed, SuppressCause::IgnoreWord),
+            allow_phrase: count_suppressed(suppressed, SuppressCause::AllowPhrase),
+            ignore_directive: count_suppressed(suppressed, SuppressCause::IgnoreDirective),
+            min_severity: count_suppressed(suppressed, SuppressCause::MinSeverity),
+            unchanged_line: count_suppressed(suppressed, SuppressCause::UnchangedLine),
+        },
+        skipped_fixes,
     };
 
+    let suppressed = show_suppressed.then(|| {
+        suppressed
+            .iter()
+            .map(|s| JsonSuppressedFinding {
+                finding: JsonFinding::new(&s.finding, mode, max_matched),
+                cause: s.cause.label(),
+            })
+            .collect()
+    });
+
     JsonReport {
         version: env!("CARGO_PKG_VERSION"),
-        mode: mode_label(mode).to_string(),
+        mode: mode.label().to_string(),
         file: filename.map(|s| s.to_string()),
+        run: None,
+        files: None,
         findings: json_findings,
+        suppressed,
         summary,
+        by_rule: group_by_rule(findings)
+            .into_iter()
+            .map(|g| {
+                let group = JsonRuleGroup {
+                    severity: g.severity,
+                    count: g.findings.len(),
+                    lines: g.lines(),
+                };
+                (g.rule_id.to_string(), group)
+            })
+            .collect(),
+        scores: None,
+        timeout: false,
     }
 }
 
-fn count_by_severity(findings: &[Finding], sev: Severity) -> usize {
-    findings.iter().filter(|f| f.severity == sev).count()
+/// The JSON report for one input, with skipped and superseded fixes marked;
+/// `run` is left for the caller.
+fn result_json_report(result: &PipelineResult, args: &Args) -> JsonReport {
+    let fixes = fixes_to_apply(&result.findings, args.safe_fixes);
+    let (_, skipped) = clean_checked(&result.content, &fixes, None);
+    let mut report = build_json_report(
+        &result.findings,
+        &result.suppressed,
+        args.show_suppressed,
+        skipped.len(),
+        &result.mode,
+        result.filename.as_deref(),
+        result.output.max_matched_len,
+    );
+    for i in superseded_in(&result.content, &result.findings, args.safe_fixes) {
+        report.findings[i].superseded = true;
+    }
+    report.summary.failing = result.failing;
+    report.scores = (is_prose(&result.mode) && !result.timed_out)
+        .then(|| score::score(&result.content, &result.findings));
+    report.timeout = result.timed_out;
+    report
 }
 
-fn mode_label(mode: &Mode) -> &'static str {
-    match mode {
-        Mode::Text => "text",
-        Mode::Code => "code",
-        Mode::CommitMsg => "commit",
+/// One JSON report for a multi-file scan: every file's findings tagged with
+/// its path, the files under `files`, and the summary counts summed.
+fn scan_json_report(
+    results: &[PipelineResult],
+    config: Option<String>,
+    started: Instant,
+    args: &Args,
+) -> JsonReport {
+    let modes: HashSet<&str> = results.iter().map(|r| r.mode.label()).collect();
+    let mut report = JsonReport {
+        version: env!("CARGO_PKG_VERSION"),
+        mode: match modes.iter().collect::<Vec<_>>()[..] {
+            [mode] => mode.to_string(),
+            _ => "mixed".to_string(),
+        },
+        file: None,
+        run: Some(json_scan_run(config, started, args)),
+        files: Some(Vec::new()),
+        findings: Vec::new(),
+        suppressed: args.show_suppressed.then(Vec::new),
+        summary: JsonSummary::default(),
+        by_rule: BTreeMap::new(),
+        scores: None,
+        timeout: false,
+    };
+    for result in results {
+        let file = result_json_report(result, args);
+        report.summary.add(&file.summary);
+        for (id, group) in file.by_rule {
+            let merged = report.by_rule.entry(id).or_insert(JsonRuleGroup {
+                severity: group.severity,
+                count: 0,
+                lines: Vec::new(),
+            });
+            merged.severity = merged.severity.max(group.severity);
+            merged.count += group.count;
+        }
+        let tag = |mut f: JsonFinding| {
+            f.file = result.path.clone();
+            f
+        };
+        report.findings.extend(file.findings.into_iter().map(tag));
+        if let (Some(all), Some(suppressed)) = (report.suppressed.as_mut(), file.suppressed) {
+            all.extend(suppressed.into_iter().map(|s| JsonSuppressedFinding {
+                finding: tag(s.finding),
+                cause: s.cause,
+            }));
+        }
+        report.files.get_or_insert_with(Vec::new).push(JsonFile {
+            path: result.path.clone().unwrap_or_default(),
+            detection: json_detection(&result.run, &result.mode),
+            rules: json_rule_counts(&result.run),
+            findings: result.findings.len(),
+            score: file.scores.as_ref().map(|s| s.document),
+            timeout: result.timed_out,
+        });
     }
+    report
+}
+
+/// Whether `clean()` should apply this finding's replacement. Under
+/// `--safe-fixes`, Caution replacements are withheld and shown as suggestions.
+fn is_applied(f: &Finding, safe_only: bool) -> bool {
+    f.replacement.is_some() && !(safe_only && f.fix_safety == FixSafety::Caution)
+}
+
+/// Indices into `findings` of fixes that `clean()` leaves out because a
+/// conflicting fix on the same line wins; see [`superseded_fixes`].
+fn superseded_in(content: &str, findings: &[Finding], safe_only: bool) -> HashSet<usize> {
+    let applied: Vec<usize> = (0..findings.len())
+        .filter(|&i| is_applied(&findings[i], safe_only))
+        .collect();
+    let fixes: Vec<Finding> = applied.iter().map(|&i| findings[i].clone()).collect();
+    superseded_fixes(content, &fixes)
+        .into_iter()
+        .map(|j| applied[j])
+        .collect()
+}
+
+fn fixes_to_apply(findings: &[Finding], safe_only: bool) -> Vec<Finding> {
+    findings
+        .iter()
+        .filter(|f| is_applied(f, safe_only))
+        .cloned()
+        .collect()
+}
+
+/// The cleaned text: applied fixes, then `--rewrap` of the edited paragraphs,
+/// then the `[output]` normalization.
+fn apply_fixes(
+    content: &str,
+    findings: &[Finding],
+    safe_only: bool,
+    rewrap: Option<usize>,
+    output: &config::OutputConfig,
+) -> String {
+    let fixes = fixes_to_apply(findings, safe_only);
+    let cleaned = match rewrap {
+        Some(width) => clean_with(content, &fixes, Some(width)),
+        None => clean(content, &fixes),
+    };
+    normalize_output(content, &cleaned, output)
+}
+
+/// Apply the `[output]` final-newline and line-ending policy to `text`, the
+/// output produced from `original`. With both set to `preserve`, LF input
+/// passes through untouched and CRLF input keeps CRLF.
+fn normalize_output(original: &str, text: &str, policy: &config::OutputConfig) -> String {
+    // `clean()` keeps each line's own terminator, so preserve leaves them
+    // alone; a newline it has to add follows the input's first line.
+    let first_crlf = original
+        .find('\n')
+        .is_some_and(|i| original[..i].ends_with('\r'));
+    let mut out = match policy.line_endings {
+        LineEndings::Preserve => text.to_string(),
+        LineEndings::Lf | LineEndings::Crlf => text.replace("\r\n", "\n"),
+    };
+    let newline = match policy.line_endings {
+        LineEndings::Preserve if first_crlf => "\r\n",
+        _ => "\n",
+    };
+    match policy.final_newline {
+        FinalNewline::Preserve => {}
+        FinalNewline::Always => {
+            if !out.is_empty() && !out.ends_with('\n') {
+                out.push_str(newline);
+            }
+        }
+        FinalNewline::Never => {
+            if out.ends_with('\n') {
+                out.pop();
+                if out.ends_with('\r') {
+                    out.pop();
+                }
+            }
+        }
+    }
+    if policy.line_endings == LineEndings::Crlf {
+        out = out.replace('\n', "\r\n");
+    }
+    out
+}
+
+fn count_by_severity(findings: &[Finding], sev: Severity) -> usize {
+    findings.iter().filter(|f| f.severity == sev).count()
 }
 
 fn write_output(content: &str, output_path: Option<&str>) -> Result<()> {
@@ -221,35 +865,78 @@ fn write_output(content: &str, output_path: Option<&str>) -> Result<()> {
                     });
                 }
             }
-            fs::write(path, content).map_err(|source| UnaiError::FileWrite {
+            write_atomic(Path::new(path), content).map_err(|source| UnaiError::FileWrite {
                 path: path.into(),
                 source,
-            })?;
-            Ok(())
+            })
         }
-        None => {
-            print!("{}", content);
-            Ok(())
+        None => write_stdout(content),
+    }
+}
+
+/// Write `content` to a temporary file next to `path`, then rename it over
+/// `path`, so a reader never sees a half-written file. An existing file's
+/// permissions are kept.
+fn write_atomic(path: &Path, content: &str) -> io::Result<()> {
+    let name = path
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "not a file path"))?;
+    let mut temp_name = std::ffi::OsString::from(".");
+    temp_name.push(name);
+    temp_name.push(format!(".unai-{}.tmp", process::id()));
+    let temp = path.with_file_name(temp_name);
+    let written = fs::write(&temp, content)
+        .and_then(|()| match fs::metadata(path) {
+            Ok(meta) => fs::set_permissions(&temp, meta.permissions()),
+            Err(_) => Ok(()),
+        })
+        .and_then(|()| fs::rename(&temp, path));
+    if written.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+    written
+}
+
+/// Write `text` to stdout. A reader that stopped reading, like `head`, is not
+/// an error for a filter: the write fails with `StdoutClosed`, and unai
+/// exits 0 without writing more.
+fn write_stdout(text: &str) -> Result<()> {
+    let mut out = io::stdout().lock();
+    out.write_all(text.as_bytes())
+        .and_then(|()| out.flush())
+        .map_err(stdout_error)
+}
+
+fn stdout_error(source: io::Error) -> UnaiError {
+    if source.kind() == io::ErrorKind::BrokenPipe {
+        UnaiError::StdoutClosed
+    } else {
+        UnaiError::FileWrite {
+            path: "<stdout>".into(),
+            source,
         }
     }
 }
 
 fn main() {
     let args = Args::parse();
-    let fail = args.fail;
 
+    let by_severity = args.exit_by_severity;
     match run(args) {
-        Ok(had_findings) => {
-            if fail && had_findings {
-                process::exit(exit_code::FINDINGS);
-            }
-        }
+        Ok(Some(worst)) => process::exit(match by_severity {
+            true => exit_code::for_severity(worst),
+            false => exit_code::FINDINGS,
+        }),
+        Ok(None) => {}
+        Err(UnaiError::StdoutClosed) => {}
         Err(e) => {
             eprintln!("unai: {e}");
             let code = match &e {
-                UnaiError::ConfigParse { .. }
-                | UnaiError::ConfigInvalid(_)
-                | UnaiError::InvalidRule(_) => exit_code::CONFIG_ERROR,
+                UnaiError::Core(
+                    unai_core::Error::ConfigParse { .. } | unai_core::Error::ConfigInvalid(_),
+                )
+                | UnaiError::InvalidRule(_)
+                | UnaiError::InvalidArgs(_) => exit_code::CONFIG_ERROR,
                 _ => exit_code::IO_ERROR,
             };
             process::exit(code);
@@ -260,50 +947,323 @@ fn main() {
 /// Orchestrates the findings pipeline: read input, detect mode, gather and filter findings.
 /// Returns structured data; performs no output.
 fn pipeline(args: &Args) -> Result<PipelineResult> {
-    let cfg = match &args.config {
-        Some(path) => Some(config::Config::load(std::path::Path::new(path))?),
-        None => config::Config::load_from_cwd()?,
+    let started = Instant::now();
+    let (cfg, config_path) = load_config(args)?;
+    let path = args.files.first().map(String::as_str);
+    let (content, filename, bom) = read_input(path)?;
+    let filename = filename.or_else(|| args.stdin_filename.clone());
+    let mut result = analyze(
+        args,
+        cfg.as_ref(),
+        config_path,
+        path,
+        content,
+        filename,
+        started,
+    )?;
+    result.bom = bom;
+    Ok(result)
+}
+
+/// The config from `--config`, or `./unai.toml` if present, with the path it
+/// was read from. Prints the config's warnings.
+fn load_config(args: &Args) -> Result<(Option<config::Config>, Option<String>)> {
+    let (cfg, config_path) = match &args.config {
+        Some(path) => (
+            Some(config::Config::load(std::path::Path::new(path))?),
+            Some(path.clone()),
+        ),
+        None => {
+            let cfg = config::Config::load_from_cwd()?;
+            let path = cfg.is_some().then(|| "unai.toml".to_string());
+            (cfg, path)
+        }
     };
 
-    let (content, filename) = read_input(&args.file)?;
+    for warning in cfg.iter().flat_map(|c| c.warnings()) {
+        eprintln!("unai: warning: {warning}");
+    }
+    Ok((cfg, config_path))
+}
 
-    let mode = resolve_mode(&args.mode, filename.as_deref(), &content);
-    let code_rules = parse_code_rules(&args.rules)?;
+/// The mode to read `content` in, and why: `--mode`, the file's inline
+/// `mode`, a `[detector] commit_globs` match on `path` (`filename` for
+/// stdin), or detection from `filename` and `content`.
+fn input_mode(
+    args: &Args,
+    cfg: Option<&config::Config>,
+    path: Option<&str>,
+    filename: Option<&str>,
+    content: &str,
+    inline: Option<&Mode>,
+) -> (Mode, DetectionReason) {
+    match (&args.mode, path.or(filename), inline) {
+        (ModeArg::Auto, _, Some(mode)) => (mode.clone(), DetectionReason::Inline),
+        (ModeArg::Auto, Some(path), _) if cfg.is_some_and(|c| c.detector.is_commit_file(path)) => {
+            (Mode::CommitMsg, DetectionReason::ConfigPattern)
+        }
+        _ => resolve_mode(&args.mode, filename, content),
+    }
+}
 
-    let mut all_findings = gather_findings(&content, &mode, &code_rules, filename.as_deref());
-    all_findings.extend(apply_user_rules(&content, cfg.as_ref()));
+/// Findings for one input: `content`, read from `path` (the argument as given,
+/// `None` for stdin) whose file name is `filename`. Past `--timeout` the
+/// rule passes stop, and the input is returned with no findings.
+fn analyze(
+    args: &Args,
+    cfg: Option<&config::Config>,
+    config_path: Option<String>,
+    path: Option<&str>,
+    content: String,
+    filename: Option<String>,
+    started: Instant,
+) -> Result<PipelineResult> {
+    let inline = inline::inline_settings(&content);
+    for warning in &inline.warnings {
+        eprintln!("unai: warning: {}: {warning}", path.unwrap_or("<stdin>"));
+    }
+    let (mode, detection) = input_mode(
+        args,
+        cfg,
+        path,
+        filename.as_deref(),
+        &content,
+        inline.mode.as_ref(),
+    );
+    let code_rules = parse_code_rules(&args.rules)?;
+    let effective = effective_code_rules(&code_rules, filename.as_deref());
+    // The flags switch on what the config can; neither turns the other off.
+    let mut engine_cfg = cfg.cloned().unwrap_or_default();
+    engine_cfg.input.scan_frontmatter |= args.scan_frontmatter;
+    engine_cfg.code.check_strings |= args.check_strings;
+    engine_cfg.code.fix_strings |= args.fix_strings;
+    let mut engine = Engine::new(engine_cfg).with_code_rules(code_rules);
+    if let Some(timeout) = args.timeout {
+        engine = engine.with_deadline(Instant::now() + timeout);
+    }
+    let cfg = engine.config();
+    let Analysis {
+        findings: all_findings,
+        disabled,
+        timed_out,
+    } = engine.analysis(&content, &mode, filename.as_deref());
+    if timed_out {
+        eprintln!(
+            "unai: warning: '{}': analysis took longer than --timeout {}s; no findings reported",
+            path.unwrap_or("<stdin>"),
+            args.timeout.unwrap_or_default().as_secs_f64()
+        );
+        return Ok(PipelineResult {
+            findings: Vec::new(),
+            suppressed: Vec::new(),
+            mode,
+            content,
+            path: path.map(str::to_string),
+            filename,
+            failing: 0,
+            fails: None,
+            output: cfg.output,
+            input: cfg.input,
+            run: RunInfo {
+                config_path,
+                detection,
+                enabled_rules: 0,
+                total_rules: registry().len(),
+                started,
+            },
+            timed_out: true,
+            bom: false,
+        });
+    }
 
-    let ignored_words: std::collections::HashSet<String> = cfg
-        .as_ref()
-        .map(|c| c.ignore.words.iter().map(|w| w.to_lowercase()).collect())
-        .unwrap_or_default();
+    let first_person = cfg.structural.first_person_plural_max.is_some();
+    let lint_config = args.lint_config || args.fail_on_unused_suppressions || args.verbose;
+    let rules = registry();
+    let enabled_rules = rules
+        .iter()
+        .filter(|r| pass_runs(&r.pass, &mode, &effective, first_person, lint_config))
+        .filter(|r| !disabled.contains(&r.id))
+        .count();
+
+    let changed = changed_lines(args, path);
+    let min_rank = args
+        .min_severity_or(inline.min_severity.unwrap_or(Severity::Low))
+        .rank();
+    let mut findings = Vec::new();
+    let mut suppressed = Vec::new();
+    for (f, cause) in all_findings {
+        let cause = cause.or_else(|| {
+            if changed
+                .as_ref()
+                .is_some_and(|lines| !(f.line..=f.last_line()).any(|l| lines.contains(&l)))
+            {
+                Some(SuppressCause::UnchangedLine)
+            } else if f.severity.rank() < min_rank {
+                Some(SuppressCause::MinSeverity)
+            } else {
+                None
+            }
+        });
+        match cause {
+            Some(cause) => suppressed.push(Suppressed { cause, finding: f }),
+            None => findings.push(f),
+        }
+    }
 
-    let ignored_lines = collect_ignored_lines(&content);
-    let min_rank = args.min_severity.as_severity().rank();
-    let findings: Vec<Finding> = all_findings
-        .into_iter()
-        .filter(|f| !ignored_words.contains(&f.matched.to_lowercase()))
-        .filter(|f| !ignored_lines.contains(&f.line))
-        .filter(|f| f.severity.rank() >= min_rank)
+    let (fail_cfg, fail_on) = (Some(&cfg.fail), args.fail_on());
+    let failing: Vec<Severity> = findings
+        .iter()
+        .filter(|f| trips_fail(f, true, fail_cfg, fail_on))
+        .chain(
+            suppressed
+                .iter()
+                .filter(|s| s.cause == SuppressCause::MinSeverity)
+                .map(|s| &s.finding)
+                .filter(|f| trips_fail(f, false, fail_cfg, fail_on)),
+        )
+        .map(|f| f.severity)
         .collect();
+    let mut fails = failing.iter().max().copied().filter(|_| args.failing_run());
+    if lint_config && !disabled.contains("meta/unused-suppression") {
+        let allow_phrases: Vec<&str> = cfg.allow.iter().map(|a| a.phrase.as_str()).collect();
+        let unused = unused_suppressions(&content, &cfg.ignore, &allow_phrases, &suppressed);
+        if args.fail_on_unused_suppressions && !unused.is_empty() {
+            fails = fails.max(Some(Severity::Low));
+        }
+        findings.extend(unused);
+    }
 
     Ok(PipelineResult {
         findings,
+        suppressed,
         mode,
         content,
+        path: path.map(str::to_string),
         filename,
+        failing: failing.len(),
+        fails,
+        output: cfg.output,
+        input: cfg.input,
+        run: RunInfo {
+            config_path,
+            detection,
+            enabled_rules,
+            total_rules: rules.len(),
+            started,
+        },
+        timed_out: false,
+        bom: false,
     })
 }
 
+/// Lines of `path` changed since the `--diff-base` ref, under
+/// `--changed-only`; `None` when every line is checked. When the lines cannot
+/// be found, such as for stdin or an untracked file, a warning says so and
+/// every line is checked.
+fn changed_lines(args: &Args, path: Option<&str>) -> Option<HashSet<usize>> {
+    let base = args
+        .diff_base
+        .as_deref()
+        .or(args.changed_only.then_some("HEAD"))?;
+    let Some(path) = path else {
+        eprintln!("unai: warning: stdin has no git history; checking every line");
+        return None;
+    };
+    changed::changed_lines(path, base)
+        .inspect_err(|e| eprintln!("unai: warning: {e}; checking every line of '{path}'"))
+        .ok()
+}
+
+/// Ignore directives, `ignore.words` entries, and allow phrases that hid none
+/// of the `suppressed` findings, as Low meta findings. A finding counts for the
+/// suppression that actually removed it, so a directive whose lines were all
+/// covered by an ignored word is reported too.
+fn unused_suppressions(
+    content: &str,
+    ignore: &config::IgnoreConfig,
+    allow_phrases: &[&str],
+    suppressed: &[Suppressed],
+) -> Vec<Finding> {
+    let hidden_by = |cause| {
+        suppressed
+            .iter()
+            .filter(move |s| s.cause == cause)
+            .map(|s| &s.finding)
+    };
+    let mut unused = unused_directives(content, hidden_by(SuppressCause::IgnoreDirective));
+
+    let used_words: HashSet<String> = hidden_by(SuppressCause::IgnoreWord)
+        .map(|f| ignore.word_key(&f.matched))
+        .collect();
+    unused.extend(
+        ignore
+            .words
+            .iter()
+            .filter(|w| !used_words.contains(&ignore.word_key(w)))
+            .map(|w| unused_config_entry("ignore.words", w)),
+    );
+
+    for &phrase in allow_phrases {
+        let spans = allowed_spans(content, &[phrase]);
+        let used = hidden_by(SuppressCause::AllowPhrase).any(|f| {
+            spans.get(&f.line).is_some_and(|spans| {
+                spans
+                    .iter()
+                    .any(|&(start, end)| start <= f.col && f.end_col() <= end)
+            })
+        });
+        if !used {
+            unused.push(unused_config_entry("allow", phrase));
+        }
+    }
+    unused
+}
+
+/// Whether a finding makes `--fail` exit non-zero. A `[fail]` threshold for its
+/// category decides on its own, independent of `--min-severity`; without one,
+/// a finding at or above `fail_on` fails, or with no `--fail-on`, any finding
+/// still `shown` after filtering. Meta findings fail only
+/// through `--fail-on-unused-suppressions`.
+fn trips_fail(
+    f: &Finding,
+    shown: bool,
+    fail: Option<&config::FailConfig>,
+    fail_on: Option<Severity>,
+) -> bool {
+    if f.category == Category::Meta {
+        return false;
+    }
+    let threshold = fail.and_then(|c| match f.category {
+        Category::Text => c.text.as_deref(),
+        Category::Structural => c.structural.as_deref(),
+        Category::Code => c.code.as_deref(),
+        Category::User => c.user.as_deref(),
+        Category::Meta => None,
+    });
+    match threshold {
+        None => fail_on.map_or(shown, |min| f.severity >= min),
+        Some("never") => false,
+        Some(level) => level
+            .parse::<Severity>()
+            .is_ok_and(|min| f.severity.rank() >= min.rank()),
+    }
+}
+
 enum Formatter {
     Text,
     Json,
+    Sarif,
+    Github,
+    Compact,
 }
 
 impl Formatter {
     fn from_args(args: &Args) -> Self {
         match args.format {
             FormatArg::Json => Formatter::Json,
+            FormatArg::Sarif => Formatter::Sarif,
+            FormatArg::Github => Formatter::Github,
+            FormatArg::Compact => Formatter::Compact,
             FormatArg::Text => Formatter::Text,
         }
     }
@@ -311,79 +1271,214 @@ impl Formatter {
     fn render(&self, result: PipelineResult, args: &Args) -> Result<bool> {
         match self {
             Formatter::Json => {
-                let PipelineResult {
-                    findings,
-                    mode,
-                    filename,
-                    ..
-                } = result;
-                let had_findings = !findings.is_empty();
-                let report = build_json_report(&findings, &mode, filename.as_deref());
-                let json =
-                    serde_json::to_string_pretty(&report).map_err(|e| UnaiError::FileWrite {
-                        path: args.output.as_deref().unwrap_or("<stdout>").into(),
-                        source: std::io::Error::other(e.to_string()),
-                    })?;
-                write_output(&json, args.output.as_deref())?;
-                Ok(had_findings)
+                let mut report = result_json_report(&result, args);
+                report.run = Some(json_run(&result.run, &result.mode, args));
+                write_json(&report, args)?;
+                Ok(!result.findings.is_empty())
+            }
+            Formatter::Sarif => {
+                write_json(&sarif::sarif_log(&[sarif_file(&result)]), args)?;
+                Ok(!result.findings.is_empty())
+            }
+            Formatter::Github => {
+                write_output(&github_annotations(&result), args.output.as_deref())?;
+                Ok(!result.findings.is_empty())
+            }
+            Formatter::Compact => {
+                write_output(&compact_lines(&result), args.output.as_deref())?;
+                Ok(!result.findings.is_empty())
             }
             Formatter::Text => {
                 let PipelineResult {
-                    findings,
+                    mut findings,
+                    mut suppressed,
                     mode,
                     content,
-                    filename: _filename,
+                    path,
+                    filename,
+                    output,
+                    input,
+                    run,
+                    bom,
+                    ..
                 } = result;
+                if args.verbose {
+                    eprintln!("unai: {} mode ({})", mode.label(), run.detection);
+                    with_citations(&mut findings, &mut suppressed);
+                }
                 let had_findings = !findings.is_empty();
-                let use_color = match args.color {
-                    ColorArg::Always => true,
-                    ColorArg::Never => false,
-                    ColorArg::Auto => std::io::stderr().is_terminal(),
-                };
+                let (use_color, hyperlinks) = terminal_decorations(args);
+
+                if args.quiet {
+                    if let Some(path) = args.output.as_deref() {
+                        let cleaned = apply_fixes(
+                            &content,
+                            &findings,
+                            args.safe_fixes,
+                            args.rewrap.map(usize::from),
+                            &output,
+                        );
+                        write_output(&with_bom(cleaned, bom), Some(path))?;
+                    }
+                    return Ok(had_findings);
+                }
 
-                if !had_findings && !args.report {
-                    write_output(&content, args.output.as_deref())?;
+                let inspecting = args.report
+                    || args.show_suppressed
+                    || args.diff
+                    || args.dry_run
+                    || args.summary;
+                if !had_findings && !inspecting {
+                    // Every finding may have been suppressed by a trailing
+                    // marker, which the output still drops.
+                    let normalized = normalize_output(&content, &clean(&content, &[]), &output);
+                    write_output(&with_bom(normalized, bom), args.output.as_deref())?;
                     return Ok(false);
                 }
 
                 if args.report {
-                    print_report(&findings, &mode, use_color);
+                    print_report(
+                        &findings,
+                        &suppressed,
+                        &content,
+                        &mode,
+                        &ReportStyle {
+                            color: use_color,
+                            hyperlinks,
+                            plain: args.report_style == ReportStyleArg::Plain,
+                            by_rule: args.group_by == GroupByArg::Rule,
+                            file: filename.clone(),
+                            file_url: path.as_deref().and_then(file_url),
+                            explain_width: args.explain_findings.then(terminal_width),
+                            top_paragraphs: args.top_paragraphs,
+                        },
+                        args.safe_fixes,
+                        output.max_matched_len,
+                    );
+                }
+                if args.show_suppressed {
+                    print_suppressed(&suppressed, output.max_matched_len);
+                }
+
+                if args.summary {
+                    let head = format!("Mode: {}", mode.label());
+                    write_output(&summary_text(&head, &findings), args.output.as_deref())?;
+                    return Ok(had_findings);
                 }
 
                 if args.diff {
-                    return render_diff(&content, &findings, had_findings, args.output.as_deref());
+                    return render_diff(
+                        &content,
+                        &findings,
+                        &suppressed,
+                        args.safe_fixes,
+                        args.rewrap.map(usize::from),
+                        &output,
+                        args.output.as_deref(),
+                    );
                 }
 
                 if args.dry_run {
-                    print_dry_run(&content, &findings);
+                    print_dry_run(
+                        &findings,
+                        &suppressed,
+                        args.safe_fixes,
+                        output.max_matched_len,
+                    );
+                    // The original content, unchanged, so the output can be
+                    // piped on for inspection.
+                    write_stdout(&with_bom(content, bom))?;
                     return Ok(had_findings);
                 }
 
                 if args.annotate {
-                    print_annotated(&content, &findings);
+                    print_annotated(&content, &findings, input.max_line_bytes)?;
                     return Ok(had_findings);
                 }
 
-                let cleaned = clean(&content, &findings);
-                write_output(&cleaned, args.output.as_deref())?;
+                let cleaned = apply_fixes(
+                    &content,
+                    &findings,
+                    args.safe_fixes,
+                    args.rewrap.map(usize::from),
+                    &output,
+                );
+                write_output(&with_bom(cleaned, bom), args.output.as_deref())?;
                 Ok(had_findings)
             }
         }
     }
 }
 
+/// Workflow commands for one result's findings, located by its path relative
+/// to the working directory as given.
+fn github_annotations(result: &PipelineResult) -> String {
+    let path = result
+        .path
+        .as_deref()
+        .map(|path| path.replace('\\', "/").trim_start_matches("./").to_string());
+    github::annotations(path.as_deref(), &result.content, &result.findings)
+}
+
+/// `--format compact` lines for one input, named by its path, then by
+/// `--stdin-filename`, then `<stdin>`.
+fn compact_lines(result: &PipelineResult) -> String {
+    let file = result
+        .path
+        .as_deref()
+        .or(result.filename.as_deref())
+        .unwrap_or("<stdin>");
+    compact::lines(file, &result.content, &result.findings)
+}
+
+/// SARIF input for one result, located by its path relative to the working
+/// directory as given, or by `file://` URL when absolute.
+fn sarif_file(result: &PipelineResult) -> sarif::SarifFile<'_> {
+    let uri = result.path.as_deref().and_then(|path| {
+        if Path::new(path).is_absolute() {
+            file_url(path)
+        } else {
+            let path = path.replace('\\', "/");
+            Some(percent_encode(path.trim_start_matches("./")))
+        }
+    });
+    sarif::SarifFile {
+        uri,
+        content: &result.content,
+        findings: &result.findings,
+    }
+}
+
+fn write_json<T: serde::Serialize>(report: &T, args: &Args) -> Result<()> {
+    let json = serde_json::to_string_pretty(report).map_err(|e| UnaiError::FileWrite {
+        path: args.output.as_deref().unwrap_or("<stdout>").into(),
+        source: std::io::Error::other(e.to_string()),
+    })?;
+    write_output(&json, args.output.as_deref())
+}
+
 fn render_diff(
     content: &str,
     findings: &[Finding],
-    had_findings: bool,
+    suppressed: &[Suppressed],
+    safe_only: bool,
+    rewrap: Option<usize>,
+    normalize: &config::OutputConfig,
     output: Option<&str>,
 ) -> Result<bool> {
-    let cleaned = clean(content, findings);
+    let had_findings = !findings.is_empty();
+    let cleaned = apply_fixes(content, findings, safe_only, rewrap, normalize);
     let diff_output = diff::unified_diff(content, &cleaned, "original", "cleaned");
     if diff_output.is_empty() {
-        let fixable = findings.iter().filter(|f| f.replacement.is_some()).count();
+        let fixable = findings.iter().filter(|f| is_applied(f, safe_only)).count();
         if !had_findings {
-            eprintln!("unai: no findings");
+            match suppressed_note(suppressed) {
+                Some(note) => eprintln!(
+                    "unai: no changes; {}. Run --show-suppressed to list them",
+                    note
+                ),
+                None => eprintln!("unai: no findings"),
+            }
         } else if fixable == 0 {
             eprintln!(
                 "unai: {} finding(s), none auto-fixable (run --report to see them)",
@@ -398,13 +1493,404 @@ fn render_diff(
     Ok(had_findings)
 }
 
-fn run(args: Args) -> Result<bool> {
+/// Run the command; returns the worst severity among the findings that fail
+/// it, or `None` when it passes.
+fn run(args: Args) -> Result<Option<Severity>> {
+    if let Some(Command::Rules {
+        command: RulesCommand::Doc { format },
+    }) = &args.command
+    {
+        let docs = render_rule_docs(&registry(), format).map_err(|e| UnaiError::FileWrite {
+            path: args.output.as_deref().unwrap_or("<stdout>").into(),
+            source: std::io::Error::other(e.to_string()),
+        })?;
+        write_output(&docs, args.output.as_deref())?;
+        return Ok(None);
+    }
+    if let Some(Command::Config {
+        command: ConfigCommand::Init { force },
+    }) = &args.command
+    {
+        init_config(Path::new("unai.toml"), *force)?;
+        eprintln!("unai: wrote unai.toml");
+        return Ok(None);
+    }
+    if args.print_default_config {
+        write_output(&config::default_config_toml(), args.output.as_deref())?;
+        return Ok(None);
+    }
+    if args.summary && args.format != FormatArg::Text {
+        return Err(UnaiError::InvalidArgs(
+            "--summary prints text; the other formats carry their own summary".to_string(),
+        ));
+    }
+    if args.watch {
+        return run_watch(args);
+    }
+    if scan::is_scan(&args.files) {
+        return run_scan(&args);
+    }
     let result = pipeline(&args)?;
-    Formatter::from_args(&args).render(result, &args)
+    let (fails, timed_out) = (result.fails, result.timed_out);
+    Formatter::from_args(&args).render(result, &args)?;
+    if args.strict_files && timed_out {
+        return Err(UnaiError::TimedOut(1));
+    }
+    Ok(fails)
+}
+
+/// Lint every file under the path arguments and report them together. A scan
+/// has no single output for cleaned text, so it only inspects: it needs
+/// --report, --diff, --dry-run, --show-suppressed, --fail, or JSON output.
+fn run_scan(args: &Args) -> Result<Option<Severity>> {
+    let inspecting = args.report
+        || args.diff
+        || args.dry_run
+        || args.show_suppressed
+        || args.failing_run()
+        || args.summary
+        || args.quiet
+        || args.format != FormatArg::Text;
+    if args.annotate || !inspecting {
+        return Err(UnaiError::InvalidArgs(
+            "several inputs can be checked but not cleaned; add --report, --summary, \
+             --diff, --dry-run, --fail, or --format json|sarif|github|compact"
+                .to_string(),
+        ));
+    }
+    let (cfg, config_path) = load_config(args)?;
+    let filter = scan::PathFilter::new(&args.include, &args.exclude)?;
+    let paths = scan::collect_files(&args.files, &filter)?;
+    check_files(args, cfg.as_ref(), config_path, &paths)
+}
+
+/// Check `paths` and report on them together, as a directory scan does.
+fn check_files(
+    args: &Args,
+    cfg: Option<&config::Config>,
+    config_path: Option<String>,
+    paths: &[String],
+) -> Result<Option<Severity>> {
+    let started = Instant::now();
+    let mut results = Vec::new();
+    for path in paths {
+        let (content, filename, bom) = match read_input(Some(path)) {
+            Ok(input) => input,
+            Err(UnaiError::FileRead { source, .. })
+                if source.kind() == io::ErrorKind::InvalidData =>
+            {
+                eprintln!("unai: warning: skipping '{path}': not valid UTF-8");
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+        let mut result = analyze(
+            args,
+            cfg,
+            config_path.clone(),
+            Some(path),
+            content,
+            filename,
+            started,
+        )?;
+        result.bom = bom;
+        if args.write {
+            write_back(&result, args)?;
+        }
+        results.push(result);
+    }
+    let fails = results.iter().filter_map(|r| r.fails).max();
+    let timed_out = results.iter().filter(|r| r.timed_out).count();
+    match args.format {
+        FormatArg::Json => write_json(
+            &scan_json_report(&results, config_path, started, args),
+            args,
+        )?,
+        FormatArg::Sarif => {
+            let files: Vec<sarif::SarifFile> = results.iter().map(sarif_file).collect();
+            write_json(&sarif::sarif_log(&files), args)?
+        }
+        FormatArg::Github => {
+            let annotations: String = results.iter().map(github_annotations).collect();
+            write_output(&annotations, args.output.as_deref())?
+        }
+        FormatArg::Compact => {
+            let lines: String = results.iter().map(compact_lines).collect();
+            write_output(&lines, args.output.as_deref())?
+        }
+        FormatArg::Text if args.quiet => {}
+        FormatArg::Text if args.summary => {
+            let head = format!("Files: {}", results.len());
+            let findings: Vec<Finding> = results.into_iter().flat_map(|r| r.findings).collect();
+            write_output(&summary_text(&head, &findings), args.output.as_deref())?
+        }
+        FormatArg::Text => render_scan_text(results, args)?,
+    }
+    if args.strict_files && timed_out > 0 {
+        return Err(UnaiError::TimedOut(timed_out));
+    }
+    Ok(fails)
+}
+
+/// `--write`: replace a checked file with its cleaned text, when that differs.
+fn write_back(result: &PipelineResult, args: &Args) -> Result<()> {
+    let Some(path) = result.path.as_deref().filter(|_| !result.timed_out) else {
+        return Ok(());
+    };
+    let cleaned = apply_fixes(
+        &result.content,
+        &result.findings,
+        args.safe_fixes,
+        args.rewrap.map(usize::from),
+        &result.output,
+    );
+    if cleaned != result.content {
+        write_output(&with_bom(cleaned, result.bom), Some(path))?;
+    }
+    Ok(())
+}
+
+/// `--watch`: check the PATHs, then again each time a file under them or the
+/// config changes. Runs until interrupted; a failed run is reported and the
+/// watch goes on.
+fn run_watch(mut args: Args) -> Result<Option<Severity>> {
+    args.report |= args.format == FormatArg::Text && !(args.diff || args.dry_run);
+    let filter = scan::PathFilter::new(&args.include, &args.exclude)?;
+    let config_file = args.config.clone().unwrap_or_else(|| "unai.toml".into());
+    let take = || {
+        let mut paths = scan::collect_files(&args.files, &filter).unwrap_or_default();
+        paths.push(config_file.clone());
+        watch::Snapshot::take(&paths)
+    };
+    let clear = args.format == FormatArg::Text && io::stderr().is_terminal();
+    let mut last = watch::Snapshot::default();
+    let mut current = take();
+    loop {
+        let changed = current.changed_since(&last);
+        let everything = args.format != FormatArg::Text || changed.contains(&config_file);
+        if everything || !changed.is_empty() {
+            if clear {
+                eprint!("\x1b[2J\x1b[H");
+            }
+            let outcome = scan::collect_files(&args.files, &filter).and_then(|files| {
+                let checked: Vec<String> = match everything {
+                    true => files,
+                    false => files.into_iter().filter(|f| changed.contains(f)).collect(),
+                };
+                let (cfg, config_path) = load_config(&args)?;
+                check_files(&args, cfg.as_ref(), config_path, &checked)
+            });
+            match outcome {
+                Err(UnaiError::StdoutClosed) => return Err(UnaiError::StdoutClosed),
+                Err(e) => eprintln!("unai: {e}"),
+                Ok(_) => {}
+            }
+            eprintln!("unai: watching for changes; press Ctrl-C to stop");
+        }
+        // Taken after the run, so the files --write just rewrote don't count
+        // as changed.
+        last = take();
+        current = watch::wait_for_change(&last, watch::POLL_INTERVAL, take);
+    }
+}
+
+/// Text output of a scan: each file with something to show under its path,
+/// then a one-line total. `--diff` writes one unified diff per changed file,
+/// labeled with its path.
+fn render_scan_text(results: Vec<PipelineResult>, args: &Args) -> Result<()> {
+    let (use_color, hyperlinks) = terminal_decorations(args);
+    let scanned = results.len();
+    let timed_out = results.iter().filter(|r| r.timed_out).count();
+    let (mut total, mut with_findings) = (0, 0);
+    let mut diffs = String::new();
+    for mut result in results {
+        let path = result.path.clone().unwrap_or_default();
+        if args.verbose {
+            eprintln!(
+                "unai: {path}: {} mode ({})",
+                result.mode.label(),
+                result.run.detection
+            );
+            with_citations(&mut result.findings, &mut result.suppressed);
+        }
+        let has_findings = !result.findings.is_empty();
+        total += result.findings.len();
+        with_findings += usize::from(has_findings);
+        let max_matched = result.output.max_matched_len;
+        if (args.report || args.dry_run) && has_findings
+            || args.show_suppressed && !result.suppressed.is_empty()
+        {
+            let bold = Style::new().bold();
+            match use_color {
+                true => anstream::eprintln!("\n{bold}{path}{bold:#}"),
+                false => anstream::eprintln!("\n{path}"),
+            }
+        }
+        if args.report && has_findings {
+            print_report(
+                &result.findings,
+                &result.suppressed,
+                &result.content,
+                &result.mode,
+                &ReportStyle {
+                    color: use_color,
+                    hyperlinks,
+                    plain: args.report_style == ReportStyleArg::Plain,
+                    by_rule: args.group_by == GroupByArg::Rule,
+                    file: Some(path.clone()),
+                    file_url: file_url(&path),
+                    explain_width: args.explain_findings.then(terminal_width),
+                    top_paragraphs: args.top_paragraphs,
+                },
+                args.safe_fixes,
+                max_matched,
+            );
+        }
+        if args.show_suppressed && !result.suppressed.is_empty() {
+            print_suppressed(&result.suppressed, max_matched);
+        }
+        if args.dry_run && has_findings {
+            print_dry_run(
+                &result.findings,
+                &result.suppressed,
+                args.safe_fixes,
+                max_matched,
+            );
+        }
+        if args.diff {
+            let cleaned = apply_fixes(
+                &result.content,
+                &result.findings,
+                args.safe_fixes,
+                args.rewrap.map(usize::from),
+                &result.output,
+            );
+            diffs.push_str(&diff::unified_diff(
+                &result.content,
+                &cleaned,
+                &format!("a/{path}"),
+                &format!("b/{path}"),
+            ));
+        }
+    }
+    match timed_out {
+        0 => eprintln!("\nunai: {total} finding(s) in {with_findings} of {scanned} file(s)"),
+        _ => eprintln!(
+            "\nunai: {total} finding(s) in {with_findings} of {scanned} file(s); \
+             {timed_out} timed out"
+        ),
+    }
+    if args.diff {
+        write_output(&diffs, args.output.as_deref())?;
+    }
+    Ok(())
+}
+
+/// Write the default configuration to `path`, refusing to replace an
+/// existing file unless `force` is set.
+fn init_config(path: &Path, force: bool) -> Result<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true);
+    if force {
+        options.create(true).truncate(true);
+    } else {
+        options.create_new(true);
+    }
+    options
+        .open(path)
+        .and_then(|mut file| file.write_all(config::default_config_toml().as_bytes()))
+        .map_err(|source| UnaiError::FileWrite {
+            path: path.into(),
+            source,
+        })
 }
 
-fn read_input(file_arg: &Option<String>) -> Result<(String, Option<String>)> {
-    match file_arg {
+/// Lowercase name a unit enum variant serializes to, as in the JSON output.
+fn serde_label<T: serde::Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+/// Documentation for `docs` as `{"rules": [...]}` JSON or one Markdown
+/// section per rule.
+fn render_rule_docs(docs: &[RuleDoc], format: &DocFormatArg) -> serde_json::Result<String> {
+    if *format == DocFormatArg::Json {
+        #[derive(serde::Serialize)]
+        struct JsonRuleDocs<'a> {
+            rules: &'a [RuleDoc],
+        }
+        let mut json = serde_json::to_string_pretty(&JsonRuleDocs { rules: docs })?;
+        json.push('\n');
+        return Ok(json);
+    }
+    let mut out = String::from("# unai rules\n");
+    for doc in docs {
+        out.push_str(&format!("\n## `{}`\n\n{}\n\n", doc.id, doc.description));
+        out.push_str(&format!("- Category: {}\n", serde_label(&doc.category)));
+        out.push_str(&format!("- Severity: {}\n", serde_label(&doc.severity)));
+        let modes: Vec<String> = doc.modes.iter().map(serde_label).collect();
+        out.push_str(&format!("- Modes: {}\n", modes.join(", ")));
+        if !doc.tags.is_empty() {
+            out.push_str(&format!("- Tags: {}\n", doc.tags.join(", ")));
+        }
+        let fix = match &doc.fix {
+            None => "none, flag only".to_string(),
+            Some(fix) => {
+                let action = if fix.replacement.is_empty() {
+                    "delete the match".to_string()
+                } else {
+                    format!("replace with `{}`", fix.replacement)
+                };
+                let condition = fix
+                    .condition
+                    .as_deref()
+                    .map(|c| format!(", {c}"))
+                    .unwrap_or_default();
+                format!("{action} ({}){condition}", serde_label(&fix.safety))
+            }
+        };
+        out.push_str(&format!("- Fix: {fix}\n"));
+        if !doc.suggestions.is_empty() {
+            let listed: Vec<String> = doc.suggestions.iter().map(|s| format!("`{s}`")).collect();
+            out.push_str(&format!("- Suggestions: {}\n", listed.join(", ")));
+        }
+        if let Some(source) = doc.source {
+            let citation = match source.url() {
+                Some(url) => format!("[{}]({url})", source.citation),
+                None => source.citation.to_string(),
+            };
+            out.push_str(&format!(
+                "- Source: {citation} ({} confidence)\n",
+                serde_label(&source.confidence)
+            ));
+        }
+        for (label, example) in [("Bad", doc.examples.bad), ("Good", doc.examples.good)] {
+            out.push_str(&format!(
+                "\n{label}:\n\n```text\n{}\n```\n",
+                example.trim_end()
+            ));
+        }
+    }
+    Ok(out)
+}
+
+/// The UTF-8 byte order mark some Windows editors write at the start of a file.
+const BOM: char = '\u{FEFF}';
+
+/// `text` with the byte order mark put back when the input had one.
+fn with_bom(text: String, bom: bool) -> String {
+    match bom {
+        true => format!("{BOM}{text}"),
+        false => text,
+    }
+}
+
+/// The input's text with any leading byte order mark stripped, its file name,
+/// and whether the mark was there.
+fn read_input(file_arg: Option<&str>) -> Result<(String, Option<String>, bool)> {
+    let (content, filename) = match file_arg {
         Some(path) => {
             let meta = fs::metadata(path).map_err(|source| UnaiError::FileRead {
                 path: path.into(),
@@ -422,7 +1908,7 @@ fn read_input(file_arg: &Option<String>) -> Result<(String, Option<String>)> {
                 .and_then(|n| n.to_str())
                 .unwrap_or(path)
                 .to_string();
-            Ok((content, Some(filename)))
+            (content, Some(filename))
         }
         None => {
             let mut buf = Vec::new();
@@ -439,74 +1925,54 @@ fn read_input(file_arg: &Option<String>) -> Result<(String, Option<String>)> {
                     "stdin is not valid UTF-8",
                 ),
             })?;
-            Ok((content, None))
+            (content, None)
         }
+    };
+    match content.strip_prefix(BOM) {
+        Some(rest) => Ok((rest.to_string(), filename, true)),
+        None => Ok((content, filename, false)),
     }
 }
 
-fn resolve_mode(mode_arg: &ModeArg, filename: Option<&str>, content: &str) -> Mode {
+fn resolve_mode(
+    mode_arg: &ModeArg,
+    filename: Option<&str>,
+    content: &str,
+) -> (Mode, DetectionReason) {
     match mode_arg {
-        ModeArg::Text => Mode::Text,
-        ModeArg::Code => Mode::Code,
+        ModeArg::Text => (Mode::Text, DetectionReason::Flag),
+        ModeArg::Markdown => (Mode::Markdown, DetectionReason::Flag),
+        ModeArg::Commit => (Mode::CommitMsg, DetectionReason::Flag),
+        ModeArg::Code => (Mode::Code, DetectionReason::Flag),
         ModeArg::Auto => detect_mode(filename, content),
     }
 }
 
+/// `--timeout` seconds as a duration; fractions are allowed, zero is not.
+fn parse_timeout(raw: &str) -> std::result::Result<Duration, String> {
+    let seconds: f64 = raw
+        .parse()
+        .map_err(|_| format!("'{raw}' is not a number of seconds"))?;
+    match Duration::try_from_secs_f64(seconds) {
+        Ok(timeout) if !timeout.is_zero() => Ok(timeout),
+        _ => Err(format!("'{raw}' must be a positive number of seconds")),
+    }
+}
+
 fn parse_code_rules(raw: &[String]) -> Result<Vec<CodeRule>> {
     raw.iter()
         .map(|s| s.parse::<CodeRule>().map_err(UnaiError::InvalidRule))
         .collect()
 }
 
-fn gather_findings(
-    content: &str,
-    mode: &Mode,
-    code_rules: &[CodeRule],
-    filename: Option<&str>,
-) -> Vec<Finding> {
-    match mode {
-        Mode::Text => {
-            let mut findings = apply_text_rules(content);
-            findings.extend(apply_structural_rules(content));
-            findings
-        }
-        Mode::CommitMsg => {
-            let mut findings = apply_text_rules(content);
-            findings.extend(apply_code_rules(content, &[CodeRule::Commits]));
-            findings.extend(apply_structural_rules(content));
-            findings
-        }
-        Mode::Code => {
-            let is_commit_file = filename.map(is_commit_msg_file).unwrap_or(false);
-            // When no explicit rules are given ("all"), exclude commit-message rules for
-            // non-commit files — they produce false positives on line 1 of arbitrary code.
-            let effective_rules: &[CodeRule] = if code_rules.is_empty() && !is_commit_file {
-                &[
-                    CodeRule::Comments,
-                    CodeRule::Naming,
-                    CodeRule::Docstrings,
-                    CodeRule::Tests,
-                    CodeRule::Errors,
-                    CodeRule::Api,
-                ]
-            } else {
-                code_rules
-            };
-            let mut findings = apply_code_rules(content, effective_rules);
-            // Ensure commit rules fire for commit message files when the caller restricted
-            // rules and did not explicitly include commits.
-            if is_commit_file && !code_rules.is_empty() && !code_rules.contains(&CodeRule::Commits)
-            {
-                findings.extend(apply_code_rules(content, &[CodeRule::Commits]));
-            }
-            findings
-        }
-    }
-}
-
-fn print_dry_run(content: &str, findings: &[Finding]) {
+fn print_dry_run(
+    findings: &[Finding],
+    suppressed: &[Suppressed],
+    safe_only: bool,
+    max_matched: usize,
+) {
     let (fixable, unfixable): (Vec<&Finding>, Vec<&Finding>) =
-        findings.iter().partition(|f| f.replacement.is_some());
+        findings.iter().partition(|f| is_applied(f, safe_only));
 
     if !fixable.is_empty() {
         eprintln!("--- Auto-fixable ({}) ---", fixable.len());
@@ -515,12 +1981,17 @@ fn print_dry_run(content: &str, findings: &[Finding]) {
             if repl.is_empty() {
                 eprintln!(
                     "  line {:>4}: [remove] {:?}  — {}",
-                    f.line, f.matched, f.message
+                    f.line,
+                    display_matched(&f.matched, max_matched),
+                    f.message
                 );
             } else {
                 eprintln!(
                     "  line {:>4}: {:?} → {:?}  — {}",
-                    f.line, f.matched, repl, f.message
+                    f.line,
+                    display_matched(&f.matched, max_matched),
+                    repl,
+                    f.message
                 );
             }
         }
@@ -529,38 +2000,118 @@ fn print_dry_run(content: &str, findings: &[Finding]) {
     if !unfixable.is_empty() {
         eprintln!("--- Flagged (no auto-fix) ({}) ---", unfixable.len());
         for f in &unfixable {
-            eprintln!("  line {:>4}: {:?}  — {}", f.line, f.matched, f.message);
+            match f.replacement.as_deref() {
+                Some(repl) => eprintln!(
+                    "  line {:>4}: {:?} (suggest {:?})  — {}{}",
+                    f.line,
+                    display_matched(&f.matched, max_matched),
+                    repl,
+                    f.message,
+                    consider_hint(f)
+                ),
+                None => eprintln!(
+                    "  line {:>4}: {:?}  — {}{}",
+                    f.line,
+                    display_matched(&f.matched, max_matched),
+                    f.message,
+                    consider_hint(f)
+                ),
+            }
         }
     }
 
-    // Emit original content unchanged when dry-running (suitable for piping inspection)
-    print!("{}", content);
+    if let Some(note) = suppressed_note(suppressed) {
+        eprintln!("--- {} ---", note);
+    }
 }
 
-fn print_annotated(content: &str, findings: &[Finding]) {
-    // Group findings by line number for inline display
-    let mut by_line: std::collections::HashMap<usize, Vec<&Finding>> =
+/// Print `content` with each finding's notes under its line. Lines past
+/// `max_line_bytes` are shortened to that length, with their full size noted.
+fn print_annotated(content: &str, findings: &[Finding], max_line_bytes: usize) -> Result<()> {
+    let notes = annotations(findings);
+    let mut out = io::stdout().lock();
+    for (idx, line) in content.lines().enumerate() {
+        if max_line_bytes > 0 && line.len() > max_line_bytes {
+            let mut cut = max_line_bytes;
+            while !line.is_char_boundary(cut) {
+                cut -= 1;
+            }
+            writeln!(out, "{}… ({} bytes)", &line[..cut], line.len())
+        } else {
+            writeln!(out, "{}", line)
+        }
+        .map_err(stdout_error)?;
+        for note in notes.get(&(idx + 1)).into_iter().flatten() {
+            eprintln!("  {}", note);
+        }
+    }
+    Ok(())
+}
+
+/// Marker and message lines to print under each line, keyed by line number.
+/// A multi-line finding gets its message on the first line and a marker on
+/// every line it covers.
+fn annotations(findings: &[Finding]) -> std::collections::HashMap<usize, Vec<String>> {
+    let mut by_line: std::collections::HashMap<usize, Vec<String>> =
         std::collections::HashMap::new();
 
     for f in findings {
-        by_line.entry(f.line).or_default().push(f);
+        let arrow = " ".repeat(f.col) + "^";
+        let fix_hint = match f.replacement.as_deref() {
+            Some("") => " (remove)".to_string(),
+            Some(r) => format!(" → \"{}\"", r),
+            None => String::new(),
+        };
+        let notes = by_line.entry(f.line).or_default();
+        notes.push(format!("{}{}", arrow, fix_hint));
+        notes.push(match f.rule_id.is_empty() {
+            true => f.message.clone(),
+            false => format!("{} [{}]", f.message, f.rule_id),
+        });
+        for lineno in f.line + 1..=f.last_line() {
+            by_line
+                .entry(lineno)
+                .or_default()
+                .push(format!("^ (continued from line {})", f.line));
+        }
     }
+    by_line
+}
 
-    for (idx, line) in content.lines().enumerate() {
-        let lineno = idx + 1;
-        println!("{}", line);
-        if let Some(line_findings) = by_line.get(&lineno) {
-            for f in line_findings {
-                let arrow = " ".repeat(f.col) + "^";
-                let fix_hint = match f.replacement.as_deref() {
-                    Some("") => " (remove line)".to_string(),
-                    Some(r) => format!(" → \"{}\"", r),
-                    None => String::new(),
-                };
-                eprintln!("  {}{}", arrow, fix_hint);
-                eprintln!("  {}", f.message);
-            }
-        }
+/// One-line tally of suppressions, largest cause first, e.g.
+/// "5 finding(s) suppressed: 3 ignore-directive, 2 min-severity".
+fn suppressed_note(suppressed: &[Suppressed]) -> Option<String> {
+    if suppressed.is_empty() {
+        return None;
+    }
+    let mut counts: Vec<(SuppressCause, usize)> = SuppressCause::ALL
+        .iter()
+        .map(|&c| (c, count_suppressed(suppressed, c)))
+        .filter(|&(_, n)| n > 0)
+        .collect();
+    counts.sort_by_key(|&(_, n)| std::cmp::Reverse(n));
+    let parts: Vec<String> = counts
+        .iter()
+        .map(|(c, n)| format!("{} {}", n, c.label()))
+        .collect();
+    Some(format!(
+        "{} finding(s) suppressed: {}",
+        suppressed.len(),
+        parts.join(", ")
+    ))
+}
+
+fn print_suppressed(suppressed: &[Suppressed], max_matched: usize) {
+    eprintln!("\nSUPPRESSED ({})", suppressed.len());
+    for s in suppressed {
+        let f = &s.finding;
+        eprintln!(
+            "  line {}: {} '{}' [{}]",
+            f.line,
+            f.message,
+            display_matched(&f.matched, max_matched),
+            s.cause.label()
+        );
     }
 }
 
@@ -589,34 +2140,551 @@ fn severity_style(sev: Severity, color: bool) -> String {
     }
 }
 
-fn print_report(findings: &[Finding], mode: &Mode, color: bool) {
-    eprintln!(
-        "Mode: {}  |  {} finding(s)",
-        mode_label(mode),
-        findings.len()
+/// How the report decorates its lines for the terminal.
+struct ReportStyle {
+    color: bool,
+    /// Wrap rule ids and line references in OSC 8 hyperlinks.
+    hyperlinks: bool,
+    /// `--report-style plain`: the grouped one-line format.
+    plain: bool,
+    /// `--group-by rule`: one line per rule instead of per finding.
+    by_rule: bool,
+    /// Input path as given, shown in the location column.
+    file: Option<String>,
+    /// `file://` URL of the input, the target of line references.
+    file_url: Option<String>,
+    /// Width to wrap `--explain-findings` text to; `None` without the flag.
+    explain_width: Option<usize>,
+    /// `--top-paragraphs`: how many of the highest-scoring paragraphs to list.
+    top_paragraphs: usize,
+}
+
+impl ReportStyle {
+    /// `text` as a hyperlink to `url` when hyperlinks are on, else as is.
+    fn link(&self, text: &str, url: Option<&str>) -> String {
+        match url {
+            Some(url) if self.hyperlinks => format!("\x1b]8;;{url}\x1b\\{text}\x1b]8;;\x1b\\"),
+            _ => text.to_string(),
+        }
+    }
+}
+
+/// Whether stderr output gets color and hyperlinks, per `--color` and
+/// `--hyperlinks`. Also sets anstream's global choice to match.
+fn terminal_decorations(args: &Args) -> (bool, bool) {
+    let use_color = match args.color {
+        ColorArg::Always => true,
+        ColorArg::Never => false,
+        // Honors NO_COLOR, CLICOLOR_FORCE, and TERM=dumb as well
+        // as whether stderr is a terminal.
+        ColorArg::Auto => AutoStream::choice(&io::stderr()) != anstream::ColorChoice::Never,
+    };
+    let hyperlinks = match args.hyperlinks {
+        HyperlinksArg::Always => args.color != ColorArg::Never,
+        HyperlinksArg::Never => false,
+        HyperlinksArg::Auto => use_color && io::stderr().is_terminal() && terminal_has_hyperlinks(),
+    };
+    // The report writes through anstream, which turns the styles into
+    // console calls on Windows consoles without ANSI support. Stripping would
+    // also remove the hyperlinks, so they keep escapes on; the report emits
+    // no color codes unless asked.
+    anstream::ColorChoice::write_global(if use_color || hyperlinks {
+        anstream::ColorChoice::Always
+    } else {
+        anstream::ColorChoice::Never
+    });
+    (use_color, hyperlinks)
+}
+
+/// Whether the terminal on stderr renders OSC 8 hyperlinks, judged from the
+/// variables the common emulators set. Unknown terminals get plain text.
+fn terminal_has_hyperlinks() -> bool {
+    let var = |name| std::env::var(name).unwrap_or_default();
+    if var("TERM") == "dumb" {
+        return false;
+    }
+    matches!(
+        var("TERM_PROGRAM").as_str(),
+        "iTerm.app" | "WezTerm" | "vscode" | "ghostty" | "Hyper"
+    ) || var("VTE_VERSION").parse::<u32>().is_ok_and(|v| v >= 5000)
+        || [
+            "WT_SESSION",
+            "KITTY_WINDOW_ID",
+            "KONSOLE_VERSION",
+            "DOMTERM",
+        ]
+        .iter()
+        .any(|name| std::env::var_os(name).is_some())
+}
+
+/// Width of the terminal, from `$COLUMNS`; 80 when it is unset or invalid.
+fn terminal_width() -> usize {
+    std::env::var("COLUMNS")
+        .ok()
+        .and_then(|c| c.trim().parse().ok())
+        .filter(|&c| c > 0)
+        .unwrap_or(80)
+}
+
+/// `file://` URL for `path`, percent-encoding anything outside the
+/// unreserved set. `None` if the path cannot be made absolute.
+fn file_url(path: &str) -> Option<String> {
+    let absolute = std::path::absolute(path).ok()?;
+    let absolute = absolute.to_str()?.replace('\\', "/");
+    let mut url = String::from("file://");
+    if !absolute.starts_with('/') {
+        url.push('/');
+    }
+    url.push_str(&percent_encode(&absolute));
+    Some(url)
+}
+
+/// `path` with bytes outside the unreserved set, `/`, and `:` percent-encoded.
+fn percent_encode(path: &str) -> String {
+    let mut encoded = String::new();
+    for b in path.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' | b':' => {
+                encoded.push(b as char)
+            }
+            _ => encoded.push_str(&format!("%{b:02X}")),
+        }
+    }
+    encoded
+}
+
+fn print_report(
+    findings: &[Finding],
+    suppressed: &[Suppressed],
+    content: &str,
+    mode: &Mode,
+    style: &ReportStyle,
+    safe_only: bool,
+    max_matched: usize,
+) {
+    anstream::eprintln!("Mode: {}  |  {} finding(s)", mode.label(), findings.len());
+    if let Some(note) = suppressed_note(suppressed) {
+        anstream::eprintln!("{}", note);
+    }
+    let superseded = superseded_in(content, findings, safe_only).len();
+    if superseded > 0 {
+        anstream::eprintln!(
+            "{} fix(es) superseded by a conflicting fix on the same line",
+            superseded
+        );
+    }
+    if style.by_rule {
+        print_report_by_rule(findings, style);
+    } else if style.plain {
+        print_report_plain(findings, style, safe_only, max_matched);
+    } else {
+        print_report_aligned(findings, content, style, safe_only, max_matched);
+    }
+    if style.top_paragraphs > 0 && is_prose(mode) {
+        print_top_paragraphs(&score::score(content, findings), style.top_paragraphs);
+    }
+}
+
+/// The paragraphs that read most machine-written, highest score first, with
+/// what raised each score. A single paragraph has nothing to rank against.
+fn print_top_paragraphs(scores: &score::Scores, n: usize) {
+    let top = scores.top(n);
+    if top.is_empty() || scores.paragraphs.len() < 2 {
+        return;
+    }
+    anstream::eprintln!(
+        "\nMost machine-like paragraphs (document score {}/100):",
+        scores.document
     );
+    let lines: Vec<String> = top
+        .iter()
+        .map(|p| match p.end_line > p.line {
+            true => format!("lines {}-{}", p.line, p.end_line),
+            false => format!("line {}", p.line),
+        })
+        .collect();
+    let width = lines.iter().map(String::len).max().unwrap_or(0);
+    for (p, lines) in top.iter().zip(&lines) {
+        anstream::eprintln!(
+            "  {:>3}  {:<width$}  {}",
+            p.score,
+            lines,
+            p.factors.join("; ")
+        );
+    }
+}
 
-    // Group findings by severity in descending order
-    let severity_levels: &[(&str, Severity)] = &[
-        ("CRITICAL", Severity::Critical),
-        ("HIGH", Severity::High),
-        ("MEDIUM", Severity::Medium),
-        ("LOW", Severity::Low),
-    ];
+/// Modes whose input is prose, which paragraphs are scored for.
+fn is_prose(mode: &Mode) -> bool {
+    matches!(mode, Mode::Text | Mode::Markdown)
+}
 
-    for (label, sev) in severity_levels {
-        let group: Vec<&Finding> = findings.iter().filter(|f| f.severity == *sev).collect();
+/// `--summary`: `head` and the finding count, then the count per severity.
+fn summary_text(head: &str, findings: &[Finding]) -> String {
+    let counts: Vec<String> = SEVERITY_LEVELS
+        .iter()
+        .map(|&(label, sev)| {
+            let n = count_by_severity(findings, sev);
+            format!("{}: {n}", label.to_lowercase())
+        })
+        .collect();
+    format!(
+        "{head}  |  {} finding(s)\n{}\n",
+        findings.len(),
+        counts.join("  ")
+    )
+}
+
+/// Severities from most to least severe, with their report labels.
+const SEVERITY_LEVELS: [(&str, Severity); 4] = [
+    ("CRITICAL", Severity::Critical),
+    ("HIGH", Severity::High),
+    ("MEDIUM", Severity::Medium),
+    ("LOW", Severity::Low),
+];
+
+/// One line per finding under a heading per severity.
+fn print_report_plain(
+    findings: &[Finding],
+    style: &ReportStyle,
+    safe_only: bool,
+    max_matched: usize,
+) {
+    let rules = registry();
+    for (label, sev) in SEVERITY_LEVELS {
+        let group: Vec<&Finding> = findings.iter().filter(|f| f.severity == sev).collect();
 
         if group.is_empty() {
             continue;
         }
 
-        let style_start = severity_style(*sev, color);
-        let reset = if color { RESET } else { "" };
-        eprintln!("\n{}{} ({}){}", style_start, label, group.len(), reset);
+        let style_start = severity_style(sev, style.color);
+        let reset = if style.color { RESET } else { "" };
+        anstream::eprintln!("\n{}{} ({}){}", style_start, label, group.len(), reset);
         for f in group {
-            eprintln!("  line {}: {} '{}'", f.line, f.message, f.matched);
+            let line = style.link(
+                &format!("line {}", f.line),
+                style.file_url.as_deref().filter(|_| f.line > 0),
+            );
+            let doc = rule_for(&rules, f);
+            let rule = match f.rule_id.is_empty() {
+                true => String::new(),
+                false => format!(
+                    " [{}]",
+                    style.link(&f.rule_id, doc.and_then(|r| r.doc_url()).as_deref())
+                ),
+            };
+            anstream::eprintln!(
+                "  {}: {} {}{}",
+                line,
+                f.message,
+                excerpt(f, safe_only, max_matched),
+                rule
+            );
+            print_explanation(doc, style);
+        }
+    }
+}
+
+/// The findings of one rule, for `--group-by rule` and the JSON `by_rule`.
+struct RuleGroup<'a> {
+    rule_id: &'a str,
+    /// The most severe of its findings'.
+    severity: Severity,
+    findings: Vec<&'a Finding>,
+}
+
+impl RuleGroup<'_> {
+    /// Distinct lines the rule fired on, in order; whole-input findings have
+    /// none.
+    fn lines(&self) -> Vec<usize> {
+        let mut lines: Vec<usize> = self
+            .findings
+            .iter()
+            .map(|f| f.line)
+            .filter(|&l| l > 0)
+            .collect();
+        lines.sort_unstable();
+        lines.dedup();
+        lines
+    }
+}
+
+/// `findings` grouped by rule id: most severe first, then most frequent,
+/// then by id.
+fn group_by_rule(findings: &[Finding]) -> Vec<RuleGroup<'_>> {
+    let mut groups: Vec<RuleGroup> = Vec::new();
+    for f in findings {
+        match groups.iter_mut().find(|g| g.rule_id == f.rule_id) {
+            Some(group) => {
+                group.severity = group.severity.max(f.severity);
+                group.findings.push(f);
+            }
+            None => groups.push(RuleGroup {
+                rule_id: &f.rule_id,
+                severity: f.severity,
+                findings: vec![f],
+            }),
+        }
+    }
+    groups.sort_by(|a, b| {
+        (b.severity, b.findings.len())
+            .cmp(&(a.severity, a.findings.len()))
+            .then_with(|| a.rule_id.cmp(b.rule_id))
+    });
+    groups
+}
+
+/// Lines shown as examples of a rule in `--group-by rule`.
+const EXAMPLE_LINES: usize = 3;
+
+/// One line per rule: severity, id, finding count, the first finding's
+/// message, and the first lines it fired on.
+fn print_report_by_rule(findings: &[Finding], style: &ReportStyle) {
+    let rules = registry();
+    let groups = group_by_rule(findings);
+    let rule_id = |g: &RuleGroup| match g.rule_id.is_empty() {
+        true => "-".to_string(),
+        false => g.rule_id.to_string(),
+    };
+    let width = |cell: &dyn Fn(&RuleGroup) -> usize| groups.iter().map(cell).max().unwrap_or(0);
+    let rule_width = width(&|g| rule_id(g).chars().count());
+    let count_width = width(&|g| g.findings.len().to_string().len());
+    let message_width = width(&|g| g.findings[0].message.chars().count());
+    let label_width = "critical".len();
+    // Pad by visible width: a hyperlink's escapes take no columns.
+    let pad = |text: &str, width: usize| " ".repeat(width - text.chars().count());
+
+    anstream::eprintln!();
+    for group in &groups {
+        let severity = format!(
+            "{} {:<label_width$}",
+            severity_glyph(group.severity, style.color),
+            group.severity.label()
+        );
+        let (style_start, reset) = match style.color {
+            true => (severity_style(group.severity, true), RESET),
+            false => (String::new(), ""),
+        };
+        let id = rule_id(group);
+        let doc = rule_for(&rules, group.findings[0]);
+        let rule = style.link(&id, doc.and_then(|r| r.doc_url()).as_deref());
+        let message = &group.findings[0].message;
+        let lines = group.lines();
+        let mut examples: Vec<String> = lines
+            .iter()
+            .take(EXAMPLE_LINES)
+            .map(usize::to_string)
+            .collect();
+        if lines.len() > EXAMPLE_LINES {
+            examples.push("…".to_string());
+        }
+        let examples = match lines.len() {
+            0 => String::new(),
+            1 => format!("line {}", examples[0]),
+            _ => format!("lines {}", examples.join(", ")),
+        };
+        anstream::eprintln!(
+            "{style_start}{severity}{reset}  {rule}{}  {:>count_width$}×  {message}{}  {examples}",
+            pad(&id, rule_width),
+            group.findings.len(),
+            pad(message, message_width),
+        );
+    }
+}
+
+/// Severity glyph: Unicode shapes with color, ASCII without.
+fn severity_glyph(sev: Severity, unicode: bool) -> &'static str {
+    match (sev, unicode) {
+        (Severity::Critical, true) => "✖",
+        (Severity::High, true) => "▲",
+        (Severity::Medium, true) => "●",
+        (Severity::Low, true) => "○",
+        (Severity::Critical, false) => "x",
+        (Severity::High, false) => "^",
+        (Severity::Medium, false) => "*",
+        (Severity::Low, false) => "o",
+    }
+}
+
+/// One row per finding, most severe first: severity, `file:line:col`, rule
+/// id, message, and the matched excerpt. Column widths fit the widest entry.
+fn print_report_aligned(
+    findings: &[Finding],
+    content: &str,
+    style: &ReportStyle,
+    safe_only: bool,
+    max_matched: usize,
+) {
+    let rules = registry();
+    let lines: Vec<&str> = content.lines().collect();
+    struct Row<'a> {
+        finding: &'a Finding,
+        location: String,
+        rule: Option<&'a RuleDoc>,
+    }
+    let mut sorted: Vec<&Finding> = findings.iter().collect();
+    sorted.sort_by_key(|f| (std::cmp::Reverse(f.severity.rank()), f.line, f.col));
+    let rows: Vec<Row> = sorted
+        .into_iter()
+        .map(|f| {
+            // 1-based character column, as editors count it.
+            let col = lines
+                .get(f.line.wrapping_sub(1))
+                .and_then(|l| l.get(..f.col))
+                .map_or(f.col, |before| before.chars().count())
+                + 1;
+            let location = match (&style.file, f.line) {
+                (_, 0) => "-".to_string(),
+                (Some(file), line) => format!("{file}:{line}:{col}"),
+                (None, line) => format!("{line}:{col}"),
+            };
+            Row {
+                finding: f,
+                location,
+                rule: rule_for(&rules, f),
+            }
+        })
+        .collect();
+    let width = |cell: &dyn Fn(&Row) -> usize| rows.iter().map(cell).max().unwrap_or(0);
+    let location_width = width(&|r| r.location.chars().count());
+    let rule_id = |f: &'_ Finding| -> String {
+        match f.rule_id.is_empty() {
+            true => "-".to_string(),
+            false => f.rule_id.clone(),
+        }
+    };
+    let rule_width = width(&|r| rule_id(r.finding).chars().count());
+    let message_width = width(&|r| r.finding.message.chars().count());
+    let label_width = "critical".len();
+
+    anstream::eprintln!();
+    for row in &rows {
+        let f = row.finding;
+        let (label, _) = SEVERITY_LEVELS
+            .iter()
+            .find(|(_, sev)| *sev == f.severity)
+            .expect("every severity has a label");
+        let severity = format!(
+            "{} {:<label_width$}",
+            severity_glyph(f.severity, style.color),
+            label.to_lowercase()
+        );
+        let (style_start, reset) = match style.color {
+            true => (severity_style(f.severity, true), RESET),
+            false => (String::new(), ""),
+        };
+        // Pad by visible width: a hyperlink's escapes take no columns.
+        let pad = |text: &str, width: usize| " ".repeat(width - text.chars().count());
+        let location = style.link(
+            &row.location,
+            style.file_url.as_deref().filter(|_| f.line > 0),
+        );
+        let rule_id = rule_id(f);
+        let rule = style.link(&rule_id, row.rule.and_then(|d| d.doc_url()).as_deref());
+        anstream::eprintln!(
+            "{style_start}{severity}{reset}  {location}{}  {rule}{}  {}{}  {}",
+            pad(&row.location, location_width),
+            pad(&rule_id, rule_width),
+            f.message,
+            pad(&f.message, message_width),
+            excerpt(f, safe_only, max_matched),
+        );
+        print_explanation(row.rule, style);
+    }
+}
+
+/// With `--explain-findings`, the rule's description and source, then its
+/// examples as a rewrite, each wrapped under a label. Nothing for findings
+/// of user rules, which have no documentation.
+fn print_explanation(rule: Option<&RuleDoc>, style: &ReportStyle) {
+    let (Some(doc), Some(width)) = (rule, style.explain_width) else {
+        return;
+    };
+    let why = match &doc.source {
+        Some(source) => format!("{} ({})", doc.description, source.citation),
+        None => doc.description.clone(),
+    };
+    // Paragraph breaks in an example would be lost to wrapping; mark them.
+    let example = |text: &str| text.replace("\n\n", " ¶ ");
+    let rewrite = format!(
+        "\"{}\" instead of \"{}\"",
+        example(doc.examples.good),
+        example(doc.examples.bad)
+    );
+    let indent = "    ";
+    let label_width = "why".len() + 2;
+    // Narrow terminals still get a usable column rather than one word a line.
+    let text_width = width
+        .saturating_sub(indent.len() + label_width)
+        .max(EXPLAIN_MIN_WIDTH);
+    let dim = match style.color {
+        true => Style::new().dimmed(),
+        false => Style::new(),
+    };
+    for (label, text) in [("why", why), ("try", rewrite)] {
+        for (i, line) in wrap_words(&text, text_width).iter().enumerate() {
+            let label = if i == 0 { label } else { "" };
+            anstream::eprintln!("{indent}{dim}{label:<label_width$}{line}{dim:#}");
+        }
+    }
+}
+
+/// Narrowest column `--explain-findings` wraps to.
+const EXPLAIN_MIN_WIDTH: usize = 24;
+
+/// `text` split into lines of at most `width` characters at whitespace. A
+/// word longer than `width` gets a line of its own rather than being cut.
+fn wrap_words(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    let mut line_width = 0;
+    for word in text.split_whitespace() {
+        let word_width = word.chars().count();
+        if line_width > 0 && line_width + 1 + word_width > width {
+            lines.push(std::mem::take(&mut line));
+            line_width = 0;
+        }
+        if line_width > 0 {
+            line.push(' ');
+            line_width += 1;
+        }
+        line.push_str(word);
+        line_width += word_width;
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// `'matched'`, then the suggested fix when it is not applied and any
+/// alternative rewrites.
+fn excerpt(f: &Finding, safe_only: bool, max_matched: usize) -> String {
+    let matched = display_matched(&f.matched, max_matched);
+    match f.replacement.as_deref() {
+        Some(repl) if !is_applied(f, safe_only) => {
+            format!("'{}' (suggest '{}'){}", matched, repl, consider_hint(f))
         }
+        _ => format!("'{}'{}", matched, consider_hint(f)),
+    }
+}
+
+/// `" (consider: a, b)"` for a finding with alternative rewrites, else empty.
+fn consider_hint(f: &Finding) -> String {
+    if f.suggestions.is_empty() {
+        String::new()
+    } else {
+        format!(" (consider: {})", f.suggestions.join(", "))
+    }
+}
+
+/// `matched` as shown in text output: cut to `max` chars with an ellipsis and
+/// the full length noted. Fixes always use the untruncated text.
+fn display_matched(matched: &str, max: usize) -> std::borrow::Cow<'_, str> {
+    match truncated(matched, max) {
+        Some(head) => format!("{}… ({} chars)", head, matched.chars().count()).into(),
+        None => matched.into(),
     }
 }
 
@@ -0,0 +1,347 @@
+//! SARIF 2.1.0 output, for uploading results to code scanning services.
+
+use serde::Serialize;
+
+use unai_core::rules::{registry, Finding, RuleDoc, Severity};
+
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// One input's findings.
+pub struct SarifFile<'a> {
+    /// URI of the input; `None` for stdin, whose results have no location.
+    pub uri: Option<String>,
+    pub content: &'a str,
+    pub findings: &'a [Finding],
+}
+
+#[derive(Serialize)]
+pub struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<Run>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Run {
+    tool: Tool,
+    /// Columns count characters, as in the text report.
+    column_kind: &'static str,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct Tool {
+    driver: Driver,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Driver {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    rules: Vec<Rule>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Rule {
+    id: String,
+    short_description: Text,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<String>,
+    default_configuration: Configuration,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<Properties>,
+}
+
+#[derive(Serialize)]
+struct Configuration {
+    level: &'static str,
+}
+
+#[derive(Serialize)]
+struct Properties {
+    tags: Vec<&'static str>,
+}
+
+#[derive(Serialize)]
+struct Text {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: String,
+    rule_index: usize,
+    level: &'static str,
+    message: Text,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    locations: Vec<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<Fix>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Location {
+    physical_location: PhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<Region>,
+}
+
+#[derive(Serialize, Clone)]
+struct ArtifactLocation {
+    uri: String,
+}
+
+#[derive(Serialize, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Fix {
+    description: Text,
+    artifact_changes: Vec<ArtifactChange>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ArtifactChange {
+    artifact_location: ArtifactLocation,
+    replacements: Vec<Replacement>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Replacement {
+    deleted_region: Region,
+    inserted_content: Text,
+}
+
+/// SARIF level for a severity.
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical | Severity::High => "error",
+        Severity::Medium => "warning",
+        Severity::Low => "note",
+    }
+}
+
+fn rule(doc: &RuleDoc) -> Rule {
+    Rule {
+        id: doc.id.clone(),
+        short_description: Text {
+            text: doc.description.clone(),
+        },
+        help_uri: doc.doc_url(),
+        default_configuration: Configuration {
+            level: level(doc.severity),
+        },
+        properties: (!doc.tags.is_empty()).then(|| Properties {
+            tags: doc.tags.clone(),
+        }),
+    }
+}
+
+/// Descriptor for a rule outside the registry, from the first finding it
+/// reported.
+fn extra_rule(f: &Finding) -> Rule {
+    Rule {
+        id: f.rule_id.clone(),
+        short_description: Text {
+            text: match f.rule_id.starts_with("user/") {
+                true => "Pattern from the [[rules]] in unai.toml".to_string(),
+                false => f.message.clone(),
+            },
+        },
+        help_uri: None,
+        default_configuration: Configuration {
+            level: level(f.severity),
+        },
+        properties: None,
+    }
+}
+
+/// The span of `f` in `lines` with 1-based character columns, the end
+/// column just past the match. `None` for a finding about the whole input.
+fn region(lines: &[&str], f: &Finding) -> Option<Region> {
+    if f.line == 0 {
+        return None;
+    }
+    let column = |line: usize, byte: usize| {
+        lines
+            .get(line - 1)
+            .and_then(|l| l.get(..byte))
+            .map_or(byte, |before| before.chars().count())
+            + 1
+    };
+    Some(Region {
+        start_line: f.line,
+        start_column: column(f.line, f.col),
+        end_line: f.last_line(),
+        end_column: column(f.last_line(), f.end_col()),
+    })
+}
+
+/// A SARIF log with one run over `files`. The driver lists every built-in
+/// rule, then each other rule id that reported a finding, such as the
+/// `[[rules]]` in unai.toml; findings with a replacement carry it as a fix.
+pub fn sarif_log(files: &[SarifFile]) -> SarifLog {
+    let docs = registry();
+    let mut rules: Vec<Rule> = docs.iter().map(rule).collect();
+
+    let mut results = Vec::new();
+    for file in files {
+        let lines: Vec<&str> = file.content.lines().collect();
+        for f in file.findings {
+            let rule_index = match rules.iter().position(|r| r.id == f.rule_id) {
+                Some(index) => index,
+                None => {
+                    rules.push(extra_rule(f));
+                    rules.len() - 1
+                }
+            };
+            let artifact = file.uri.clone().map(|uri| ArtifactLocation { uri });
+            let region = region(&lines, f);
+            let fixes = match (&artifact, region, &f.replacement) {
+                (Some(artifact), Some(region), Some(replacement)) => vec![Fix {
+                    description: Text {
+                        text: match replacement.as_str() {
+                            "" => format!("Remove '{}'", f.matched),
+                            _ => format!("Replace with '{}'", replacement),
+                        },
+                    },
+                    artifact_changes: vec![ArtifactChange {
+                        artifact_location: artifact.clone(),
+                        replacements: vec![Replacement {
+                            deleted_region: region,
+                            inserted_content: Text {
+                                text: replacement.clone(),
+                            },
+                        }],
+                    }],
+                }],
+                _ => Vec::new(),
+            };
+            results.push(SarifResult {
+                rule_id: rules[rule_index].id.clone(),
+                rule_index,
+                level: level(f.severity),
+                message: Text {
+                    text: f.message.clone(),
+                },
+                locations: artifact
+                    .map(|artifact_location| Location {
+                        physical_location: PhysicalLocation {
+                            artifact_location,
+                            region,
+                        },
+                    })
+                    .into_iter()
+                    .collect(),
+                fixes,
+            });
+        }
+    }
+
+    SarifLog {
+        schema: SCHEMA,
+        version: "2.1.0",
+        runs: vec![Run {
+            tool: Tool {
+                driver: Driver {
+                    name: "unai",
+                    version: env!("CARGO_PKG_VERSION"),
+                    information_uri: env!("CARGO_PKG_REPOSITORY"),
+                    rules,
+                },
+            },
+            column_kind: "unicodeCodePoints",
+            results,
+        }],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unai_core::rules::{apply_text_rules, Category};
+
+    fn log_for(content: &str, findings: &[Finding]) -> serde_json::Value {
+        let file = SarifFile {
+            uri: Some("docs/draft.md".to_string()),
+            content,
+            findings,
+        };
+        serde_json::to_value(sarif_log(&[file])).unwrap()
+    }
+
+    #[test]
+    fn results_reference_rules_with_levels_and_fixes() {
+        let content = "Café, we delve.\n";
+        let log = log_for(content, &apply_text_rules(content));
+        let run = &log["runs"][0];
+        assert_eq!(log["version"], "2.1.0");
+        assert_eq!(run["tool"]["driver"]["version"], env!("CARGO_PKG_VERSION"));
+        let result = &run["results"][0];
+        let index = result["ruleIndex"].as_u64().unwrap() as usize;
+        assert_eq!(result["ruleId"], "text/delve");
+        assert_eq!(run["tool"]["driver"]["rules"][index]["id"], "text/delve");
+        assert_eq!(result["level"], "error");
+        let region = &result["locations"][0]["physicalLocation"]["region"];
+        assert_eq!(
+            (region["startColumn"].as_u64(), region["endColumn"].as_u64()),
+            (Some(10), Some(15))
+        );
+        let change = &result["fixes"][0]["artifactChanges"][0];
+        assert_eq!(change["artifactLocation"]["uri"], "docs/draft.md");
+        assert_eq!(
+            change["replacements"][0]["insertedContent"]["text"],
+            "explore"
+        );
+    }
+
+    #[test]
+    fn user_findings_and_stdin_results() {
+        let user = Finding {
+            line: 1,
+            matched: "synergy".to_string(),
+            message: "Custom rule: 'synergy'".to_string(),
+            rule_id: "user/synergy".to_string(),
+            category: Category::User,
+            ..Default::default()
+        };
+        let file = SarifFile {
+            uri: None,
+            content: "synergy\n",
+            findings: std::slice::from_ref(&user),
+        };
+        let log = serde_json::to_value(sarif_log(&[file])).unwrap();
+        let result = &log["runs"][0]["results"][0];
+        assert_eq!(result["ruleId"], "user/synergy");
+        assert_eq!(result["level"], "note");
+        assert!(result.get("locations").is_none());
+        let rules = log["runs"][0]["tool"]["driver"]["rules"]
+            .as_array()
+            .unwrap();
+        let ids: std::collections::HashSet<&str> =
+            rules.iter().map(|r| r["id"].as_str().unwrap()).collect();
+        assert_eq!(ids.len(), rules.len(), "rule ids must be unique");
+    }
+}
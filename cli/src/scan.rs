@@ -0,0 +1,175 @@
+//! Expansion of the command-line paths into the files a run reads.
+
+use std::fs;
+use std::path::Path;
+
+use globset::GlobSet;
+
+use crate::error::{Result, UnaiError};
+use unai_core::config::{glob_set, slash_path};
+
+/// `--include` and `--exclude` globs, applied to files found in a directory.
+pub struct PathFilter {
+    include: Option<GlobSet>,
+    exclude: GlobSet,
+}
+
+impl PathFilter {
+    pub fn new(include: &[String], exclude: &[String]) -> Result<PathFilter> {
+        let compile = |patterns: &[String], flag: &str| {
+            glob_set(patterns).map_err(|e| UnaiError::InvalidArgs(format!("{e} in {flag}")))
+        };
+        Ok(PathFilter {
+            include: match include {
+                [] => None,
+                patterns => Some(compile(patterns, "--include")?),
+            },
+            exclude: compile(exclude, "--exclude")?,
+        })
+    }
+
+    /// Whether to keep a file, given its path as shown and relative to the
+    /// directory argument it was found under. A glob matching either counts.
+    fn keeps(&self, shown: &str, relative: &str) -> bool {
+        let matches = |set: &GlobSet| {
+            [shown, relative]
+                .iter()
+                .any(|p| set.is_match(Path::new(slash_path(p).as_ref())))
+        };
+        self.include.as_ref().is_none_or(matches) && !matches(&self.exclude)
+    }
+}
+
+/// Whether the paths make a multi-file scan rather than a single input.
+pub fn is_scan(paths: &[String]) -> bool {
+    match paths {
+        [] => false,
+        [path] => Path::new(path).is_dir(),
+        _ => true,
+    }
+}
+
+/// The files to read for `paths`, in order. A file is taken as given; a
+/// directory is walked recursively, entries sorted by name, keeping the files
+/// `filter` lets through. Hidden entries and symlinked directories are not
+/// walked into.
+pub fn collect_files(paths: &[String], filter: &PathFilter) -> Result<Vec<String>> {
+    let mut files = Vec::new();
+    for path in paths {
+        if Path::new(path).is_dir() {
+            walk(Path::new(path), Path::new(""), filter, &mut files)?;
+        } else {
+            files.push(path.clone());
+        }
+    }
+    Ok(files)
+}
+
+fn walk(root: &Path, relative: &Path, filter: &PathFilter, files: &mut Vec<String>) -> Result<()> {
+    let dir = root.join(relative);
+    let read_error = |source| UnaiError::FileRead {
+        path: dir.clone(),
+        source,
+    };
+    let mut entries = fs::read_dir(&dir)
+        .map_err(read_error)?
+        .collect::<std::io::Result<Vec<_>>>()
+        .map_err(read_error)?;
+    entries.sort_by_key(|e| e.file_name());
+    for entry in entries {
+        let name = entry.file_name();
+        let Some(name) = name.to_str() else {
+            eprintln!(
+                "unai: warning: skipping '{}': path is not valid UTF-8",
+                entry.path().display()
+            );
+            continue;
+        };
+        if name.starts_with('.') {
+            continue;
+        }
+        let relative = relative.join(name);
+        let file_type = entry.file_type().map_err(read_error)?;
+        if file_type.is_dir() {
+            walk(root, &relative, filter, files)?;
+        } else if file_type.is_file() || entry.path().is_file() {
+            let shown = root.join(&relative);
+            let (Some(shown), Some(rel)) = (shown.to_str(), relative.to_str()) else {
+                continue;
+            };
+            if filter.keeps(shown, rel) {
+                files.push(shown.to_string());
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree(files: &[&str]) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        for file in files {
+            let path = dir.path().join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "text\n").unwrap();
+        }
+        dir
+    }
+
+    fn collect(root: &Path, include: &[&str], exclude: &[&str]) -> Vec<String> {
+        let strings = |globs: &[&str]| globs.iter().map(|g| g.to_string()).collect::<Vec<_>>();
+        let filter = PathFilter::new(&strings(include), &strings(exclude)).unwrap();
+        let root = root.to_str().unwrap().to_string();
+        collect_files(std::slice::from_ref(&root), &filter)
+            .unwrap()
+            .into_iter()
+            .map(|f| slash_path(&f[root.len() + 1..]).into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn walks_directories_sorted_and_skips_hidden_entries() {
+        let dir = tree(&["b.md", "a/z.txt", "a/b.rs", ".git/config", "a/.hidden.md"]);
+        assert_eq!(collect(dir.path(), &[], &[]), ["a/b.rs", "a/z.txt", "b.md"]);
+    }
+
+    #[test]
+    fn include_and_exclude_match_shown_or_relative_path() {
+        let dir = tree(&["docs/guide.md", "docs/drafts/old.md", "src/main.rs"]);
+        assert_eq!(
+            collect(dir.path(), &["*.md"], &[]),
+            ["docs/drafts/old.md", "docs/guide.md"]
+        );
+        assert_eq!(
+            collect(dir.path(), &["*.md"], &["docs/drafts/**"]),
+            ["docs/guide.md"]
+        );
+        assert_eq!(collect(dir.path(), &[], &["**/*.md"]), ["src/main.rs"]);
+    }
+
+    #[test]
+    fn explicit_files_are_kept_and_bad_globs_rejected() {
+        let filter = PathFilter::new(&[], &["*.md".to_string()]).unwrap();
+        assert_eq!(
+            collect_files(&["notes.md".to_string()], &filter).unwrap(),
+            ["notes.md"]
+        );
+        let err = PathFilter::new(&["[abc".to_string()], &[])
+            .err()
+            .expect("unclosed class is rejected");
+        assert!(err.to_string().contains("--include"), "got: {err}");
+    }
+
+    #[test]
+    fn one_directory_or_several_paths_is_a_scan() {
+        let dir = tree(&["a.md"]);
+        let root = dir.path().to_str().unwrap().to_string();
+        assert!(is_scan(std::slice::from_ref(&root)));
+        assert!(!is_scan(&[format!("{root}/a.md")]));
+        assert!(is_scan(&[format!("{root}/a.md"), format!("{root}/a.md")]));
+        assert!(!is_scan(&[]));
+    }
+}
@@ -1,4 +1,6 @@
 use super::*;
+use unai_core::gather_findings;
+use unai_core::rules::{apply_text_rules, Deadline, LineContext};
 
 // RED → GREEN: pipeline() isolated from rendering — verifies findings are returned
 // without any output side effects.
@@ -9,19 +11,8 @@ fn pipeline_returns_findings_without_rendering() {
     std::fs::write(&input_path, "We should utilize this.\n").unwrap();
 
     let args = Args {
-        file: Some(input_path.to_str().unwrap().to_string()),
-        mode: ModeArg::Text,
-        rules: vec![],
-        dry_run: false,
-        diff: false,
-        annotate: false,
-        report: false,
-        min_severity: MinSeverityArg::Low,
-        format: FormatArg::Text,
-        output: None,
-        config: None,
-        fail: false,
-        color: ColorArg::Never,
+        files: vec![input_path.to_str().unwrap().to_string()],
+        ..default_args(FormatArg::Text)
     };
 
     let result = pipeline(&args).unwrap();
@@ -47,19 +38,8 @@ fn pipeline_no_findings_on_clean_input() {
     std::fs::write(&input_path, "The sky is blue.\n").unwrap();
 
     let args = Args {
-        file: Some(input_path.to_str().unwrap().to_string()),
-        mode: ModeArg::Text,
-        rules: vec![],
-        dry_run: false,
-        diff: false,
-        annotate: false,
-        report: false,
-        min_severity: MinSeverityArg::Low,
-        format: FormatArg::Text,
-        output: None,
-        config: None,
-        fail: false,
-        color: ColorArg::Never,
+        files: vec![input_path.to_str().unwrap().to_string()],
+        ..default_args(FormatArg::Text)
     };
 
     let result = pipeline(&args).unwrap();
@@ -69,6 +49,31 @@ fn pipeline_no_findings_on_clean_input() {
     );
 }
 
+// "In conclusion" is a text-only rule; sycophantic openers run in every mode.
+#[test]
+fn pipeline_skips_rules_outside_their_modes() {
+    let dir = tempfile::tempdir().unwrap();
+    let input_path = dir.path().join("COMMIT_EDITMSG");
+    std::fs::write(
+        &input_path,
+        "Add cache warmup\n\nIn conclusion, the cache stays warm. Certainly!\n",
+    )
+    .unwrap();
+    let args = Args {
+        files: vec![input_path.to_str().unwrap().to_string()],
+        mode: ModeArg::Auto,
+        ..default_args(FormatArg::Text)
+    };
+    let result = pipeline(&args).unwrap();
+    assert_eq!(result.mode, Mode::CommitMsg);
+    let ids: Vec<&str> = result.findings.iter().map(|f| f.rule_id.as_str()).collect();
+    assert!(ids.contains(&"text/certainly"), "{ids:?}");
+    assert!(!ids.contains(&"text/in-conclusion"), "{ids:?}");
+
+    let prose = apply_text_rules("In conclusion, the cache stays warm.\n");
+    assert_eq!(prose[0].rule_id, "text/in-conclusion");
+}
+
 #[test]
 #[cfg(unix)]
 fn write_output_refuses_symlink() {
@@ -83,10 +88,49 @@ fn write_output_refuses_symlink() {
     assert_eq!(std::fs::read_to_string(&target).unwrap(), "original");
 }
 
+#[test]
+#[cfg(windows)]
+fn write_output_refuses_symlink() {
+    use std::os::windows::fs::symlink_file;
+    let dir = tempfile::tempdir().unwrap();
+    let target = dir.path().join("target.txt");
+    std::fs::write(&target, "original").unwrap();
+    let link = dir.path().join("link.txt");
+    // Creating symlinks needs Developer Mode or admin rights on Windows.
+    if symlink_file(&target, &link).is_err() {
+        return;
+    }
+    let result = write_output("content", Some(link.to_str().unwrap()));
+    assert!(result.is_err(), "should refuse to write through symlink");
+    assert_eq!(std::fs::read_to_string(&target).unwrap(), "original");
+}
+
+#[test]
+fn write_output_replaces_the_file_and_leaves_no_temp() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("findings.json");
+    std::fs::write(&path, "old").unwrap();
+    write_output("new", Some(path.to_str().unwrap())).unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "new");
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+}
+
+#[test]
+fn read_input_strips_bom() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("notes.md");
+    std::fs::write(&path, "\u{FEFF}Delve into it.\n").unwrap();
+    let (content, filename, bom) = read_input(Some(path.to_str().unwrap())).unwrap();
+    assert_eq!(content, "Delve into it.\n");
+    assert_eq!(filename.as_deref(), Some("notes.md"));
+    assert!(bom);
+    assert_eq!(with_bom(content, bom), "\u{FEFF}Delve into it.\n");
+}
+
 #[test]
 fn resolve_mode_explicit_text() {
     assert_eq!(
-        resolve_mode(&ModeArg::Text, None, "fn main() {}"),
+        resolve_mode(&ModeArg::Text, None, "fn main() {}").0,
         Mode::Text
     );
 }
@@ -94,7 +138,7 @@ fn resolve_mode_explicit_text() {
 #[test]
 fn resolve_mode_explicit_code() {
     assert_eq!(
-        resolve_mode(&ModeArg::Code, None, "hello world"),
+        resolve_mode(&ModeArg::Code, None, "hello world").0,
         Mode::Code
     );
 }
@@ -102,7 +146,7 @@ fn resolve_mode_explicit_code() {
 #[test]
 fn resolve_mode_auto_code_by_filename() {
     assert_eq!(
-        resolve_mode(&ModeArg::Auto, Some("main.rs"), "hello"),
+        resolve_mode(&ModeArg::Auto, Some("main.rs"), "hello").0,
         Mode::Code
     );
 }
@@ -110,7 +154,7 @@ fn resolve_mode_auto_code_by_filename() {
 #[test]
 fn resolve_mode_auto_text_by_content() {
     assert_eq!(
-        resolve_mode(&ModeArg::Auto, None, "just prose here, nothing to see"),
+        resolve_mode(&ModeArg::Auto, None, "just prose here, nothing to see").0,
         Mode::Text
     );
 }
@@ -128,6 +172,33 @@ fn parse_invalid_rule_errors() {
     assert!(result.is_err());
 }
 
+#[test]
+fn inline_mode_wins_over_detection_but_not_over_the_flag() {
+    let code = Mode::Code;
+    let auto = Args {
+        mode: ModeArg::Auto,
+        ..default_args(FormatArg::Text)
+    };
+    assert_eq!(
+        input_mode(&auto, None, None, Some("notes.md"), "Text.", Some(&code)),
+        (Mode::Code, DetectionReason::Inline)
+    );
+    let text = default_args(FormatArg::Text);
+    assert_eq!(
+        input_mode(&text, None, None, Some("notes.md"), "Text.", Some(&code)),
+        (Mode::Text, DetectionReason::Flag)
+    );
+}
+
+#[test]
+fn parse_timeout_takes_positive_seconds() {
+    assert_eq!(parse_timeout("0.25"), Ok(Duration::from_millis(250)));
+    assert_eq!(parse_timeout("3"), Ok(Duration::from_secs(3)));
+    assert!(parse_timeout("0").is_err());
+    assert!(parse_timeout("-1").is_err());
+    assert!(parse_timeout("soon").is_err());
+}
+
 #[test]
 fn end_to_end_text_clean() {
     let input = "We should utilize this to facilitate growth.\n";
@@ -141,29 +212,198 @@ fn end_to_end_text_clean() {
     assert!(cleaned.ends_with('\n'));
 }
 
+/// `gather_findings` with no explicit code rules, no filename, the default
+/// structural limits, no disabled rules and no deadline.
+fn gather(content: &str, mode: Mode) -> Vec<Finding> {
+    let lines = LineContext::split(content);
+    gather_findings(
+        content,
+        &lines,
+        &mode,
+        &effective_code_rules(&[], None),
+        &Default::default(),
+        &HashSet::new(),
+        Deadline::NONE,
+    )
+}
+
 #[test]
-fn gather_findings_commit_msg_fires_commit_rules() {
-    let findings = gather_findings("wip", &Mode::CommitMsg, &[], None);
-    assert!(
-        findings.iter().any(|f| f.message.contains("Vague commit")),
-        "commit rules should fire for CommitMsg mode"
+fn json_reports_region_when_known() {
+    let findings = gather("# --- Setup ---\nlet dataManager = 1;\n", Mode::Code);
+    let report = build_json_report(&findings, &[], false, 0, &Mode::Code, None, 200);
+    let value = serde_json::to_value(&report).unwrap();
+    let findings = value["findings"].as_array().unwrap();
+    let header = findings.iter().find(|f| f["line"] == 1).unwrap();
+    assert_eq!(header["region"], "code-comment");
+    let naming = findings.iter().find(|f| f["line"] == 2).unwrap();
+    assert!(naming.get("region").is_none());
+}
+
+fn two_line_deletion() -> Finding {
+    Finding {
+        line: 2,
+        end_line: Some(3),
+        matched: "I hope this helps.\nLet me know!".to_string(),
+        message: "Chatbot closer".to_string(),
+        replacement: Some(String::new()),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn multi_line_finding_through_clean_json_and_annotate() {
+    let content = "Done.\nI hope this helps.\nLet me know!\n";
+    let f = two_line_deletion();
+    assert_eq!(clean(content, std::slice::from_ref(&f)), "Done.\n");
+
+    let report = build_json_report(
+        std::slice::from_ref(&f),
+        &[],
+        false,
+        0,
+        &Mode::Text,
+        None,
+        200,
     );
+    let value = serde_json::to_value(&report).unwrap();
+    assert_eq!(value["findings"][0]["line"], 2);
+    assert_eq!(value["findings"][0]["end_line"], 3);
+    assert_eq!(value["findings"][0]["end_column"], 12);
+
+    let notes = annotations(&[f]);
+    assert_eq!(notes[&2], vec!["^ (remove)", "Chatbot closer"]);
+    assert_eq!(notes[&3], vec!["^ (continued from line 2)"]);
+    assert!(!notes.contains_key(&1));
 }
 
 #[test]
-fn gather_findings_commit_msg_fires_both_text_and_commit_rules() {
-    let findings = gather_findings("Added utilize to the codebase", &Mode::CommitMsg, &[], None);
-    assert!(
-        findings
-            .iter()
-            .any(|f| f.matched.to_lowercase().contains("utilize")),
-        "text rules should fire in CommitMsg mode"
+fn single_line_json_omits_end_line() {
+    let findings = apply_text_rules("We utilize it.");
+    let report = build_json_report(&findings, &[], false, 0, &Mode::Text, None, 200);
+    let value = serde_json::to_value(&report).unwrap();
+    assert!(value["findings"][0].get("end_line").is_none());
+    assert_eq!(value["findings"][0]["end_column"], 10);
+}
+
+#[test]
+fn suggestions_in_json_but_not_applied() {
+    let content = "A robust and seamless setup.\n";
+    let findings = apply_text_rules(content);
+    let report = build_json_report(&findings, &[], false, 0, &Mode::Text, None, 200);
+    let value = serde_json::to_value(&report).unwrap();
+    let suggestions: Vec<&serde_json::Value> = value["findings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| &f["suggestions"])
+        .collect();
+    assert_eq!(suggestions[0], &serde_json::json!(["reliable", "sturdy"]));
+    assert_eq!(
+        suggestions[1],
+        &serde_json::json!(["smooth", "transparent"])
     );
-    assert!(
-        findings
-            .iter()
-            .any(|f| f.message.contains("imperative mood")),
-        "commit past-tense rule should fire in CommitMsg mode"
+    assert_eq!(clean(content, &findings), content);
+}
+
+fn output_policy(final_newline: FinalNewline, line_endings: LineEndings) -> config::OutputConfig {
+    config::OutputConfig {
+        final_newline,
+        line_endings,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn long_matched_truncated_for_display_only() {
+    let long = "X".repeat(250);
+    let content = format!("{}\n", long);
+    let f = Finding {
+        line: 1,
+        col: 0,
+        matched: long.clone(),
+        message: "Whole line".to_string(),
+        replacement: Some(String::new()),
+        ..Default::default()
+    };
+
+    let shown = display_matched(&f.matched, 200);
+    assert_eq!(shown, format!("{}… (250 chars)", "X".repeat(200)));
+    assert_eq!(display_matched(&f.matched, 0), long);
+    assert_eq!(display_matched("é".repeat(3).as_str(), 2), "éé… (3 chars)");
+
+    let report = build_json_report(
+        std::slice::from_ref(&f),
+        &[],
+        false,
+        0,
+        &Mode::Text,
+        None,
+        200,
+    );
+    let json = serde_json::to_value(&report).unwrap();
+    let finding = &json["findings"][0];
+    assert_eq!(finding["matched"], format!("{}…", "X".repeat(200)));
+    assert_eq!(finding["matched_len"], 250);
+
+    // Fixes still see the full text.
+    assert_eq!(clean(&content, &[f]), "\n");
+}
+
+#[test]
+fn normalize_output_final_newline_modes() {
+    use FinalNewline::*;
+    let lf = LineEndings::Preserve;
+    assert_eq!(
+        normalize_output("a", "a", &output_policy(Preserve, lf)),
+        "a"
+    );
+    assert_eq!(
+        normalize_output("a", "a", &output_policy(Always, lf)),
+        "a\n"
+    );
+    assert_eq!(
+        normalize_output("a\n", "a\n", &output_policy(Always, lf)),
+        "a\n"
+    );
+    assert_eq!(
+        normalize_output("a\n", "a\n", &output_policy(Never, lf)),
+        "a"
+    );
+    assert_eq!(normalize_output("", "", &output_policy(Always, lf)), "");
+}
+
+#[test]
+fn normalize_output_line_ending_modes() {
+    use LineEndings::*;
+    let keep = FinalNewline::Preserve;
+    // clean() keeps each line's ending; preserve leaves them as they are.
+    assert_eq!(
+        normalize_output("a\r\nb\r\n", "a\r\nb\r\n", &output_policy(keep, Preserve)),
+        "a\r\nb\r\n"
+    );
+    assert_eq!(
+        normalize_output(
+            "a\r\nb",
+            "a\r\nb",
+            &output_policy(FinalNewline::Always, Preserve)
+        ),
+        "a\r\nb\r\n"
+    );
+    assert_eq!(
+        normalize_output("a\nb\r\n", "a\nb\r\n", &output_policy(keep, Preserve)),
+        "a\nb\r\n"
+    );
+    assert_eq!(
+        normalize_output("a\r\nb", "a\r\nb", &output_policy(keep, Lf)),
+        "a\nb"
+    );
+    assert_eq!(
+        normalize_output("a\nb\r\n", "a\nb\r\n", &output_policy(keep, Crlf)),
+        "a\r\nb\r\n"
+    );
+    assert_eq!(
+        normalize_output("a\r\n", "a\r\n", &output_policy(FinalNewline::Never, Crlf)),
+        "a"
     );
 }
 
@@ -199,6 +439,7 @@ fn make_finding(severity: Severity) -> Finding {
         message: "test".to_string(),
         replacement: None,
         severity,
+        ..Default::default()
     }
 }
 
@@ -214,32 +455,182 @@ fn count_by_severity_counts_correctly() {
     assert_eq!(count_by_severity(&findings, Severity::Low), 0);
 }
 
+#[test]
+fn safe_fixes_withholds_caution_replacements() {
+    let input = "We should utilize a comprehensive plan.\n";
+    let findings = apply_text_rules(input);
+    let cleaned = clean(input, &fixes_to_apply(&findings, true));
+    assert_eq!(cleaned, "We should use a comprehensive plan.\n");
+    let all = clean(input, &fixes_to_apply(&findings, false));
+    assert_eq!(all, "We should use a thorough plan.\n");
+}
+
+#[test]
+fn json_exposes_fix_safety_only_for_fixable_findings() {
+    let findings = apply_text_rules("utilize a comprehensive and meticulous plan");
+    let report = build_json_report(&findings, &[], false, 0, &Mode::Text, None, 200);
+    let value = serde_json::to_value(&report).unwrap();
+    let safety = |matched: &str| {
+        value["findings"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|f| f["matched"] == matched)
+            .map(|f| f["fix_safety"].clone())
+            .unwrap()
+    };
+    assert_eq!(safety("utilize"), "safe");
+    assert_eq!(safety("comprehensive"), "caution");
+    assert!(safety("meticulous").is_null());
+}
+
+#[test]
+fn json_reports_extended_hedge_span() {
+    let findings = apply_text_rules("It is worth noting that the cache is shared.");
+    let report = build_json_report(&findings, &[], false, 0, &Mode::Text, None, 200);
+    let f = report
+        .findings
+        .iter()
+        .find(|f| f.message.contains("worth noting"))
+        .unwrap();
+    assert_eq!(f.matched, "It is worth noting that ");
+    assert_eq!(f.column, 0);
+    assert_eq!(f.end_column, 24);
+    assert_eq!(f.replacement.as_deref(), Some(""));
+}
+
+#[test]
+fn pipeline_counts_suppressions_by_cause() {
+    let dir = tempfile::tempdir().unwrap();
+    let cfg_path = dir.path().join("unai.toml");
+    std::fs::write(&cfg_path, "version = 1\n[ignore]\nwords = [\"robust\"]\n").unwrap();
+    let input_path = dir.path().join("input.txt");
+    std::fs::write(
+        &input_path,
+        "A robust plan.\n\
+         <!-- unai-ignore -->\n\
+         Let me delve into it and utilize it.\n\
+         <!-- /unai-ignore -->\n\
+         Moreover, it works.\n",
+    )
+    .unwrap();
+    let args = Args {
+        files: vec![input_path.to_str().unwrap().to_string()],
+        config: Some(cfg_path.to_str().unwrap().to_string()),
+        min_severity: Some(MinSeverityArg::Medium),
+        ..default_args(FormatArg::Json)
+    };
+
+    let result = pipeline(&args).unwrap();
+    let count = |cause| count_suppressed(&result.suppressed, cause);
+    assert_eq!(count(SuppressCause::IgnoreWord), 1);
+    assert_eq!(count(SuppressCause::IgnoreDirective), 2);
+    assert_eq!(count(SuppressCause::MinSeverity), 1);
+    assert!(result.findings.is_empty());
+    assert_eq!(
+        suppressed_note(&result.suppressed).unwrap(),
+        "4 finding(s) suppressed: 2 ignore-directive, 1 ignore-word, 1 min-severity"
+    );
+
+    let report = build_json_report(
+        &result.findings,
+        &result.suppressed,
+        true,
+        0,
+        &result.mode,
+        None,
+        200,
+    );
+    let value = serde_json::to_value(&report).unwrap();
+    assert_eq!(value["summary"]["suppressed"]["total"], 4);
+    assert_eq!(value["summary"]["suppressed"]["ignore_directive"], 2);
+    assert_eq!(value["suppressed"][0]["cause"], "ignore-word");
+}
+
+#[test]
+fn pipeline_scoped_directive_hides_only_listed_rules() {
+    let (findings, suppressed) = pipeline_text(
+        "A robust and comprehensive plan to delve. <!-- unai-ignore: text/robust, text/comprehensive -->\n",
+    );
+    let ids = |findings: &[Finding]| {
+        let mut ids: Vec<String> = findings.iter().map(|f| f.rule_id.clone()).collect();
+        ids.sort();
+        ids
+    };
+    assert_eq!(ids(&findings), ["text/delve"]);
+    assert_eq!(ids(&suppressed), ["text/comprehensive", "text/robust"]);
+}
+
 // --- Formatter dispatch (OCP) ---
 
 fn make_pipeline_result(content: &str, findings: Vec<Finding>, mode: Mode) -> PipelineResult {
     PipelineResult {
         findings,
+        suppressed: vec![],
         mode,
         content: content.to_string(),
+        path: None,
         filename: None,
+        failing: 0,
+        fails: None,
+        output: Default::default(),
+        input: Default::default(),
+        run: RunInfo {
+            config_path: None,
+            detection: DetectionReason::Flag,
+            enabled_rules: 0,
+            total_rules: 0,
+            started: Instant::now(),
+        },
+        timed_out: false,
+        bom: false,
     }
 }
 
 fn default_args(format: FormatArg) -> Args {
     Args {
-        file: None,
+        files: vec![],
+        include: vec![],
+        exclude: vec![],
         mode: ModeArg::Text,
+        stdin_filename: None,
         rules: vec![],
         dry_run: false,
         diff: false,
         annotate: false,
+        safe_fixes: false,
+        rewrap: None,
+        scan_frontmatter: false,
+        check_strings: false,
+        fix_strings: false,
         report: false,
-        min_severity: MinSeverityArg::Low,
+        quiet: false,
+        summary: false,
+        report_style: ReportStyleArg::Aligned,
+        group_by: GroupByArg::Severity,
+        explain_findings: false,
+        top_paragraphs: 3,
+        show_suppressed: false,
+        verbose: false,
+        min_severity: None,
         format,
         output: None,
+        watch: false,
+        write: false,
         config: None,
         fail: false,
+        fail_on: None,
+        exit_by_severity: false,
+        lint_config: false,
+        fail_on_unused_suppressions: false,
+        hyperlinks: HyperlinksArg::Auto,
         color: ColorArg::Never,
+        changed_only: false,
+        diff_base: None,
+        timeout: None,
+        strict_files: false,
+        print_default_config: false,
+        command: None,
     }
 }
 
@@ -281,6 +672,30 @@ fn formatter_json_produces_valid_json() {
     );
 }
 
+#[test]
+fn formatter_json_counts_skipped_fixes() {
+    let stale: Vec<Finding> = (1..=8)
+        .map(|col| Finding {
+            line: 1,
+            col: col * 10,
+            matched: "utilize".to_string(),
+            replacement: Some("use".to_string()),
+            ..Default::default()
+        })
+        .collect();
+    let result = make_pipeline_result("utilize this\n", stale, Mode::Text);
+    let dir = tempfile::tempdir().unwrap();
+    let out_path = dir.path().join("out.json");
+    let args = Args {
+        output: Some(out_path.to_str().unwrap().to_string()),
+        ..default_args(FormatArg::Json)
+    };
+    Formatter::Json.render(result, &args).unwrap();
+    let parsed: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&out_path).unwrap()).unwrap();
+    assert_eq!(parsed["summary"]["skipped_fixes"], 8);
+}
+
 #[test]
 fn formatter_text_passthrough_on_clean_input() {
     let formatter = Formatter::Text;
@@ -320,3 +735,95 @@ fn formatter_from_args_selects_text() {
         "FormatArg::Text must map to Formatter::Text"
     );
 }
+
+#[test]
+fn rule_docs_markdown_has_a_section_per_rule() {
+    let docs = registry();
+    let md = render_rule_docs(&docs, &DocFormatArg::Markdown).unwrap();
+    assert_eq!(md.matches("\n## `").count(), docs.len());
+    assert!(md.contains("## `text/it-is-worth-noting`"));
+    assert!(md.contains("- Fix: delete the match (safe), only when followed by \"that\""));
+    assert!(md.contains("- Fix: none, flag only"));
+}
+
+#[test]
+fn file_url_is_absolute_and_encoded() {
+    let url = file_url("notes/my draft.md").unwrap();
+    assert!(url.starts_with("file:///"), "{url}");
+    assert!(url.ends_with("/notes/my%20draft.md"), "{url}");
+}
+
+#[test]
+fn wrap_words_fits_width_and_keeps_long_words_whole() {
+    assert_eq!(
+        wrap_words("We look at  the results.", 11),
+        ["We look at", "the", "results."]
+    );
+    assert_eq!(
+        wrap_words("see https://example.com/long/path now", 10),
+        ["see", "https://example.com/long/path", "now"]
+    );
+    assert_eq!(wrap_words("Café délice", 6), ["Café", "délice"]);
+    assert!(wrap_words("  ", 10).is_empty());
+}
+
+/// Findings from piping `content` through the pipeline, and the suppressed ones.
+fn pipeline_text(content: &str) -> (Vec<Finding>, Vec<Finding>) {
+    let dir = tempfile::tempdir().unwrap();
+    let input_path = dir.path().join("input.txt");
+    std::fs::write(&input_path, content).unwrap();
+    let args = Args {
+        files: vec![input_path.to_str().unwrap().to_string()],
+        ..default_args(FormatArg::Json)
+    };
+    let result = pipeline(&args).unwrap();
+    let suppressed = result.suppressed.into_iter().map(|s| s.finding).collect();
+    (result.findings, suppressed)
+}
+
+/// An 85-word paragraph that clears the structural rules' length minimums,
+/// then a dense wrapped paragraph for `wrap` to surround.
+fn connector_doc(wrap: impl Fn(&str) -> String) -> String {
+    let lead = "The build server compiles every branch overnight. Reviewers read the logs \
+        each morning, looking for flaky tests, slow steps, and warnings that crept in since \
+        the last release. Most days nothing stands out. When a job fails twice in a row, the \
+        on-call engineer opens a ticket, tags the owning team, and links the failing run so \
+        the history stays in one place. Caches are wiped weekly. Disk usage on the runners \
+        is graphed, and an alert fires when any of them passes ninety percent.";
+    let dense = "Moreover, this is important.\n\
+                 Furthermore, we note that.\n\
+                 Additionally, as a result, the data shows.\n\
+                 Consequently, we conclude.";
+    format!("{lead}\n\n{}\n", wrap(dense))
+}
+
+#[test]
+fn structural_finding_covers_its_paragraph() {
+    let (findings, _) = pipeline_text(&connector_doc(str::to_string));
+    let dense = findings
+        .iter()
+        .find(|f| f.rule_id == "structural/connector-density")
+        .unwrap();
+    assert_eq!((dense.line, dense.last_line()), (3, 6));
+}
+
+#[test]
+fn html_ignore_block_hides_structural_finding() {
+    let doc = connector_doc(|p| format!("<!-- unai-ignore -->\n{p}\n<!-- /unai-ignore -->"));
+    let (findings, suppressed) = pipeline_text(&doc);
+    assert!(findings.is_empty(), "{findings:?}");
+    assert!(suppressed
+        .iter()
+        .any(|f| f.rule_id == "structural/connector-density"));
+}
+
+#[test]
+fn next_line_ignore_before_paragraph_hides_structural_finding() {
+    let doc = connector_doc(|p| format!("# unai-ignore-next-line\n{p}"));
+    let (findings, suppressed) = pipeline_text(&doc);
+    let ids: Vec<&str> = findings.iter().map(|f| f.rule_id.as_str()).collect();
+    assert_eq!(ids, ["text/furthermore"]);
+    assert!(suppressed
+        .iter()
+        .any(|f| f.rule_id == "structural/connector-density"));
+}
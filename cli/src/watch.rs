@@ -0,0 +1,115 @@
+//! Change detection for `--watch`, by polling file modification times.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// How often the watched files are looked at. A burst of saves settles once
+/// one poll sees nothing new, so this is also the debounce window.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Modification time and length of each watched path; `None` when the path
+/// could not be read.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Snapshot(BTreeMap<String, Option<(SystemTime, u64)>>);
+
+impl Snapshot {
+    pub fn take(paths: &[String]) -> Snapshot {
+        Snapshot(paths.iter().map(|p| (p.clone(), stamp(p))).collect())
+    }
+
+    /// Readable paths that are new or modified since `earlier`, in order.
+    pub fn changed_since(&self, earlier: &Snapshot) -> Vec<String> {
+        self.0
+            .iter()
+            .filter(|(path, stamp)| stamp.is_some() && earlier.0.get(*path) != Some(stamp))
+            .map(|(path, _)| path.clone())
+            .collect()
+    }
+}
+
+fn stamp(path: &str) -> Option<(SystemTime, u64)> {
+    let meta = fs::metadata(path).ok()?;
+    Some((meta.modified().ok()?, meta.len()))
+}
+
+/// Block until a snapshot from `take` differs from `last` and then stays the
+/// same for one more `poll`, and return that settled snapshot.
+pub fn wait_for_change(
+    last: &Snapshot,
+    poll: Duration,
+    mut take: impl FnMut() -> Snapshot,
+) -> Snapshot {
+    let mut seen = loop {
+        thread::sleep(poll);
+        let current = take();
+        if current != *last {
+            break current;
+        }
+    };
+    loop {
+        thread::sleep(poll);
+        let current = take();
+        if current == seen {
+            return current;
+        }
+        seen = current;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(secs: u64) -> Option<(SystemTime, u64)> {
+        Some((SystemTime::UNIX_EPOCH + Duration::from_secs(secs), 10))
+    }
+
+    fn snapshot(entries: &[(&str, Option<(SystemTime, u64)>)]) -> Snapshot {
+        Snapshot(entries.iter().map(|(p, s)| (p.to_string(), *s)).collect())
+    }
+
+    #[test]
+    fn changed_since_lists_new_and_modified_paths() {
+        let before = snapshot(&[("a.md", at(1)), ("b.md", at(1)), ("gone.md", at(1))]);
+        let after = snapshot(&[("a.md", at(1)), ("b.md", at(2)), ("c.md", at(1))]);
+        assert_eq!(after.changed_since(&before), ["b.md", "c.md"]);
+        assert!(after.changed_since(&after).is_empty());
+    }
+
+    #[test]
+    fn changed_since_skips_unreadable_paths() {
+        let before = snapshot(&[("a.md", at(1))]);
+        let after = snapshot(&[("a.md", None)]);
+        assert!(after.changed_since(&before).is_empty());
+        assert_ne!(after, before);
+    }
+
+    #[test]
+    fn take_sees_a_rewrite() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("doc.md").to_string_lossy().into_owned();
+        fs::write(&path, "one\n").unwrap();
+        let paths = [path.clone()];
+        let before = Snapshot::take(&paths);
+        fs::write(&path, "one two\n").unwrap();
+        assert_eq!(Snapshot::take(&paths).changed_since(&before), [path]);
+    }
+
+    #[test]
+    fn wait_for_change_settles_after_a_burst() {
+        let last = snapshot(&[("a.md", at(1))]);
+        let mut polls = vec![
+            snapshot(&[("a.md", at(1))]),
+            snapshot(&[("a.md", at(2))]),
+            snapshot(&[("a.md", at(3))]),
+            snapshot(&[("a.md", at(3))]),
+            snapshot(&[("a.md", at(4))]),
+        ]
+        .into_iter();
+        let settled = wait_for_change(&last, Duration::ZERO, || polls.next().unwrap());
+        assert_eq!(settled, snapshot(&[("a.md", at(3))]));
+        assert_eq!(polls.len(), 1);
+    }
+}
@@ -0,0 +1,149 @@
+//! Precision and recall of the built-in rules on labeled samples.
+//!
+//! `tests/eval/human` holds human-written text that should draw few or no
+//! findings. `tests/eval/ai` holds LLM-styled text; next to each sample a
+//! `<name>.json` sidecar maps line numbers to the rule ids a reader expects
+//! there, e.g. `{"3": ["text/delve", "text/pivotal"]}`. A finding is correct
+//! when its line and rule id are labeled; nothing in a human sample is.
+//!
+//! The test fails when precision or recall drops below its floor. When a
+//! change improves the numbers, raise the floor with it.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Share of findings that are labeled.
+const PRECISION_FLOOR: f64 = 0.9;
+/// Share of labels that draw a finding.
+const RECALL_FLOOR: f64 = 0.85;
+
+/// A sample file, a 1-based line, and a rule id.
+type Label = (String, usize, String);
+
+fn eval_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/eval")
+}
+
+/// The `.md` samples in `set`, sorted by name.
+fn samples(set: &str) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(eval_dir().join(set))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "md"))
+        .collect();
+    paths.sort();
+    paths
+}
+
+fn name(path: &Path) -> String {
+    let set = path
+        .parent()
+        .unwrap()
+        .file_name()
+        .unwrap()
+        .to_string_lossy();
+    format!("{set}/{}", path.file_name().unwrap().to_string_lossy())
+}
+
+/// Line and rule id of each finding unai reports on `path` in text mode.
+fn findings(path: &Path) -> Vec<Label> {
+    let output = Command::new(env!("CARGO_BIN_EXE_unai"))
+        .args(["--mode", "text", "--format", "json"])
+        .arg(path)
+        .output()
+        .expect("failed to run unai");
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap_or_else(|_| {
+        panic!(
+            "{}: {}",
+            name(path),
+            String::from_utf8_lossy(&output.stderr)
+        )
+    });
+    json["findings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| {
+            (
+                name(path),
+                f["line"].as_u64().unwrap() as usize,
+                f["rule_id"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+/// The labels in the sidecar of `path`.
+fn labels(path: &Path) -> Vec<Label> {
+    let sidecar = path.with_extension("json");
+    let text = std::fs::read_to_string(&sidecar)
+        .unwrap_or_else(|_| panic!("{} has no sidecar {}", name(path), sidecar.display()));
+    let by_line: BTreeMap<String, Vec<String>> =
+        serde_json::from_str(&text).unwrap_or_else(|e| panic!("{}: {e}", sidecar.display()));
+    by_line
+        .into_iter()
+        .flat_map(|(line, ids)| {
+            let line: usize = line.parse().expect("sidecar keys are line numbers");
+            ids.into_iter().map(move |id| (name(path), line, id))
+        })
+        .collect()
+}
+
+fn list(labels: &[&Label]) -> String {
+    labels
+        .iter()
+        .map(|(file, line, id)| format!("  {file}:{line} {id}\n"))
+        .collect()
+}
+
+#[test]
+fn rules_meet_precision_and_recall_floors() {
+    let mut predicted = BTreeSet::new();
+    let mut expected = BTreeSet::new();
+    for path in samples("human") {
+        predicted.extend(findings(&path));
+    }
+    for path in samples("ai") {
+        predicted.extend(findings(&path));
+        expected.extend(labels(&path));
+    }
+    let hits = predicted.intersection(&expected).count();
+    let precision = hits as f64 / predicted.len() as f64;
+    let recall = hits as f64 / expected.len() as f64;
+    let unexpected: Vec<&Label> = predicted.difference(&expected).collect();
+    let missed: Vec<&Label> = expected.difference(&predicted).collect();
+    assert!(
+        precision >= PRECISION_FLOOR && recall >= RECALL_FLOOR,
+        "precision {precision:.3} (floor {PRECISION_FLOOR}), recall {recall:.3} (floor {RECALL_FLOOR})\n\
+         unexpected findings:\n{}missed labels:\n{}",
+        list(&unexpected),
+        list(&missed),
+    );
+}
+
+/// Labels name rules that exist, so a typo cannot pass as a miss.
+#[test]
+fn labels_name_built_in_rules() {
+    let output = Command::new(env!("CARGO_BIN_EXE_unai"))
+        .args(["rules", "doc", "--format", "json"])
+        .output()
+        .expect("failed to run unai");
+    let docs: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let ids: BTreeSet<&str> = docs["rules"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|rule| rule["id"].as_str().unwrap())
+        .collect();
+    let samples = samples("ai");
+    assert!(!samples.is_empty());
+    for path in samples {
+        for (file, line, id) in labels(&path) {
+            assert!(
+                ids.contains(id.as_str()),
+                "{file}:{line}: unknown rule id {id}"
+            );
+        }
+    }
+}
@@ -0,0 +1,9 @@
+// --- Setup ---
+use std::collections::HashMap;
+
+/// This function serves as the entry point for the cache.
+pub fn load() -> HashMap<String, String> {
+    // TODO: fix this
+    let cacheManager = HashMap::new();
+    cacheManager
+}
@@ -62,12 +62,95 @@ fn report_mode_shows_severity() {
     let input = "Certainly! Let me delve into that.\n";
     let (_stdout, stderr, _code) = run_unai(&["--report"], input);
     assert!(
-        stderr.contains("CRITICAL"),
+        stderr.contains("x critical"),
         "--report should show CRITICAL severity, got: {:?}",
         stderr
     );
 }
 
+/// --report lists alternative rewrites for flag-only findings.
+#[test]
+fn report_lists_suggestions() {
+    let input = "A robust plan.\n";
+    let (stdout, stderr, _code) = run_unai(&["--report"], input);
+    assert!(
+        stderr.contains("(consider: reliable, sturdy)"),
+        "report should list suggestions, got: {:?}",
+        stderr
+    );
+    assert_eq!(stdout, input, "suggestions must not be applied");
+}
+
+/// Citations live in `source_ref`; the report shows them only with --verbose.
+#[test]
+fn delve_citation_structured_not_in_message() {
+    let input = "Let me delve into that.\n";
+    let (stdout, _stderr, _code) = run_unai(&["--format", "json"], input);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let f = &json["findings"][0];
+    assert_eq!(f["message"], "LLM tell: 'delve'");
+    assert_eq!(
+        f["source_ref"]["citation"],
+        "25× excess frequency, Kobak 2025"
+    );
+    assert_eq!(f["source_ref"]["url"], "https://arxiv.org/abs/2406.07016");
+    assert_eq!(f["source_ref"]["confidence"], "high");
+
+    let (_stdout, stderr, _code) = run_unai(&["--report"], input);
+    assert!(!stderr.contains("Kobak"), "got: {:?}", stderr);
+    let (_stdout, stderr, _code) = run_unai(&["--report", "--verbose"], input);
+    assert!(
+        stderr.contains("LLM tell: 'delve' (25× excess frequency, Kobak 2025)"),
+        "got: {:?}",
+        stderr
+    );
+    assert!(
+        stderr.contains("unai: text mode (signals:0)"),
+        "got: {stderr:?}"
+    );
+}
+
+/// The JSON `run` object records how a file-based invocation was set up.
+#[test]
+fn json_run_metadata_for_file_with_config() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let file = dir.path().join("lib.rs");
+    std::fs::write(&file, "// TODO: fix this\nlet userManager = 1;\n").unwrap();
+    let cfg = write_temp_config("version = 1\n");
+    let cfg_path = cfg.path().to_str().unwrap();
+    let (stdout, stderr, _code) = run_unai(
+        &[
+            "--format",
+            "json",
+            "--config",
+            cfg_path,
+            "--min-severity",
+            "high",
+            "--fail",
+            file.to_str().unwrap(),
+        ],
+        "",
+    );
+    let json: serde_json::Value = serde_json::from_str(&stdout).expect(&stderr);
+    let run = &json["run"];
+    assert_eq!(run["config"], cfg_path);
+    assert!(run["preset"].is_null());
+    assert_eq!(run["min_severity"], "high");
+    assert_eq!(run["fail_threshold"], "high");
+    assert_eq!(run["detection"]["mode"], "code");
+    assert_eq!(run["detection"]["reason"], "extension:.rs");
+    let enabled = run["rules"]["enabled"].as_u64().unwrap();
+    let disabled = run["rules"]["disabled"].as_u64().unwrap();
+    // Code mode runs the comment, docstring, naming, and API rules only.
+    assert_eq!(enabled, 10);
+    assert!(disabled > enabled);
+    let elapsed = run["elapsed_ms"].as_f64().unwrap();
+    assert!(
+        elapsed > 0.0 && elapsed < 60_000.0,
+        "elapsed_ms = {elapsed}"
+    );
+}
+
 /// --diff mode produces unified diff output starting with "---".
 #[test]
 fn diff_mode_unified_format() {
@@ -112,7 +195,7 @@ fn min_severity_high_filters_low() {
     let input = "Certainly! In order to proceed.\n";
     let (_stdout, stderr, _code) = run_unai(&["--report", "--min-severity", "high"], input);
     assert!(
-        stderr.contains("CRITICAL"),
+        stderr.contains("x critical"),
         "should still show CRITICAL findings, got: {:?}",
         stderr
     );
@@ -191,7 +274,7 @@ fn ignore_directive_suppresses_findings() {
     let plain_input = "Certainly! Let me delve.\n";
     let (_stdout, stderr_plain, _code) = run_unai(&["--report"], plain_input);
     assert!(
-        stderr_plain.contains("CRITICAL"),
+        stderr_plain.contains("x critical"),
         "baseline: CRITICAL should fire without ignore directive, got: {:?}",
         stderr_plain
     );
@@ -200,7 +283,7 @@ fn ignore_directive_suppresses_findings() {
     let input = "Good prose here.\n<!-- unai-ignore -->\nCertainly! Let me delve.\n<!-- /unai-ignore -->\nMore good prose.\n";
     let (_stdout, stderr, _code) = run_unai(&["--report"], input);
     assert!(
-        !stderr.contains("CRITICAL"),
+        !stderr.contains("x critical"),
         "CRITICAL findings on ignored lines should be suppressed, got: {:?}",
         stderr
     );
@@ -211,6 +294,56 @@ fn ignore_directive_suppresses_findings() {
     );
 }
 
+/// A trailing marker suppresses its own line and is stripped from the output.
+#[test]
+fn trailing_ignore_marker_suppresses_and_is_stripped() {
+    let input = "Let me delve into it. <!-- unai-ignore -->\nLet me delve into it.\n";
+    let (stdout, _stderr, _code) = run_unai(&[], input);
+    assert_eq!(stdout, "Let me delve into it.\nLet me explore into it.\n");
+
+    let (_stdout, stderr, _code) = run_unai(&["--report"], input);
+    assert!(stderr.contains("  2:8  text/delve"), "{stderr}");
+    assert!(stderr.contains("1 ignore-directive"), "{stderr}");
+}
+
+/// A trailing marker is stripped when it suppresses every finding, too.
+#[test]
+fn trailing_ignore_marker_stripped_when_all_findings_suppressed() {
+    let input = "We need a robust API. <!-- unai-ignore -->\nPlain line.\n";
+    let (stdout, _stderr, code) = run_unai(&[], input);
+    assert_eq!(stdout, "We need a robust API.\nPlain line.\n");
+    assert_eq!(code, 0);
+
+    let code_input = "let robust = 1; // unai-ignore\nlet x = 2;\n";
+    let (stdout, _stderr, _code) = run_unai(&["--mode", "code"], code_input);
+    assert_eq!(stdout, "let robust = 1;\nlet x = 2;\n");
+}
+
+/// Frontmatter is skipped unless --scan-frontmatter asks for it; findings
+/// after it keep their line numbers.
+#[test]
+fn frontmatter_skipped_by_default() {
+    let yaml = "---\ntitle: Let us delve in\ntags: [robust]\n---\nWe delve into it.\n";
+    let (_stdout, stderr, _code) = run_unai(&["--report"], yaml);
+    assert!(stderr.contains("1 finding(s)"), "{stderr}");
+    assert!(stderr.contains("5:4  text/delve"), "{stderr}");
+
+    let (_stdout, stderr, _code) = run_unai(&["--report", "--scan-frontmatter"], yaml);
+    assert!(stderr.contains("2:15  text/delve"), "{stderr}");
+    assert!(stderr.contains("text/robust"), "{stderr}");
+
+    let toml = "+++\ntitle = \"A delve\"\n+++\nNo tells here.\n";
+    let (_stdout, stderr, _code) = run_unai(&["--report"], toml);
+    assert!(stderr.contains("0 finding(s)"), "{stderr}");
+
+    let cfg = write_temp_config("version = 1\n[input]\nscan_frontmatter = true\n");
+    let (_stdout, stderr, _code) = run_unai(
+        &["--report", "--config", cfg.path().to_str().unwrap()],
+        toml,
+    );
+    assert!(stderr.contains("2:12  text/delve"), "{stderr}");
+}
+
 /// --color never produces no ANSI escape sequences in report.
 #[test]
 fn color_never_no_ansi_in_report() {
@@ -250,6 +383,476 @@ fn commit_editmsg_fires_commit_rules() {
     );
 }
 
+/// `--mode commit` runs the commit, text, and structural rules on stdin as
+/// they run on a COMMIT_EDITMSG file, with git's comment lines skipped.
+#[test]
+fn mode_commit_checks_piped_messages() {
+    let message = "Added retry budget and fixed several bugs\n\
+                   \n\
+                   This commit leverages a comprehensive approach.\n\
+                   # Please enter the commit message for your changes.\n";
+    let ids = |stdout: &str| -> Vec<String> {
+        let json: serde_json::Value = serde_json::from_str(stdout).unwrap();
+        json["findings"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|f| format!("{}:{}", f["line"], f["rule_id"].as_str().unwrap()))
+            .collect()
+    };
+    let (piped, stderr, code) = run_unai(&["--mode", "commit", "--format", "json"], message);
+    assert_eq!(code, 0, "{stderr}");
+    let json: serde_json::Value = serde_json::from_str(&piped).unwrap();
+    assert_eq!(json["mode"], "commit");
+    assert_eq!(json["run"]["detection"]["reason"], "flag:--mode");
+    let piped = ids(&piped);
+    for expected in [
+        "1:commit/past-tense",
+        "1:commit/vague-scope",
+        "3:text/comprehensive",
+    ] {
+        assert!(
+            piped.iter().any(|id| id == expected),
+            "{expected} missing from {piped:?}"
+        );
+    }
+    assert!(!piped.iter().any(|id| id.starts_with("4:")), "{piped:?}");
+
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let path = dir.path().join("COMMIT_EDITMSG");
+    std::fs::write(&path, message).unwrap();
+    let (from_file, _stderr, _code) = run_unai(&["--format", "json", path.to_str().unwrap()], "");
+    assert_eq!(piped, ids(&from_file));
+
+    let (_stdout, _stderr, code) = run_unai(&["--mode", "commit", "--fail"], message);
+    assert_eq!(code, 10);
+}
+
+/// `--stdin-filename` gives piped content the mode a file of that name
+/// would get, and names it in JSON; it cannot be combined with a path.
+#[test]
+fn stdin_filename_drives_detection() {
+    let (stdout, stderr, code) = run_unai(
+        &[
+            "--format",
+            "json",
+            "--stdin-filename",
+            ".git/COMMIT_EDITMSG",
+        ],
+        "Added new feature\n",
+    );
+    assert_eq!(code, 0, "{stderr}");
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["mode"], "commit");
+    assert_eq!(json["file"], ".git/COMMIT_EDITMSG");
+    assert_eq!(
+        json["run"]["detection"]["reason"],
+        "filename:COMMIT_EDITMSG"
+    );
+    assert!(json["findings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|f| f["rule_id"] == "commit/past-tense"));
+
+    let (stdout, _stderr, _code) = run_unai(
+        &["--format", "json", "--stdin-filename", "src/lib.rs"],
+        "Added new feature\n",
+    );
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["mode"], "code");
+
+    let (_stdout, stderr, code) = run_unai(&["--stdin-filename", "a.md", "README.md"], "");
+    assert_eq!(code, 2);
+    assert!(stderr.contains("--stdin-filename"), "{stderr}");
+}
+
+/// A file matching `[detector] commit_globs` gets commit rules; the same
+/// content under another name does not.
+#[test]
+fn commit_glob_selects_commit_mode() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let notes = dir.path().join("release-notes");
+    std::fs::create_dir(&notes).unwrap();
+    let matching = notes.join("v1.txt");
+    let other = dir.path().join("v1.txt");
+    std::fs::write(&matching, "Added new feature\n").unwrap();
+    std::fs::write(&other, "Added new feature\n").unwrap();
+    let cfg =
+        write_temp_config("version = 1\n[detector]\ncommit_globs = [\"**/release-notes/*.txt\"]\n");
+    let cfg_path = cfg.path().to_str().unwrap();
+
+    let (_stdout, stderr, _code) = run_unai(
+        &["--report", "--config", cfg_path, matching.to_str().unwrap()],
+        "",
+    );
+    assert!(stderr.contains("imperative"), "got: {:?}", stderr);
+
+    let (_stdout, stderr, _code) = run_unai(
+        &["--report", "--config", cfg_path, other.to_str().unwrap()],
+        "",
+    );
+    assert!(!stderr.contains("imperative"), "got: {:?}", stderr);
+}
+
+/// In SQUASH_MSG only the summary the author edits is checked; the squashed
+/// commit log and git's comment lines are not.
+#[test]
+fn squash_msg_log_exempt_from_commit_rules() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let path = dir.path().join("SQUASH_MSG");
+    std::fs::write(
+        &path,
+        "Fixed retry budget in the HTTP client\n\
+         \n\
+         * commit 9f8e7d6 Updated various things\n\
+         # Conflicts:\n\
+         #\tsrc/client.rs\n\
+         Squashed commit of the following:\n\
+         \n\
+         commit 1234abcd5678ef901234abcd5678ef901234abcd\n\
+         Author: Dev <dev@example.com>\n\
+         Date:   Mon Jan 6 10:00:00 2025 +0000\n\
+         \n\
+         \x20   Added stuff, wip\n",
+    )
+    .unwrap();
+    let (stdout, _stderr, _code) = run_unai(&["--format", "json", path.to_str().unwrap()], "");
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let findings: Vec<(u64, &str)> = json["findings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| (f["line"].as_u64().unwrap(), f["matched"].as_str().unwrap()))
+        .collect();
+    assert_eq!(findings, vec![(1, "Fixed")]);
+}
+
+/// A COMMIT_EDITMSG as `git commit --verbose` writes it: only the lines the
+/// author wrote are checked, keeping their line numbers. With
+/// `[commit] comment_char`, lines opening with that character are skipped
+/// and `#` lines are checked.
+#[test]
+fn commit_editmsg_template_comments_exempt_from_rules() {
+    let template = "Fixed retry budget in the HTTP client\n\
+         \n\
+         Caps retries per request.\n\
+         {c} Please enter the commit message for your changes. Lines starting\n\
+         {c} with '{c}' will be ignored, and an empty message aborts the commit.\n\
+         {c}\n\
+         {c} On branch wip/Various-Retry-Fixes\n\
+         {c} Changes to be committed:\n\
+         {c}\tmodified:   src/Client Retry Budget.rs\n\
+         {c}\n\
+         {c} ------------------------ >8 ------------------------\n\
+         {c} Do not modify or remove the line above.\n\
+         diff --git a/src/client.rs b/src/client.rs\n\
+         +// Updated various things, wip\n";
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let path = dir.path().join("COMMIT_EDITMSG");
+    let findings = |args: &[&str]| {
+        let mut args = args.to_vec();
+        args.extend(["--format", "json", path.to_str().unwrap()]);
+        let (stdout, stderr, _code) = run_unai(&args, "");
+        let json: serde_json::Value =
+            serde_json::from_str(&stdout).unwrap_or_else(|_| panic!("{stderr}"));
+        json["findings"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|f| {
+                (
+                    f["line"].as_u64().unwrap(),
+                    f["rule_id"].as_str().unwrap().to_string(),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    let expected = vec![
+        (1, "commit/past-tense".to_string()),
+        (3, "commit/body".to_string()),
+    ];
+
+    std::fs::write(&path, template.replace("{c}", "#")).unwrap();
+    assert_eq!(findings(&[]), expected);
+
+    std::fs::write(&path, template.replace("{c}", ";")).unwrap();
+    let cfg = write_temp_config("version = 1\n[commit]\ncomment_char = \";\"\n");
+    assert_eq!(
+        findings(&["--config", cfg.path().to_str().unwrap()]),
+        expected
+    );
+
+    std::fs::write(&path, template.replace("{c}", "#")).unwrap();
+    let hash_lines = findings(&["--config", cfg.path().to_str().unwrap()]);
+    assert!(
+        hash_lines.iter().any(|(line, _)| *line > 3),
+        "'#' lines should be checked when comment_char is ';', got: {hash_lines:?}"
+    );
+}
+
+/// `[whitespace] max_blank_lines` caps blank-line runs; trailing spaces are
+/// trimmed except a two-space Markdown line break.
+#[test]
+fn whitespace_cleanup_respects_config() {
+    let input = "Title\n\n\n\nBody  \nmore \n";
+    let (stdout, _stderr, _code) = run_unai(&[], input);
+    assert_eq!(stdout, "Title\n\n\nBody  \nmore\n");
+
+    let cfg = write_temp_config("version = 1\n[whitespace]\nmax_blank_lines = 1\n");
+    let (stdout, _stderr, _code) = run_unai(&["--config", cfg.path().to_str().unwrap()], input);
+    assert_eq!(stdout, "Title\n\nBody  \nmore\n");
+}
+
+/// Curly quotes and ellipses are always fixed; em dashes past
+/// `[punctuation] em_dashes_per_paragraph` only with `em_dash_fix` set, and
+/// not under `--safe-fixes`.
+#[test]
+fn punctuation_cleanup_respects_config() {
+    let input = "\u{201C}Ready\u{2026}\u{201D} It ships Tuesday \u{2014} if tests pass \u{2014} with docs.\n";
+    let (stdout, _stderr, _code) = run_unai(&[], input);
+    assert_eq!(
+        stdout,
+        "\"Ready...\" It ships Tuesday \u{2014} if tests pass \u{2014} with docs.\n"
+    );
+
+    let cfg = write_temp_config("version = 1\n[punctuation]\nem_dash_fix = \"comma\"\n");
+    let cfg_path = cfg.path().to_str().unwrap();
+    let (stdout, _stderr, _code) = run_unai(&["--config", cfg_path], input);
+    assert_eq!(
+        stdout,
+        "\"Ready...\" It ships Tuesday \u{2014} if tests pass, with docs.\n"
+    );
+    let (stdout, _stderr, _code) = run_unai(&["--config", cfg_path, "--safe-fixes"], input);
+    assert!(stdout.ends_with("pass \u{2014} with docs.\n"), "{stdout}");
+}
+
+/// An emoji is Critical in a commit subject and High in a heading, and a
+/// clean run deletes it with the space beside it.
+#[test]
+fn emoji_severity_depends_on_context() {
+    let (stdout, _stderr, _code) = run_unai(
+        &["--mode", "commit", "--format", "json"],
+        "\u{2728} Add sparkle\n",
+    );
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let emoji = json["findings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f["rule_id"] == "punctuation/emoji")
+        .unwrap();
+    assert_eq!(emoji["severity"], "critical");
+    assert_eq!(emoji["region"], "commit-subject");
+
+    let (stdout, _stderr, _code) = run_unai(
+        &["--mode", "markdown"],
+        "## \u{1F680} Getting Started\n\nRun `unai \u{1F680}` once.\n",
+    );
+    assert_eq!(stdout, "## Getting Started\n\nRun `unai \u{1F680}` once.\n");
+}
+
+/// A chatbot disclaimer is Critical whether it sits on one line or a hard
+/// wrap splits it; the wrapped one is reported at its paragraph's first line.
+#[test]
+fn disclaimers_found_on_one_line_and_wrapped() {
+    let input = "Notes on the release.\n\nThe date is unknown. As of my\nknowledge cutoff, version 2\nis the latest.\n\nI cannot browse the internet today.\n";
+    let (stdout, _stderr, code) =
+        run_unai(&["--format", "json", "--fail", "--mode", "text"], input);
+    assert_eq!(code, 10);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let mut found: Vec<(u64, &str)> = json["findings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|f| f["severity"] == "critical")
+        .map(|f| (f["line"].as_u64().unwrap(), f["rule_id"].as_str().unwrap()))
+        .collect();
+    found.sort();
+    assert_eq!(
+        found,
+        [
+            (3, "text/as-of-my-knowledge-cutoff"),
+            (7, "text/i-cannot-browse-the-internet")
+        ]
+    );
+}
+
+/// `[output] final_newline = "always"` adds a missing final newline even when
+/// nothing else changes, and `--diff` shows it.
+#[test]
+fn output_final_newline_always() {
+    let cfg = write_temp_config("version = 1\n[output]\nfinal_newline = \"always\"\n");
+    let cfg_path = cfg.path().to_str().unwrap();
+    let (stdout, _stderr, _code) = run_unai(&["--config", cfg_path], "Plain text.");
+    assert_eq!(stdout, "Plain text.\n");
+
+    let (stdout, _stderr, _code) = run_unai(&["--config", cfg_path, "--diff"], "Plain text.");
+    assert!(
+        stdout.contains("\\ No newline at end of file"),
+        "diff should show the added newline, got: {:?}",
+        stdout
+    );
+
+    let (stdout, _stderr, _code) = run_unai(&[], "Plain text.");
+    assert_eq!(
+        stdout, "Plain text.",
+        "default preserves the missing newline"
+    );
+}
+
+/// `[output] line_endings = "crlf"` converts the cleaned output.
+#[test]
+fn output_line_endings_crlf() {
+    let cfg = write_temp_config("version = 1\n[output]\nline_endings = \"crlf\"\n");
+    let (stdout, _stderr, _code) = run_unai(
+        &["--config", cfg.path().to_str().unwrap()],
+        "We utilize it.\nDone.\n",
+    );
+    assert_eq!(stdout, "We use it.\r\nDone.\r\n");
+}
+
+/// The default policy keeps every line's own ending, so a mixed file only
+/// changes where a fix applies.
+#[test]
+fn mixed_line_endings_preserved() {
+    let input = "Intro line.\r\nWe utilize it.\nDone.\r\n";
+    let (stdout, _stderr, _code) = run_unai(&[], input);
+    assert_eq!(stdout, "Intro line.\r\nWe use it.\nDone.\r\n");
+
+    let (stdout, _stderr, _code) = run_unai(&["--diff"], input);
+    assert!(
+        stdout.contains("-We utilize it.\n+We use it.\n"),
+        "got: {stdout:?}"
+    );
+    assert!(!stdout.contains("-Intro line."), "got: {stdout:?}");
+}
+
+/// A leading byte order mark is not part of line 1: columns count from the
+/// text after it, and the cleaned output keeps it.
+#[test]
+fn bom_stripped_for_analysis_and_restored_on_output() {
+    let input = "\u{FEFF}Delve into the results.\n";
+    let (stdout, _stderr, _code) = run_unai(&[], input);
+    assert_eq!(stdout, "\u{FEFF}Explore into the results.\n");
+
+    let (stdout, stderr, _code) = run_unai(&["--format", "json"], input);
+    let json: serde_json::Value = serde_json::from_str(&stdout).expect(&stderr);
+    let f = &json["findings"][0];
+    assert_eq!(f["rule_id"], "text/delve");
+    assert_eq!(f["column"], 0);
+    assert_eq!(f["matched"], "Delve");
+
+    let (stdout, _stderr, _code) = run_unai(&["--diff"], input);
+    assert!(stdout.contains("-Delve into"), "got: {stdout:?}");
+    assert!(!stdout.contains('\u{FEFF}'), "got: {stdout:?}");
+}
+
+/// Long matched text is cut in the report, to `[output] max_matched_len`
+/// chars when set, while the written output keeps the full line.
+#[test]
+fn long_matched_text_truncated_in_report() {
+    let input = format!("// --- {} ---\nfn main() {{}}\n", "a".repeat(300));
+    let (stdout, stderr, _code) = run_unai(&["--mode", "code", "--report"], &input);
+    assert!(
+        stderr.contains(&format!("'// --- {}… (311 chars)'", "a".repeat(193))),
+        "got: {}",
+        stderr
+    );
+    assert_eq!(stdout, input);
+
+    let cfg = write_temp_config("version = 1\n[output]\nmax_matched_len = 10\n");
+    let (_stdout, stderr, _code) = run_unai(
+        &[
+            "--config",
+            cfg.path().to_str().unwrap(),
+            "--mode",
+            "code",
+            "--report",
+        ],
+        &input,
+    );
+    assert!(
+        stderr.contains("'// --- aaa… (311 chars)'"),
+        "got: {}",
+        stderr
+    );
+}
+
+/// A multi-megabyte single line is reported once instead of analyzed, stays
+/// intact in the output, and is shortened in --annotate.
+#[test]
+fn very_long_line_skipped_quickly() {
+    let input = "We utilize it. ".repeat(350_000);
+    let start = std::time::Instant::now();
+    let (stdout, stderr, _code) = run_unai(&["--report"], &input);
+    assert!(
+        start.elapsed() < std::time::Duration::from_secs(20),
+        "took {:?}",
+        start.elapsed()
+    );
+    assert!(
+        stderr.contains("Line too long to analyze (5250000 bytes, limit 10000)"),
+        "got: {}",
+        &stderr[..stderr.len().min(500)]
+    );
+    assert!(!stderr.contains("LLM filler"));
+    assert!(stderr.len() < 2_000, "report should truncate the line");
+    assert_eq!(stdout, input.trim_end(), "only the trailing space is fixed");
+
+    let (stdout, _stderr, _code) = run_unai(&["--annotate"], &input);
+    assert!(stdout.ends_with("… (5250000 bytes)\n"));
+    assert!(stdout.len() < 11_000);
+}
+
+/// `--check-strings` lints string literals in code without changing them;
+/// `--fix-strings` applies the fixes.
+#[test]
+fn check_strings_flags_literals_only_with_flag() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let path = dir.path().join("app.py");
+    let source = "print(\"We leverage cutting-edge AI\")\n";
+    std::fs::write(&path, source).unwrap();
+    let file = path.to_str().unwrap();
+
+    let (_stdout, stderr, _code) = run_unai(&["--report", file], "");
+    assert!(!stderr.contains("cutting-edge"), "got: {:?}", stderr);
+
+    let (stdout, stderr, _code) = run_unai(&["--report", "--check-strings", file], "");
+    assert!(stderr.contains("cutting-edge"), "got: {:?}", stderr);
+    assert_eq!(stdout, source, "strings must not be fixed by default");
+
+    let (stdout, _stderr, _code) = run_unai(&["--check-strings", "--fix-strings", file], "");
+    assert!(!stdout.contains("leverage"), "got: {:?}", stdout);
+}
+
+/// The first-person-plural rule only runs when the config enables it.
+#[test]
+fn first_person_plural_enabled_by_config() {
+    let input = "We believe in it. We recommend it. We ship it.\n";
+    let (_stdout, stderr, _code) = run_unai(&["--report"], input);
+    assert!(!stderr.contains("First-person"), "got: {:?}", stderr);
+
+    let cfg = write_temp_config("version = 1\n[structural]\nfirst_person_plural_max = 0.3\n");
+    let (_stdout, stderr, _code) = run_unai(
+        &["--report", "--config", cfg.path().to_str().unwrap()],
+        input,
+    );
+    assert!(
+        stderr.contains("First-person plural opens 3 of 3"),
+        "got: {:?}",
+        stderr
+    );
+}
+
+/// An invalid commit glob is a config error.
+#[test]
+fn invalid_commit_glob_exits_2() {
+    let cfg = write_temp_config("version = 1\n[detector]\ncommit_globs = [\"[abc\"]\n");
+    let (_stdout, _stderr, code) = run_unai(&["--config", cfg.path().to_str().unwrap()], "hi\n");
+    assert_eq!(code, 2);
+}
+
 // ===== T1: enabled = false rule is skipped =====
 #[test]
 fn user_rule_disabled_is_skipped() {
@@ -272,6 +875,24 @@ enabled = false
     );
 }
 
+/// A user rule for a word a text rule already reports yields one finding and
+/// one replacement.
+#[test]
+fn user_rule_overlapping_text_rule_reported_once() {
+    let toml = r#"version = 1
+[[rules]]
+pattern = "utilize"
+replacement = "employ"
+"#;
+    let cfg = write_temp_config(toml);
+    let cfg = cfg.path().to_str().unwrap();
+    let (_stdout, stderr, _code) = run_unai(&["--report", "--config", cfg], "We utilize it.\n");
+    assert!(stderr.contains("1 finding(s)"), "{stderr}");
+    let (stdout, stderr, _code) = run_unai(&["--config", cfg], "We utilize it.\n");
+    assert_eq!(stdout, "We use it.\n");
+    assert!(!stderr.contains("skipping"), "{stderr}");
+}
+
 // ===== T2: ignore.words end-to-end =====
 #[test]
 fn ignore_words_suppresses_findings() {
@@ -285,7 +906,7 @@ words = ["certainly!"]
         "Certainly!\n",
     );
     assert!(
-        !stderr.contains("CRITICAL"),
+        !stderr.contains("x critical"),
         "ignored word should suppress CRITICAL finding, got: {:?}",
         stderr
     );
@@ -304,6 +925,149 @@ fn fail_with_min_severity_high_exits_0_for_low_only() {
     );
 }
 
+/// --fail-on decides what fails, independent of what --min-severity shows.
+#[test]
+fn fail_on_is_separate_from_min_severity() {
+    let input = "Furthermore, it works.\n";
+    let (_stdout, stderr, code) = run_unai(&["--report", "--fail", "--fail-on", "critical"], input);
+    assert_eq!(code, 0, "stderr: {stderr}");
+    assert!(stderr.contains("text/furthermore"), "got: {stderr}");
+
+    let (stdout, _stderr, code) = run_unai(
+        &[
+            "--format",
+            "json",
+            "--fail",
+            "--fail-on",
+            "low",
+            "--min-severity",
+            "high",
+        ],
+        input,
+    );
+    assert_eq!(code, 10);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["summary"]["total"], 0);
+    assert_eq!(json["summary"]["failing"], 1);
+    assert_eq!(json["run"]["fail_threshold"], "low");
+}
+
+/// --exit-by-severity exits 10 + the worst failing finding's rank.
+#[test]
+fn exit_by_severity_codes_each_tier() {
+    for (input, expected) in [
+        ("Certainly!\n", 13),
+        ("We utilize it.\n", 12),
+        ("A robust design.\n", 11),
+        ("Furthermore, it works.\n", 10),
+        ("Plain text.\n", 0),
+        ("Certainly! We utilize it. Furthermore, it works.\n", 13),
+    ] {
+        let (_stdout, stderr, code) = run_unai(&["--exit-by-severity"], input);
+        assert_eq!(code, expected, "input {input:?}, stderr: {stderr}");
+    }
+}
+
+/// The tier follows the findings that fail, not every finding shown.
+#[test]
+fn exit_by_severity_respects_fail_on_and_min_severity() {
+    let input = "We utilize it. Furthermore, it works.\n";
+    let (_, _, code) = run_unai(&["--exit-by-severity", "--min-severity", "critical"], input);
+    assert_eq!(code, 0);
+    let (_, _, code) = run_unai(&["--exit-by-severity", "--fail-on", "critical"], input);
+    assert_eq!(code, 0);
+    let (_, _, code) = run_unai(&["--exit-by-severity", "--fail-on", "low"], input);
+    assert_eq!(code, 12);
+}
+
+/// --quiet prints nothing; the exit code and an explicit format still work.
+#[test]
+fn quiet_prints_nothing_but_keeps_exit_code_and_formats() {
+    let input = "Certainly! We utilize it.\n";
+    let (stdout, stderr, code) = run_unai(&["--quiet", "--fail", "--report"], input);
+    assert_eq!((stdout.as_str(), stderr.as_str(), code), ("", "", 10));
+
+    let (stdout, _, _) = run_unai(&["--quiet", "--format", "json"], input);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["summary"]["total"], 2);
+
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let out = dir.path().join("clean.txt");
+    let (stdout, _, _) = run_unai(&["--quiet", "--output", out.to_str().unwrap()], input);
+    assert_eq!(stdout, "");
+    assert_eq!(std::fs::read_to_string(&out).unwrap(), "We use it.\n");
+}
+
+/// --summary prints the counts instead of the text.
+#[test]
+fn summary_prints_counts_only() {
+    let input = "Certainly! We utilize it.\n";
+    let (stdout, _, code) = run_unai(&["--summary", "--fail"], input);
+    assert_eq!(
+        stdout,
+        "Mode: text  |  2 finding(s)\ncritical: 1  high: 1  medium: 0  low: 0\n"
+    );
+    assert_eq!(code, 10);
+
+    let (_, stderr, code) = run_unai(&["--summary", "--format", "json"], input);
+    assert_eq!(code, 2, "stderr: {stderr}");
+}
+
+/// --format compact prints one compiler-style line per finding and nothing else.
+#[test]
+fn compact_format_prints_one_line_per_finding() {
+    let input = "We utilize it.\nCertainly! Moreover, it works.\n";
+    let (stdout, _, code) = run_unai(&["--format", "compact", "--fail"], input);
+    assert_eq!(
+        stdout,
+        "<stdin>:1:4: high: text/utilize: LLM filler: 'utilize'\n\
+         <stdin>:2:1: critical: text/certainly: Sycophantic opener: 'Certainly!'\n\
+         <stdin>:2:12: low: text/moreover: LLM connector: 'moreover'\n"
+    );
+    assert_eq!(code, 10);
+
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let doc = dir.path().join("README.md");
+    std::fs::write(&doc, input).unwrap();
+    let doc = doc.to_str().unwrap();
+    let (stdout, _, _) = run_unai(
+        &[doc, "--format", "compact", "--min-severity", "critical"],
+        "",
+    );
+    assert_eq!(
+        stdout,
+        format!("{doc}:2:1: critical: text/certainly: Sycophantic opener: 'Certainly!'\n")
+    );
+}
+
+/// --report ranks paragraphs by score, and JSON carries every paragraph's.
+#[test]
+fn paragraph_scores_rank_llm_prose_first() {
+    let input = "I rewrote the parser last week. It was slow. The old version re-read \
+                 every file twice, so I deleted the cache and nothing broke.\n\n\
+                 Certainly! Moreover, we leverage a robust and comprehensive framework. \
+                 Furthermore, it is a testament to our meticulous design process.\n";
+    let (_, stderr, _) = run_unai(&["--report", "--mode", "text"], input);
+    let ranking = stderr
+        .split("Most machine-like paragraphs")
+        .nth(1)
+        .unwrap_or_else(|| panic!("no ranking in: {stderr}"));
+    assert!(
+        ranking.lines().nth(1).unwrap().contains("line 3"),
+        "{ranking}"
+    );
+
+    let (stdout, _, _) = run_unai(&["--format", "json", "--mode", "text"], input);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let paragraphs = json["scores"]["paragraphs"].as_array().unwrap();
+    let score = |i: usize| paragraphs[i]["score"].as_u64().unwrap();
+    assert!(score(1) > score(0), "{paragraphs:?}");
+
+    let (stdout, _, _) = run_unai(&["--format", "json", "--mode", "code"], input);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert!(json.get("scores").is_none());
+}
+
 // ===== T4: Config error exits code 2 =====
 #[test]
 fn invalid_config_exits_2() {
@@ -360,3 +1124,877 @@ fn format_json_fail_exits_10_with_findings() {
         "JSON must have 'findings'"
     );
 }
+
+/// --safe-fixes applies Safe replacements and leaves Caution ones as suggestions.
+#[test]
+fn safe_fixes_leaves_caution_replacements_as_suggestions() {
+    let input = "We should utilize a comprehensive plan.\n";
+    let (stdout, stderr, code) = run_unai(&["--safe-fixes", "--report"], input);
+    assert_eq!(code, 0);
+    assert_eq!(stdout, "We should use a comprehensive plan.\n");
+    assert!(
+        stderr.contains("'comprehensive' (suggest 'thorough')"),
+        "withheld fix should be reported as a suggestion, got: {:?}",
+        stderr
+    );
+}
+
+/// --report notes suppressed findings; --show-suppressed lists them with their cause.
+#[test]
+fn report_notes_and_lists_suppressed_findings() {
+    let input = "<!-- unai-ignore -->\nLet me delve.\n<!-- /unai-ignore -->\n";
+    let (_stdout, stderr, _code) = run_unai(&["--report"], input);
+    assert!(
+        stderr.contains("1 finding(s) suppressed: 1 ignore-directive"),
+        "report should tally suppressions, got: {:?}",
+        stderr
+    );
+    assert!(!stderr.contains("SUPPRESSED"));
+
+    let (_stdout, stderr, _code) = run_unai(&["--show-suppressed"], input);
+    assert!(
+        stderr.contains("'delve' [ignore-directive]"),
+        "--show-suppressed should list the finding, got: {:?}",
+        stderr
+    );
+}
+
+/// `--hyperlinks always` wraps rule ids and line references in OSC 8 links;
+/// a captured, non-TTY run gets plain text, and so does `--color never`.
+#[test]
+fn report_hyperlinks() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let file = dir.path().join("draft.md");
+    std::fs::write(&file, "Let us delve into it.\n").unwrap();
+    let path = file.to_str().unwrap();
+
+    let (_stdout, stderr, _code) = run_unai(&["--report", "--hyperlinks", "always", path], "");
+    assert!(
+        stderr.contains(
+            "\x1b]8;;https://github.com/HugoLopes45/unai/blob/main/rules/text.md\x1b\\text/delve\x1b]8;;\x1b\\"
+        ),
+        "{stderr:?}"
+    );
+    assert!(stderr.contains("\x1b]8;;file:///"), "{stderr:?}");
+    let url = format!("\x1b]8;;file://{path}\x1b\\draft.md:1:8\x1b]8;;\x1b\\");
+    assert!(stderr.contains(&url), "{stderr:?}");
+
+    let (_stdout, stderr, _code) = run_unai(&["--report", path], "");
+    assert!(stderr.contains("  text/delve  "), "{stderr:?}");
+    assert!(!stderr.contains('\x1b'), "{stderr:?}");
+
+    let (_stdout, stderr, _code) = run_unai(
+        &[
+            "--report",
+            "--hyperlinks",
+            "always",
+            "--color",
+            "never",
+            path,
+        ],
+        "",
+    );
+    assert!(!stderr.contains('\x1b'), "{stderr:?}");
+}
+
+/// Both report layouts for a fixed input: aligned columns sorted by
+/// severity and position, and the plain grouped format.
+#[test]
+fn report_styles_for_fixture() {
+    let input = "Certainly! We delve into robust data.\nIt is worth noting that this helps.\n";
+    let (_stdout, aligned, _code) = run_unai(&["--report", "--color", "never"], input);
+    assert_eq!(
+        aligned,
+        "Mode: text  |  4 finding(s)\n\
+         \n\
+         x critical  1:1   text/certainly           Sycophantic opener: 'Certainly!'  'Certainly!'\n\
+         x critical  1:15  text/delve               LLM tell: 'delve'                 'delve'\n\
+         * medium    1:26  text/robust              LLM filler: 'robust'              'robust' (consider: reliable, sturdy)\n\
+         o low       2:1   text/it-is-worth-noting  LLM hedge: 'it is worth noting'   'It is worth noting that '\n"
+    );
+    let (_stdout, plain, _code) = run_unai(
+        &["--report", "--report-style", "plain", "--color", "never"],
+        input,
+    );
+    assert_eq!(
+        plain,
+        "Mode: text  |  4 finding(s)\n\
+         \n\
+         CRITICAL (2)\n  \
+         line 1: LLM tell: 'delve' 'delve' [text/delve]\n  \
+         line 1: Sycophantic opener: 'Certainly!' 'Certainly!' [text/certainly]\n\
+         \n\
+         MEDIUM (1)\n  \
+         line 1: LLM filler: 'robust' 'robust' (consider: reliable, sturdy) [text/robust]\n\
+         \n\
+         LOW (1)\n  \
+         line 2: LLM hedge: 'it is worth noting' 'It is worth noting that ' [text/it-is-worth-noting]\n"
+    );
+}
+
+/// `--group-by rule` lists each rule once with its count and first lines; the
+/// JSON `by_rule` map is there either way.
+#[test]
+fn group_by_rule_counts_each_rule_once() {
+    let input = "We utilize robust tools.\nA robust plan.\nRobust again, robust.\nCertainly!\n";
+    let (_stdout, stderr, _code) = run_unai(
+        &["--report", "--group-by", "rule", "--color", "never"],
+        input,
+    );
+    assert_eq!(
+        stderr,
+        "Mode: text  |  6 finding(s)\n\
+         \n\
+         x critical  text/certainly  1×  Sycophantic opener: 'Certainly!'  line 4\n\
+         ^ high      text/utilize    1×  LLM filler: 'utilize'             line 1\n\
+         * medium    text/robust     4×  LLM filler: 'robust'              lines 1, 2, 3\n"
+    );
+
+    let (stdout, _, _) = run_unai(&["--format", "json"], input);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(
+        json["by_rule"]["text/robust"],
+        serde_json::json!({"severity": "medium", "count": 4, "lines": [1, 2, 3]})
+    );
+}
+
+/// `--explain-findings` adds the rule's description, source, and rewrite
+/// under each finding in both layouts, wrapped to `$COLUMNS`; a default
+/// report has none of it.
+#[test]
+fn explain_findings_under_each_finding() {
+    let input = "We delve into it.\n";
+    let explain = |args: &[&str], columns: &str| {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_unai"))
+            .args(args)
+            .env("COLUMNS", columns)
+            .stdout(Stdio::null())
+            .stdin(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(input.as_bytes())
+            .unwrap();
+        let output = child.wait_with_output().unwrap();
+        String::from_utf8(output.stderr).unwrap()
+    };
+
+    let aligned = explain(
+        &["--report", "--explain-findings", "--color", "never"],
+        "200",
+    );
+    assert!(
+        aligned.ends_with(
+            "'delve'\n    \
+             why  LLM tell: 'delve' (25× excess frequency, Kobak 2025)\n    \
+             try  \"We look at the results.\" instead of \"We delve into the results.\"\n"
+        ),
+        "{aligned}"
+    );
+    let plain = explain(
+        &["--report", "--report-style", "plain", "--explain-findings"],
+        "200",
+    );
+    assert!(
+        plain.contains("[text/delve]\n    why  LLM tell: 'delve'"),
+        "{plain}"
+    );
+
+    let narrow = explain(
+        &["--report", "--explain-findings", "--color", "always"],
+        "20",
+    );
+    let why: Vec<&str> = narrow
+        .lines()
+        .skip_while(|l| !l.contains("why"))
+        .take_while(|l| !l.contains("try"))
+        .collect();
+    assert!(why.len() > 1, "{narrow}");
+    assert!(why.iter().all(|l| l.ends_with("\x1b[0m")), "{narrow:?}");
+
+    let (_stdout, default, _code) = run_unai(&["--report"], input);
+    assert!(!default.contains("why"), "{default}");
+    let (_stdout, stderr, code) = run_unai(&["--explain-findings"], input);
+    assert_eq!(code, 2, "{stderr}");
+}
+
+/// --lint-config reports an ignore block that hides nothing; dropping the
+/// block drops the meta finding.
+#[test]
+fn lint_config_reports_unused_ignore_block() {
+    let cfg = write_temp_config("version = 1\n");
+    let cfg_path = cfg.path().to_str().unwrap();
+    let meta = |input: &str| -> Vec<serde_json::Value> {
+        let (stdout, stderr, _code) = run_unai(
+            &["--format", "json", "--config", cfg_path, "--lint-config"],
+            input,
+        );
+        let json: serde_json::Value = serde_json::from_str(&stdout).expect(&stderr);
+        json["findings"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|f| {
+                f["message"]
+                    .as_str()
+                    .is_some_and(|m| m.starts_with("Unused suppression"))
+            })
+            .cloned()
+            .collect()
+    };
+    let unused = meta("Intro.\n<!-- unai-ignore -->\nThe sky is blue.\n<!-- /unai-ignore -->\n");
+    assert_eq!(unused.len(), 1, "{unused:?}");
+    assert_eq!(unused[0]["line"], 2);
+    assert_eq!(unused[0]["severity"], "low");
+    assert!(meta("Intro.\nThe sky is blue.\n").is_empty());
+    let used = "<!-- unai-ignore -->\nLet us delve into it.\n<!-- /unai-ignore -->\n";
+    assert!(meta(used).is_empty());
+}
+
+/// Unused config suppressions are reported too, and
+/// --fail-on-unused-suppressions turns them into a failing exit code while
+/// plain --fail ignores them.
+#[test]
+fn fail_on_unused_suppressions_exit_code() {
+    let cfg = write_temp_config(
+        "version = 1\n[ignore]\nwords = [\"delve\", \"tapestry\"]\n[[allow]]\nphrase = \"rich history\"\n",
+    );
+    let cfg_path = cfg.path().to_str().unwrap();
+    let input = "Let us delve into it.\n";
+    let (_stdout, stderr, code) = run_unai(
+        &[
+            "--config",
+            cfg_path,
+            "--report",
+            "--fail-on-unused-suppressions",
+        ],
+        input,
+    );
+    assert_eq!(code, 10, "{stderr}");
+    assert!(stderr.contains("ignore.words entry 'tapestry'"), "{stderr}");
+    assert!(stderr.contains("allow entry 'rich history'"), "{stderr}");
+    assert!(!stderr.contains("'delve' hides"), "{stderr}");
+    let (_stdout, stderr, code) =
+        run_unai(&["--config", cfg_path, "--lint-config", "--fail"], input);
+    assert_eq!(code, 0, "{stderr}");
+}
+
+/// `ignore.words` ignore case by default; with `ignore.case_sensitive` an
+/// entry hides only its exact spelling, and a case variant of a built-in
+/// needle draws a warning.
+#[test]
+fn ignore_words_case_sensitivity() {
+    let input = "Delve in. We delve deeper.\n";
+    let words = "version = 1\n[ignore]\nwords = [\"Delve\"]\n";
+    let delves = |toml: &str| {
+        let cfg = write_temp_config(toml);
+        let (stdout, stderr, _code) = run_unai(
+            &["--format", "json", "--config", cfg.path().to_str().unwrap()],
+            input,
+        );
+        let json: serde_json::Value = serde_json::from_str(&stdout).expect(&stderr);
+        let count = json["findings"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|f| f["matched"].as_str().unwrap().eq_ignore_ascii_case("delve"))
+            .count();
+        (count, stderr)
+    };
+    let (count, stderr) = delves(words);
+    assert_eq!(count, 0);
+    assert!(!stderr.contains("warning"), "{stderr}");
+    let (count, stderr) = delves(&format!("{words}case_sensitive = true\n"));
+    assert_eq!(count, 1);
+    assert!(
+        stderr.contains("unai: warning: ignore.words entry 'Delve' differs only by case"),
+        "{stderr}"
+    );
+}
+
+/// `disabled_rules` turns off a built-in rule while the others keep firing;
+/// an id that is not a built-in rule is a config error naming the valid ones.
+#[test]
+fn disabled_rules_turn_off_built_in_rules() {
+    let cfg = write_temp_config("disabled_rules = [\"text/robust\"]\nversion = 1\n");
+    let (stdout, stderr, _code) = run_unai(
+        &["--format", "json", "--config", cfg.path().to_str().unwrap()],
+        "A robust plan to delve into.\n",
+    );
+    let json: serde_json::Value = serde_json::from_str(&stdout).expect(&stderr);
+    let ids: Vec<&str> = json["findings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| f["rule_id"].as_str().unwrap())
+        .collect();
+    assert_eq!(ids, ["text/delve"]);
+
+    let cfg = write_temp_config("version = 1\ndisabled_rules = [\"text/sturdy\"]\n");
+    let (_stdout, stderr, code) = run_unai(
+        &["--config", cfg.path().to_str().unwrap()],
+        "A robust plan.\n",
+    );
+    assert_eq!(code, 2, "{stderr}");
+    assert!(
+        stderr.contains("unknown rule id 'text/sturdy'") && stderr.contains("text/robust"),
+        "{stderr}"
+    );
+}
+
+/// A severity override applies before filtering: a Critical finding demoted
+/// to Low no longer trips `--fail --min-severity high`.
+#[test]
+fn severity_override_demotes_before_fail() {
+    let input = "Feel free to reach out.\n";
+    let (_stdout, _stderr, code) = run_unai(&["--fail", "--min-severity", "high"], input);
+    assert_ne!(code, 0);
+
+    let cfg =
+        write_temp_config("version = 1\n[severity_overrides]\n\"text/feel-free-to\" = \"low\"\n");
+    let config = cfg.path().to_str().unwrap();
+    let (_stdout, stderr, code) = run_unai(
+        &["--fail", "--min-severity", "high", "--config", config],
+        input,
+    );
+    assert_eq!(code, 0, "{stderr}");
+    let (stdout, stderr, _code) = run_unai(&["--format", "json", "--config", config], input);
+    let json: serde_json::Value = serde_json::from_str(&stdout).expect(&stderr);
+    assert_eq!(json["findings"][0]["severity"], "low");
+
+    let cfg = write_temp_config("version = 1\n[severity_overrides]\n\"text/robust\" = \"mild\"\n");
+    let (_stdout, stderr, code) = run_unai(&["--config", cfg.path().to_str().unwrap()], input);
+    assert_eq!(code, 2);
+    assert!(
+        stderr.contains("unknown severity 'mild'; valid: critical, high, medium, low"),
+        "{stderr}"
+    );
+}
+
+/// An `[[allow]]` phrase suppresses findings inside it but not the same word
+/// elsewhere.
+#[test]
+fn allow_phrase_suppresses_only_inside_phrase() {
+    let cfg = write_temp_config("version = 1\n[[allow]]\nphrase = \"comprehensive income\"\n");
+    let input = "Other comprehensive income rose.\nRead the comprehensive guide.\n";
+    let (stdout, stderr, _code) = run_unai(
+        &["--config", cfg.path().to_str().unwrap(), "--format", "json"],
+        input,
+    );
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let lines: Vec<u64> = json["findings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|f| f["matched"] == "comprehensive")
+        .map(|f| f["line"].as_u64().unwrap())
+        .collect();
+    assert_eq!(lines, vec![2], "stderr: {}", stderr);
+    assert_eq!(json["summary"]["suppressed"]["allow_phrase"], 1);
+}
+
+/// [fail] structural = "never" keeps structural findings advisory under --fail.
+#[test]
+fn fail_config_structural_never_exits_0_but_reports() {
+    let toml = "version = 1\n[fail]\nstructural = \"never\"\n\
+        [structural]\nrepetition_min_words = 0\n";
+    let cfg = write_temp_config(toml);
+    let cfg_path = cfg.path().to_str().unwrap();
+    let input = "The service stores every event in a durable queue.\n\n\
+        The service stores every event in a durable queue.\n";
+    let (_stdout, stderr, code) = run_unai(&["--fail", "--report", "--config", cfg_path], input);
+    assert_eq!(
+        code, 0,
+        "structural findings should not fail, got: {}",
+        code
+    );
+    assert!(
+        stderr.contains("Near-duplicate sentence"),
+        "structural finding should still be reported, got: {:?}",
+        stderr
+    );
+
+    let (_stdout, _stderr, code) = run_unai(&["--fail", "--config", cfg_path], "Certainly!\n");
+    assert_eq!(
+        code, 10,
+        "critical text finding should still fail, got: {}",
+        code
+    );
+}
+
+/// A [fail] threshold applies even to findings hidden by --min-severity.
+#[test]
+fn fail_config_threshold_ignores_display_filter() {
+    let toml = "version = 1\n[[rules]]\npattern = \"synergy\"\nseverity = \"low\"\n\
+        [fail]\nuser = \"low\"\n";
+    let cfg = write_temp_config(toml);
+    let (_stdout, _stderr, code) = run_unai(
+        &[
+            "--fail",
+            "--min-severity",
+            "high",
+            "--config",
+            cfg.path().to_str().unwrap(),
+        ],
+        "Team synergy matters.\n",
+    );
+    assert_eq!(code, 10, "user threshold should trip --fail, got: {}", code);
+}
+
+/// --rewrap re-wraps only the paragraph a fix changed.
+#[test]
+fn rewrap_reflows_edited_paragraph_only() {
+    let input = "The build failed due to the fact that the cache\n\
+        was stale for a week.\n\
+        \n\
+        Untouched text\n\
+        stays as is.\n";
+    let (stdout, _stderr, code) = run_unai(&["--rewrap", "40"], input);
+    assert_eq!(code, 0);
+    assert_eq!(
+        stdout,
+        "The build failed because the cache was\n\
+         stale for a week.\n\
+         \n\
+         Untouched text\n\
+         stays as is.\n"
+    );
+}
+
+/// --diff and --dry-run say when every finding was suppressed.
+#[test]
+fn diff_and_dry_run_note_suppressed_findings() {
+    let input = "Intro.\n<!-- unai-ignore -->\nWe utilize it.\n<!-- /unai-ignore -->\n";
+
+    let (stdout, stderr, code) = run_unai(&["--diff"], input);
+    assert_eq!(code, 0);
+    assert!(stdout.is_empty(), "no diff expected, got: {:?}", stdout);
+    assert!(
+        stderr.contains("no changes; 1 finding(s) suppressed: 1 ignore-directive"),
+        "got: {:?}",
+        stderr
+    );
+    assert!(!stderr.contains("none auto-fixable"));
+
+    let (stdout, stderr, _code) = run_unai(&["--dry-run"], input);
+    assert_eq!(stdout, input);
+    assert!(
+        stderr.contains("--- 1 finding(s) suppressed: 1 ignore-directive ---"),
+        "got: {:?}",
+        stderr
+    );
+}
+
+#[test]
+fn rules_doc_json_documents_every_rule() {
+    let (stdout, stderr, code) = run_unai(&["rules", "doc", "--format", "json"], "");
+    assert_eq!(code, 0, "stderr: {stderr}");
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let rules = json["rules"].as_array().unwrap();
+    let delve = rules.iter().find(|r| r["id"] == "text/delve").unwrap();
+    assert_eq!(delve["severity"], "critical");
+    assert_eq!(delve["fix"]["replacement"], "explore");
+    assert_eq!(delve["source"]["confidence"], "high");
+    assert!(rules.iter().any(|r| r["id"] == "commit/past-tense"));
+    assert!(rules
+        .iter()
+        .all(|r| r["examples"]["bad"].is_string() && r["examples"]["good"].is_string()));
+}
+
+/// A High "utilize" fix beats the Medium deletion of the restating conclusion
+/// it sits in; the losing deletion is flagged and the paragraph kept whole.
+#[test]
+fn severe_fix_supersedes_paragraph_deletion() {
+    let input = "The cache holds parsed files and we utilize it for builds.\n\n\
+                 In summary, we utilize the cache for parsed files.\n";
+    let cfg = write_temp_config("version = 1\n[structural]\nconclusion_min_words = 0\n");
+    let config = ["--config", cfg.path().to_str().unwrap()];
+    let run_unai = |args: &[&str], input| run_unai(&[&config[..], args].concat(), input);
+    let (stdout, _stderr, _code) = run_unai(&[], input);
+    assert_eq!(
+        stdout,
+        "The cache holds parsed files and we use it for builds.\n\n\
+         In summary, we use the cache for parsed files.\n"
+    );
+
+    let (stdout, _stderr, _code) = run_unai(&["--format", "json"], input);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let superseded: Vec<u64> = json["findings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|f| f["superseded"] == true)
+        .map(|f| f["line"].as_u64().unwrap())
+        .collect();
+    assert_eq!(superseded, vec![3]);
+
+    let (_stdout, stderr, _code) = run_unai(&["--report"], input);
+    assert!(
+        stderr.contains("1 fix(es) superseded by a conflicting fix on the same line"),
+        "got: {stderr:?}"
+    );
+}
+
+/// `config init` writes what `--print-default-config` prints, refuses to
+/// replace an existing file without `--force`, and the result loads.
+#[test]
+fn config_init_writes_default_config() {
+    let (printed, _, code) = run_unai(&["--print-default-config"], "");
+    assert_eq!(code, 0);
+    assert!(printed.contains("[output]"), "got: {printed}");
+
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let binary = env!("CARGO_BIN_EXE_unai");
+    let init = |extra: &[&str]| {
+        Command::new(binary)
+            .args(["config", "init"])
+            .args(extra)
+            .current_dir(dir.path())
+            .output()
+            .expect("failed to run unai config init")
+    };
+    assert!(init(&[]).status.success());
+    let written = std::fs::read_to_string(dir.path().join("unai.toml")).unwrap();
+    assert_eq!(written, printed);
+
+    let again = init(&[]);
+    assert_eq!(again.status.code(), Some(1));
+    assert!(init(&["--force"]).status.success());
+
+    let config = dir.path().join("unai.toml");
+    let (stdout, stderr, code) = run_unai(&["--config", config.to_str().unwrap()], "Hello.\n");
+    assert_eq!(code, 0, "stderr: {stderr}");
+    assert_eq!(stdout, "Hello.\n");
+}
+
+/// A directory argument is walked: findings from every file are reported
+/// with their path, binary files are skipped with a warning, `--exclude`
+/// drops files, and `--fail` exits 10 if any file fails.
+#[test]
+fn scans_directories_and_attributes_findings() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let root = dir.path();
+    std::fs::create_dir_all(root.join("docs/drafts")).unwrap();
+    std::fs::write(root.join("docs/guide.md"), "Clear text.\n").unwrap();
+    std::fs::write(root.join("docs/drafts/old.md"), "We delve into it.\n").unwrap();
+    std::fs::write(root.join("docs/logo.png"), b"\x89PNG\xff\xfe").unwrap();
+    let docs = root.join("docs");
+    let docs = docs.to_str().unwrap();
+
+    let (stdout, stderr, code) = run_unai(&[docs, "--format", "json", "--fail"], "");
+    assert_eq!(code, 10, "stderr: {stderr}");
+    assert!(
+        stderr.contains("skipping") && stderr.contains("logo.png"),
+        "got: {stderr}"
+    );
+    let report: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let files: Vec<&str> = report["files"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| f["path"].as_str().unwrap())
+        .collect();
+    assert_eq!(files.len(), 2, "got: {files:?}");
+    assert!(files[0].ends_with("old.md") && files[1].ends_with("guide.md"));
+    assert_eq!(report["summary"]["total"], 1);
+    assert_eq!(report["findings"][0]["file"], files[0]);
+
+    let (_, stderr, code) = run_unai(
+        &[
+            docs,
+            "--report",
+            "--exclude",
+            "drafts/**",
+            "--fail",
+            "--color",
+            "never",
+        ],
+        "",
+    );
+    assert_eq!(code, 0, "stderr: {stderr}");
+    assert!(
+        stderr.contains("0 finding(s) in 0 of 1 file(s)"),
+        "got: {stderr}"
+    );
+
+    let (_, stderr, code) = run_unai(&[docs], "");
+    assert_eq!(code, 2);
+    assert!(
+        stderr.contains("can be checked but not cleaned"),
+        "got: {stderr}"
+    );
+}
+
+/// A file whose analysis runs past `--timeout` is reported as timed out with
+/// no findings, and the scan goes on to the next file. The exit code is 1
+/// only under `--strict-files`.
+#[test]
+fn timeout_abandons_a_slow_file_and_scans_the_rest() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let paragraph = "We delve into the robust landscape. Moreover, the tapestry is pivotal. ";
+    let big = format!("{}\n\n", paragraph.repeat(8)).repeat(4000);
+    let (big_path, small_path) = (dir.path().join("a-big.md"), dir.path().join("b-small.md"));
+    std::fs::write(&big_path, big).unwrap();
+    std::fs::write(&small_path, "We delve into it.\n").unwrap();
+    let (big_path, small_path) = (big_path.to_str().unwrap(), small_path.to_str().unwrap());
+
+    let (stdout, stderr, code) = run_unai(
+        &[big_path, small_path, "--format", "json", "--timeout", "0.5"],
+        "",
+    );
+    assert_eq!(code, 0, "stderr: {stderr}");
+    assert!(
+        stderr.contains("a-big.md") && stderr.contains("--timeout"),
+        "got: {stderr}"
+    );
+    let report: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(report["files"][0]["timeout"], true);
+    assert_eq!(report["files"][0]["findings"], 0);
+    assert!(report["files"][1].get("timeout").is_none());
+    assert_eq!(report["files"][1]["findings"], 1);
+
+    let (_, stderr, code) = run_unai(
+        &[
+            big_path,
+            small_path,
+            "--report",
+            "--timeout",
+            "0.5",
+            "--strict-files",
+        ],
+        "",
+    );
+    assert_eq!(code, 1, "stderr: {stderr}");
+    assert!(stderr.contains("1 timed out"), "got: {stderr}");
+}
+
+/// A reader that stops early, like `head`, ends the run quietly with exit 0
+/// instead of a broken-pipe panic.
+#[test]
+fn closed_stdout_exits_quietly() {
+    use std::io::Read as _;
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let path = dir.path().join("long.md");
+    let paragraph = "We should utilize this plan.\nA plain line of text.\n\n";
+    std::fs::write(&path, paragraph.repeat(5_000)).unwrap();
+
+    let binary = env!("CARGO_BIN_EXE_unai");
+    let mut child = Command::new(binary)
+        .arg(&path)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("failed to spawn unai binary");
+    let mut head = [0u8; 16];
+    child.stdout.take().unwrap().read_exact(&mut head).unwrap();
+    let output = child.wait_with_output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(output.status.code(), Some(0), "stderr: {stderr}");
+    assert!(!stderr.contains("panicked"), "got: {stderr}");
+    assert_eq!(&head, b"We should use th");
+}
+
+/// `--format sarif` emits a SARIF 2.1.0 log locating each finding in the file.
+#[test]
+fn sarif_output_locates_findings() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let path = dir.path().join("notes.md");
+    std::fs::write(&path, "We delve into it.\n").unwrap();
+    let (stdout, stderr, code) = run_unai(&["--format", "sarif", path.to_str().unwrap()], "");
+    assert_eq!(code, 0, "stderr: {stderr}");
+    let log: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(log["version"], "2.1.0");
+    let run = &log["runs"][0];
+    assert_eq!(run["tool"]["driver"]["name"], "unai");
+    let result = &run["results"][0];
+    assert_eq!(result["ruleId"], "text/delve");
+    let location = &result["locations"][0]["physicalLocation"];
+    assert!(location["artifactLocation"]["uri"]
+        .as_str()
+        .unwrap()
+        .ends_with("/notes.md"));
+    assert_eq!(location["region"]["startLine"], 1);
+    assert_eq!(location["region"]["startColumn"], 4);
+}
+
+/// `--format github` prints one workflow command per finding, leveled by
+/// severity, with the file when there is one; `--fail` still sets the exit
+/// code.
+#[test]
+fn github_format_prints_workflow_commands() {
+    let input = "We delve into it.\nIt is worth noting that it helps.\n";
+    let (stdout, stderr, code) = run_unai(&["--format", "github"], input);
+    assert_eq!(code, 0, "stderr: {stderr}");
+    assert_eq!(
+        stdout,
+        "::error line=1,col=4,title=text/delve::LLM tell: 'delve'\n\
+         ::notice line=2,col=1,title=text/it-is-worth-noting::LLM hedge: 'it is worth noting'\n"
+    );
+    let (_stdout, _stderr, code) = run_unai(&["--format", "github", "--fail"], input);
+    assert_eq!(code, 10);
+
+    let dir = tempfile::tempdir().expect("create temp dir");
+    std::fs::write(dir.path().join("a.md"), "We delve into it.\n").unwrap();
+    std::fs::write(dir.path().join("b.md"), "Plain text.\n").unwrap();
+    let root = dir.path().to_str().unwrap();
+    let (stdout, stderr, code) = run_unai(&["--format", "github", root], "");
+    assert_eq!(code, 0, "stderr: {stderr}");
+    let file = format!("file={}/a.md,", root.replace('\\', "/").replace(':', "%3A"));
+    assert!(
+        stdout.starts_with("::error ") && stdout.contains(&file),
+        "{stdout}"
+    );
+    assert_eq!(stdout.lines().count(), 1, "{stdout}");
+}
+
+/// An inline `<!-- unai: ... -->` comment configures its own file: it adds to
+/// the config's disabled rules, sets the minimum severity unless
+/// `--min-severity` is given, and is never flagged or changed itself.
+#[test]
+fn inline_settings_apply_to_their_file_and_yield_to_cli() {
+    let comment = "<!-- unai: min-severity=high disable=text/leverage mode=markdown -->";
+    let input = format!("{comment}\nWe delve into a robust plan. Leverage it to utilize it.\n");
+    let cfg = write_temp_config("version = 1\ndisabled_rules = [\"text/delve\"]\n");
+    let cfg = cfg.path().to_str().unwrap();
+    let ids = |stdout: &str| -> Vec<String> {
+        let report: serde_json::Value = serde_json::from_str(stdout).unwrap();
+        report["findings"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|f| f["rule_id"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    let (stdout, stderr, code) = run_unai(&["--format", "json", "--config", cfg], &input);
+    assert_eq!(code, 0, "stderr: {stderr}");
+    assert_eq!(ids(&stdout), ["text/utilize"]);
+
+    let (stdout, _, _) = run_unai(
+        &["--format", "json", "--config", cfg, "--min-severity", "low"],
+        &input,
+    );
+    let ids = ids(&stdout);
+    assert!(ids.contains(&"text/robust".to_string()), "got: {ids:?}");
+    assert!(!ids.contains(&"text/leverage".to_string()), "got: {ids:?}");
+
+    let (stdout, _, _) = run_unai(&["--config", cfg], &input);
+    assert!(stdout.starts_with(&format!("{comment}\n")), "got: {stdout}");
+    assert!(stdout.contains("to use it"), "got: {stdout}");
+}
+
+/// A bad inline setting is skipped with a warning; the rest still apply.
+#[test]
+fn inline_settings_warn_on_bad_values() {
+    let input = "<!-- unai: min-severity=urgent disable=text/delve -->\nWe delve into it.\n";
+    let (stdout, stderr, code) = run_unai(&["--format", "json"], input);
+    assert_eq!(code, 0, "stderr: {stderr}");
+    assert!(
+        stderr.contains("warning") && stderr.contains("'urgent'"),
+        "got: {stderr}"
+    );
+    let report: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(report["summary"]["total"], 0, "got: {stdout}");
+}
+
+/// `--changed-only` keeps findings on lines changed since HEAD, and
+/// `--diff-base` picks another ref. An untracked file is checked in full,
+/// with a warning.
+#[test]
+fn changed_only_reports_lines_changed_since_the_base() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let git = |args: &[&str]| {
+        let status = Command::new("git")
+            .args(["-c", "user.name=unai", "-c", "user.email=unai@example.com"])
+            .args(args)
+            .current_dir(dir.path())
+            .stdout(Stdio::null())
+            .status()
+            .expect("git is installed");
+        assert!(status.success(), "git {args:?}");
+    };
+    let doc = dir.path().join("doc.md");
+    git(&["init", "-q"]);
+    std::fs::write(&doc, "We delve into it.\nPlain line.\n").unwrap();
+    git(&["add", "doc.md"]);
+    git(&["commit", "-q", "-m", "Add doc"]);
+    std::fs::write(&doc, "We delve into it.\nA robust line.\nWe leverage it.\n").unwrap();
+    let untracked = dir.path().join("new.md");
+    std::fs::write(&untracked, "We delve into it.\n").unwrap();
+    let lines = |stdout: &str| -> Vec<u64> {
+        let report: serde_json::Value = serde_json::from_str(stdout).unwrap();
+        report["findings"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|f| f["line"].as_u64().unwrap())
+            .collect()
+    };
+    let doc = doc.to_str().unwrap();
+
+    let (stdout, stderr, code) = run_unai(&[doc, "--changed-only", "--format", "json"], "");
+    assert_eq!(code, 0, "stderr: {stderr}");
+    assert_eq!(lines(&stdout), [2, 3]);
+    let report: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(report["summary"]["suppressed"]["unchanged_line"], 1);
+
+    git(&["commit", "-q", "-am", "Edit doc"]);
+    let (stdout, _, _) = run_unai(&[doc, "--diff-base", "HEAD~1", "--format", "json"], "");
+    assert_eq!(lines(&stdout), [2, 3]);
+    let (stdout, _, _) = run_unai(&[doc, "--changed-only", "--format", "json"], "");
+    assert_eq!(lines(&stdout), Vec::<u64>::new());
+
+    let (stdout, stderr, _) = run_unai(
+        &[
+            untracked.to_str().unwrap(),
+            "--changed-only",
+            "--format",
+            "json",
+        ],
+        "",
+    );
+    assert!(stderr.contains("not tracked"), "got: {stderr}");
+    assert_eq!(lines(&stdout), [1]);
+}
+
+#[test]
+fn watch_rewrites_json_output_on_change() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let docs = dir.path().join("docs");
+    std::fs::create_dir(&docs).unwrap();
+    std::fs::write(docs.join("a.md"), "We utilize it.\n").unwrap();
+    std::fs::write(docs.join("b.md"), "Fine text.\n").unwrap();
+    let output = dir.path().join("findings.json");
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_unai"))
+        .args([docs.to_str().unwrap(), "--watch", "--format", "json"])
+        .args(["--output", output.to_str().unwrap()])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("failed to spawn unai binary");
+    let findings = || -> Option<u64> {
+        let report: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&output).ok()?).ok()?;
+        report["summary"]["total"].as_u64()
+    };
+    let wait_for = |total: u64| {
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(20);
+        while findings() != Some(total) && std::time::Instant::now() < deadline {
+            std::thread::sleep(std::time::Duration::from_millis(50));
+        }
+        findings()
+    };
+
+    let first = wait_for(1);
+    std::fs::write(docs.join("b.md"), "We leverage it.\n").unwrap();
+    let second = wait_for(2);
+    child.kill().unwrap();
+    child.wait().unwrap();
+    assert_eq!(first, Some(1));
+    assert_eq!(second, Some(2));
+}
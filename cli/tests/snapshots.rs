@@ -0,0 +1,131 @@
+//! Snapshot tests for the output formats. Every fixture in `tests/fixtures` is
+//! run through every format, and the output is compared with
+//! `tests/snapshots/<fixture>.<format>.snap`.
+//!
+//! After a deliberate output change, run
+//! `UNAI_UPDATE_SNAPSHOTS=1 cargo test --test snapshots` to rewrite the
+//! snapshots, then review them in the diff like any other change.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Inputs, one per mode.
+const FIXTURES: &[&str] = &["prose.md", "cache.rs", "COMMIT_EDITMSG"];
+
+/// Formats and the arguments that select them. Color and hyperlinks are off
+/// so the text formats do not depend on the terminal.
+const FORMATS: &[(&str, &[&str])] = &[
+    ("clean", &[]),
+    ("report", &["--report", "--color", "never"]),
+    (
+        "report-plain",
+        &["--report", "--report-style", "plain", "--color", "never"],
+    ),
+    ("diff", &["--diff", "--color", "never"]),
+    ("json", &["--format", "json"]),
+    ("sarif", &["--format", "sarif"]),
+    ("github", &["--format", "github"]),
+];
+
+fn tests_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests")
+}
+
+/// Exit code, stdout, and stderr of unai on `fixture`, run from the fixtures
+/// directory so paths in the output are relative.
+fn render(fixture: &str, args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_unai"))
+        .args(args)
+        .arg(fixture)
+        .current_dir(tests_dir().join("fixtures"))
+        .env_remove("COLUMNS")
+        .output()
+        .expect("failed to run unai");
+    let mut snapshot = String::new();
+    let code = output.status.code().unwrap_or(-1);
+    writeln!(snapshot, "exit: {code}").unwrap();
+    for (name, stream) in [("stdout", &output.stdout), ("stderr", &output.stderr)] {
+        writeln!(snapshot, "==> {name} <==").unwrap();
+        snapshot.push_str(&redact(&String::from_utf8_lossy(stream)));
+    }
+    snapshot
+}
+
+/// `output` with the crate version and timings replaced by placeholders, so
+/// snapshots survive a release and a slow machine.
+fn redact(output: &str) -> String {
+    output
+        .replace(env!("CARGO_PKG_VERSION"), "[version]")
+        .lines()
+        .map(|line| match line.find("\"elapsed_ms\": ") {
+            Some(at) => {
+                let end = if line.ends_with(',') { "," } else { "" };
+                format!("{}\"elapsed_ms\": \"[elapsed]\"{end}\n", &line[..at])
+            }
+            None => format!("{line}\n"),
+        })
+        .collect()
+}
+
+#[test]
+fn formatter_outputs_match_snapshots() {
+    let update = std::env::var_os("UNAI_UPDATE_SNAPSHOTS").is_some();
+    let snapshots = tests_dir().join("snapshots");
+    let mut failures = Vec::new();
+    for fixture in FIXTURES {
+        for (format, args) in FORMATS {
+            let actual = render(fixture, args);
+            let path = snapshots.join(format!("{fixture}.{format}.snap"));
+            let expected = std::fs::read_to_string(&path).unwrap_or_default();
+            if actual == expected {
+                continue;
+            }
+            if update {
+                std::fs::write(&path, &actual).expect("write snapshot");
+                continue;
+            }
+            let diff = similar::TextDiff::from_lines(&expected, &actual)
+                .unified_diff()
+                .header("snapshot", "actual")
+                .to_string();
+            failures.push(format!("{}:\n{diff}", path.display()));
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "{} snapshot(s) differ; rerun with UNAI_UPDATE_SNAPSHOTS=1 to accept:\n\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+/// Every snapshot on disk belongs to a fixture and format, so a removed
+/// format leaves no stale file behind.
+#[test]
+fn no_orphan_snapshots() {
+    let expected: Vec<String> = FIXTURES
+        .iter()
+        .flat_map(|fixture| {
+            FORMATS
+                .iter()
+                .map(move |(format, _)| format!("{fixture}.{format}.snap"))
+        })
+        .collect();
+    for entry in std::fs::read_dir(tests_dir().join("snapshots")).unwrap() {
+        let name = entry.unwrap().file_name().into_string().unwrap();
+        assert!(expected.contains(&name), "orphan snapshot: {name}");
+    }
+}
+
+#[test]
+fn redact_replaces_version_and_timings() {
+    let json = format!(
+        "  \"version\": \"{}\",\n    \"elapsed_ms\": 0.456\n",
+        env!("CARGO_PKG_VERSION")
+    );
+    assert_eq!(
+        redact(&json),
+        "  \"version\": \"[version]\",\n    \"elapsed_ms\": \"[elapsed]\"\n"
+    );
+}
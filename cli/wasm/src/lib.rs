@@ -0,0 +1,93 @@
+//! WebAssembly bindings for unai-core, so a browser extension can lint text
+//! before it is posted. Build with `wasm-pack build --target web` from
+//! `cli/wasm/`.
+//!
+//! Both functions run the built-in rules with the default config; there is
+//! no filesystem to read an `unai.toml` from. `mode` is one of `text`,
+//! `markdown`, `code` or `commit`, and an unknown mode throws.
+
+use serde::Serialize;
+use unai_core::config::OutputConfig;
+use unai_core::report::JsonFinding;
+use unai_core::{clean, Config, Engine, Finding, Mode};
+use wasm_bindgen::prelude::*;
+
+/// The findings in `input`, as an array shaped like the `findings` of
+/// `unai --format json`.
+#[wasm_bindgen]
+pub fn analyze_text(input: &str, mode: &str) -> Result<JsValue, JsError> {
+    let findings = json_findings(input, mode).map_err(|e| JsError::new(&e))?;
+    findings
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// `input` with every fix applied, as `unai` writes it.
+#[wasm_bindgen]
+pub fn clean_text(input: &str, mode: &str) -> Result<String, JsError> {
+    cleaned(input, mode).map_err(|e| JsError::new(&e))
+}
+
+/// [`analyze_text`] before conversion to a JS value.
+pub fn json_findings(input: &str, mode: &str) -> Result<Vec<JsonFinding>, String> {
+    let (findings, mode) = analyze(input, mode)?;
+    let max_matched = OutputConfig::default().max_matched_len;
+    Ok(findings
+        .iter()
+        .map(|f| JsonFinding::new(f, &mode, max_matched))
+        .collect())
+}
+
+/// [`clean_text`] with the error as a plain string.
+pub fn cleaned(input: &str, mode: &str) -> Result<String, String> {
+    let (findings, _) = analyze(input, mode)?;
+    Ok(clean(input, &findings))
+}
+
+fn analyze(input: &str, mode: &str) -> Result<(Vec<Finding>, Mode), String> {
+    let mode: Mode = mode.parse()?;
+    let findings = Engine::new(Config::default()).analyze(input, mode.clone());
+    Ok((findings, mode))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn findings_have_the_cli_json_shape() {
+        let findings = json_findings("We utilize it.\n", "text").unwrap();
+        let value = serde_json::to_value(&findings).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!([{
+                "line": 1,
+                "column": 3,
+                "end_column": 10,
+                "matched": "utilize",
+                "message": findings[0].message,
+                "rule_id": "text/utilize",
+                "severity": "high",
+                "replacement": "use",
+                "fix_safety": "safe",
+                "source": "text",
+                "region": "prose",
+                "source_ref": serde_json::to_value(findings[0].source_ref).unwrap(),
+            }])
+        );
+    }
+
+    #[test]
+    fn cleaned_applies_fixes() {
+        assert_eq!(
+            cleaned("We utilize it.\n", "markdown").unwrap(),
+            "We use it.\n"
+        );
+    }
+
+    #[test]
+    fn unknown_mode_is_an_error() {
+        let err = json_findings("text", "prose").unwrap_err();
+        assert!(err.contains("unknown mode 'prose'"), "{err}");
+    }
+}
@@ -47,7 +47,7 @@ done
 # ── lint / fmt ────────────────────────────────────────────────────────────────
 
 info "fmt check"
-cargo fmt --manifest-path "$MANIFEST" -- --check
+cargo fmt --all --manifest-path "$MANIFEST" -- --check
 
 info "clippy"
 cargo clippy --manifest-path "$MANIFEST" -- -D warnings
@@ -10,6 +10,9 @@
 set -euo pipefail
 
 CARGO_TOML="cli/Cargo.toml"
+# unai-core and unai-wasm are released in lockstep with the binary.
+CORE_TOML="cli/core/Cargo.toml"
+WASM_TOML="cli/wasm/Cargo.toml"
 CHANGELOG="CHANGELOG.md"
 BINARY_NAME="unai"
 
@@ -90,7 +93,7 @@ echo ""
 # ── apply changes ─────────────────────────────────────────────────────────────
 
 if [[ "$DRY_RUN" == "true" ]]; then
-  echo "[dry-run] Would bump Cargo.toml: $CURRENT → $NEW"
+  echo "[dry-run] Would bump cli, core and wasm Cargo.toml: $CURRENT → $NEW"
   echo "[dry-run] Would prepend CHANGELOG entry for $TAG"
   echo "[dry-run] Would commit: chore: release $TAG"
   echo "[dry-run] Would tag: $TAG"
@@ -98,8 +101,13 @@ if [[ "$DRY_RUN" == "true" ]]; then
   exit 0
 fi
 
-# 1. Bump version in Cargo.toml (first occurrence only)
-sed -i.bak "0,/^version = \"${CURRENT}\"/s//version = \"${NEW}\"/" "$CARGO_TOML"
+# 1. Bump version in every Cargo.toml (first occurrence only), and the
+#    binary's requirement on unai-core
+for toml in "$CARGO_TOML" "$CORE_TOML" "$WASM_TOML"; do
+  sed -i.bak "0,/^version = \"${CURRENT}\"/s//version = \"${NEW}\"/" "$toml"
+  rm -f "${toml}.bak"
+done
+sed -i.bak "s/^unai-core = { version = \"${CURRENT}\"/unai-core = { version = \"${NEW}\"/" "$CARGO_TOML"
 rm -f "${CARGO_TOML}.bak"
 
 # 2. Update Cargo.lock (cargo fetch is enough — no build needed)
@@ -125,7 +133,7 @@ echo "CHANGELOG updated — please fill in the release notes, then press Enter t
 read -r
 
 # 4. Commit
-git add "$CARGO_TOML" "cli/Cargo.lock" "$CHANGELOG"
+git add "$CARGO_TOML" "$CORE_TOML" "$WASM_TOML" "cli/Cargo.lock" "$CHANGELOG"
 git commit -m "chore: release ${TAG}"
 
 # 5. Tag